@@ -7,7 +7,9 @@ use crate::opt::Style;
 use crate::parser::Action;
 use crate::str::display_of_osstr;
 use crate::str::display_of_str;
+use crate::value::BoolLiteral;
 use crate::value::RawValParser;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -24,6 +26,8 @@ pub struct InnerCtx<'a> {
     index: usize,
 
     total: usize,
+
+    source: Option<ValueSource>,
 }
 
 impl<'a> InnerCtx<'a> {
@@ -57,6 +61,11 @@ impl<'a> InnerCtx<'a> {
         self
     }
 
+    pub fn with_source(mut self, source: Option<ValueSource>) -> Self {
+        self.source = source;
+        self
+    }
+
     /// The uid of matched option.
     pub fn uid(&self) -> Uid {
         self.uid
@@ -89,6 +98,13 @@ impl<'a> InnerCtx<'a> {
         self.arg.as_ref()
     }
 
+    /// The source of argument, default is [`ValueSource::CommandLine`] with current index.
+    pub fn source(&self) -> ValueSource {
+        self.source
+            .clone()
+            .unwrap_or(ValueSource::CommandLine { index: self.index })
+    }
+
     pub fn set_uid(&mut self, uid: Uid) -> &mut Self {
         self.uid = uid;
         self
@@ -120,19 +136,25 @@ impl<'a> InnerCtx<'a> {
         self.arg = arg;
         self
     }
+
+    pub fn set_source(&mut self, source: Option<ValueSource>) -> &mut Self {
+        self.source = source;
+        self
+    }
 }
 
 impl Display for InnerCtx<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "InnerCtx {{ uid: {}, name: {}, style: {}, arg: {}, index: {}, total: {} }}",
+            "InnerCtx {{ uid: {}, name: {}, style: {}, arg: {}, index: {}, total: {}, source: {} }}",
             self.uid,
             display_of_str(self.name.as_deref()),
             self.style,
             display_of_osstr(self.arg.as_deref()),
             self.index,
             self.total,
+            self.source(),
         )
     }
 }
@@ -147,6 +169,8 @@ pub struct Ctx<'a> {
 
     pub inner_ctx: Option<InnerCtx<'a>>,
 
+    literal: Option<BoolLiteral>,
+
     #[cfg(not(feature = "sync"))]
     action: std::cell::RefCell<Action>,
 
@@ -160,6 +184,7 @@ impl Clone for Ctx<'_> {
             orig: self.orig.clone(),
             args: self.args.clone(),
             inner_ctx: self.inner_ctx.clone(),
+            literal: self.literal.clone(),
             #[cfg(not(feature = "sync"))]
             action: self.action.clone(),
             #[cfg(feature = "sync")]
@@ -183,6 +208,11 @@ impl<'a> Ctx<'a> {
         self.inner_ctx = Some(inner_ctx);
         self
     }
+
+    pub fn with_bool_literal(mut self, literal: Option<BoolLiteral>) -> Self {
+        self.literal = literal;
+        self
+    }
 }
 
 impl<'a> Ctx<'a> {
@@ -224,6 +254,11 @@ impl<'a> Ctx<'a> {
         Ok(self.inner_ctx()?.arg())
     }
 
+    /// The source of argument, such as command line, environment variable or configuration.
+    pub fn source(&self) -> Result<ValueSource, Error> {
+        Ok(self.inner_ctx()?.source())
+    }
+
     pub fn inner_ctx(&self) -> Result<&InnerCtx<'a>, Error> {
         self.inner_ctx
             .as_ref()
@@ -236,6 +271,11 @@ impl<'a> Ctx<'a> {
             .ok_or_else(|| crate::error!("InnerCtx(mutable) not exist, try create a new one"))
     }
 
+    /// The words accepted when parsing [`bool`], None means the default [`BoolLiteral`].
+    pub fn bool_literal(&self) -> Option<&BoolLiteral> {
+        self.literal.as_ref()
+    }
+
     /// The original arguments passed by user.
     pub fn orig(&self) -> &Args {
         &self.orig
@@ -298,6 +338,11 @@ impl<'a> Ctx<'a> {
         self
     }
 
+    pub fn set_bool_literal(&mut self, literal: Option<BoolLiteral>) -> &mut Self {
+        self.literal = literal;
+        self
+    }
+
     pub fn set_inner_ctx(&mut self, inner_ctx: Option<InnerCtx<'a>>) -> &mut Self {
         crate::trace!("switching InnerCtx to {:?}", inner_ctx);
         self.inner_ctx = inner_ctx;
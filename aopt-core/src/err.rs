@@ -10,6 +10,7 @@ use crate::Uid;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Kind {
     MissingValue,
@@ -18,6 +19,12 @@ pub enum Kind {
 
     OptRequired,
 
+    OptConflict,
+
+    OptOccurrence,
+
+    ConfigSource,
+
     CmdRequired,
 
     OptionNotFound,
@@ -63,6 +70,24 @@ pub struct Error {
     desp: Option<String>,
 
     cause: Option<Box<Error>>,
+
+    detail: Option<Box<Detail>>,
+}
+
+/// The details of [`Error`], boxed for keeping the [`Error`] small.
+#[derive(Debug, Clone, Default)]
+struct Detail {
+    name: Option<String>,
+
+    index: Option<usize>,
+
+    argument: Option<String>,
+
+    hints: Vec<String>,
+
+    suggestions: Vec<String>,
+
+    fails: Vec<Error>,
 }
 
 impl std::error::Error for Error {
@@ -91,6 +116,38 @@ impl Display for Error {
     }
 }
 
+/// Serialize the error with its kind, description and the details,
+/// the cause and the errors of parsers are serialized recursively.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let message = self
+            .desp
+            .as_deref()
+            .or(self.kind.desp())
+            .unwrap_or_default();
+        let mut state = serializer.serialize_struct("Error", 11)?;
+
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("failure", &self.is_failure())?;
+        state.serialize_field("message", message)?;
+        state.serialize_field("uid", &self.uid)?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("index", &self.index())?;
+        state.serialize_field("argument", &self.argument())?;
+        state.serialize_field("hints", self.hints())?;
+        state.serialize_field("suggestions", self.suggestions())?;
+        state.serialize_field("cause", &self.cause)?;
+        state.serialize_field("fails", self.fails())?;
+        state.end()
+    }
+}
+
 impl Error {
     pub fn new(kind: Kind) -> Self {
         Self {
@@ -98,6 +155,7 @@ impl Error {
             uid: None,
             desp: None,
             cause: None,
+            detail: None,
         }
     }
 
@@ -120,6 +178,40 @@ impl Error {
         self
     }
 
+    fn detail_mut(&mut self) -> &mut Detail {
+        self.detail.get_or_insert_with(Default::default)
+    }
+
+    /// Set the name of option which caused the error.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.detail_mut().name = Some(name.into());
+        self
+    }
+
+    /// Set the index of argument which caused the error.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.detail_mut().index = Some(index);
+        self
+    }
+
+    /// Set the argument value which caused the error.
+    pub fn with_argument(mut self, argument: impl Into<String>) -> Self {
+        self.detail_mut().argument = Some(argument.into());
+        self
+    }
+
+    /// Add a hint that help the user fix the error.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.detail_mut().hints.push(hint.into());
+        self
+    }
+
+    /// Set the names user may want, such as the similar option names.
+    pub fn with_suggestions<S: Into<String>>(mut self, suggestions: Vec<S>) -> Self {
+        self.detail_mut().suggestions = suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
     pub fn uid(&self) -> Option<Uid> {
         self.uid
     }
@@ -132,6 +224,40 @@ impl Error {
         self.cause.as_deref()
     }
 
+    pub fn name(&self) -> Option<&str> {
+        self.detail.as_ref().and_then(|v| v.name.as_deref())
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        self.detail.as_ref().and_then(|v| v.index)
+    }
+
+    pub fn argument(&self) -> Option<&str> {
+        self.detail.as_ref().and_then(|v| v.argument.as_deref())
+    }
+
+    pub fn hints(&self) -> &[String] {
+        self.detail
+            .as_ref()
+            .map(|v| v.hints.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn suggestions(&self) -> &[String] {
+        self.detail
+            .as_ref()
+            .map(|v| v.suggestions.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The errors of each parser, see [`sp_no_parser_matched`](Error::sp_no_parser_matched).
+    pub fn fails(&self) -> &[Error] {
+        self.detail
+            .as_ref()
+            .map(|v| v.fails.as_slice())
+            .unwrap_or_default()
+    }
+
     /// The error can be moitted if [`is_failure`](Error::is_failure) return true.
     pub fn is_failure(&self) -> bool {
         let kind = &self.kind;
@@ -145,6 +271,9 @@ impl Error {
                 | Kind::CmdRequired
                 | Kind::PosRequired
                 | Kind::OptRequired
+                | Kind::OptConflict
+                | Kind::OptOccurrence
+                | Kind::ConfigSource
                 | Kind::MissingValue
         )
     }
@@ -162,20 +291,45 @@ impl Error {
         Self::new(Kind::NoParserMatched)
     }
 
+    /// All the parsers failed, the error keep the reason of each parser.
+    pub fn sp_no_parser_matched<S: Into<String>>(fails: Vec<(S, Error)>) -> Self {
+        let (names, fails): (Vec<String>, Vec<Error>) =
+            fails.into_iter().map(|(n, e)| (n.into(), e)).unzip();
+        let mut desp = String::from("all parser passed to `getopt!` match failed");
+
+        for (name, fail) in names.iter().zip(fails.iter()) {
+            desp.push_str(&format!("\n  parser `{name}`: {fail}"));
+        }
+
+        let mut error = Self::new(Kind::NoParserMatched).with_desp(desp);
+
+        error.detail_mut().fails = fails;
+        error
+    }
+
     pub fn from<E: std::error::Error + Display>(error: E) -> Self {
         Self::raise_error(error.to_string())
     }
 
     pub fn arg(arg: impl Into<String>, hint: impl Into<String>) -> Self {
-        let desp = format!("invalid argument `{}`: {}", arg.into(), hint.into());
+        let (arg, hint) = (arg.into(), hint.into());
+        let desp = format!("invalid argument `{}`: {}", arg, hint);
 
-        Self::new(Kind::Arg).with_desp(desp)
+        Self::new(Kind::Arg)
+            .with_desp(desp)
+            .with_argument(arg)
+            .with_hint(hint)
     }
 
     pub fn sp_rawval(val: Option<&OsStr>, hint: impl Into<String>) -> Self {
-        let desp = format!("invalid value `{}`: {}", display_of_osstr(val), hint.into());
+        let hint = hint.into();
+        let desp = format!("invalid value `{}`: {}", display_of_osstr(val), hint);
+        let error = Self::new(Kind::RawValParse).with_desp(desp).with_hint(hint);
 
-        Self::new(Kind::RawValParse).with_desp(desp)
+        match val {
+            Some(val) => error.with_argument(val.to_string_lossy()),
+            None => error,
+        }
     }
 
     pub fn index_parse(pat: impl Into<String>, hint: impl Into<String>) -> Self {
@@ -203,9 +357,12 @@ impl Error {
     }
 
     pub fn sp_missing_value(name: impl Into<String>) -> Self {
-        let desp = format!("missing value for option `{}`", name.into());
+        let name = name.into();
+        let desp = format!("missing value for option `{}`", name);
 
-        Self::new(Kind::MissingValue).with_desp(desp)
+        Self::new(Kind::MissingValue)
+            .with_desp(desp)
+            .with_name(name)
     }
 
     pub fn sp_pos_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -236,6 +393,107 @@ impl Error {
         Self::new(Kind::OptRequired).with_desp(desp)
     }
 
+    pub fn sp_opt_conflict(name: impl Into<String>, other: impl Into<String>) -> Self {
+        let name = name.into();
+        let desp = format!("option `{}` can not be used with `{}`", name, other.into());
+
+        Self::new(Kind::OptConflict).with_desp(desp).with_name(name)
+    }
+
+    pub fn sp_opt_depend(name: impl Into<String>, other: impl Into<String>) -> Self {
+        let name = name.into();
+        let desp = format!("option `{}` requires `{}` to be set", name, other.into());
+
+        Self::new(Kind::OptRequired).with_desp(desp).with_name(name)
+    }
+
+    pub fn sp_opt_duplicate(name: impl Into<String>, first: usize, current: usize) -> Self {
+        let name = name.into();
+        let desp = format!(
+            "option `{}` is duplicated at position {}, it is first set at position {}",
+            name, current, first
+        );
+
+        Self::new(Kind::OptOccurrence)
+            .with_desp(desp)
+            .with_name(name)
+            .with_index(current)
+    }
+
+    pub fn sp_opt_too_many(
+        name: impl Into<String>,
+        max: usize,
+        last: usize,
+        current: usize,
+    ) -> Self {
+        let name = name.into();
+        let desp = format!(
+            "option `{}` can occur at most {} time(s), found at position {} after position {}",
+            name, max, current, last
+        );
+
+        Self::new(Kind::OptOccurrence)
+            .with_desp(desp)
+            .with_name(name)
+            .with_index(current)
+    }
+
+    pub fn sp_opt_too_few(name: impl Into<String>, min: usize, count: usize) -> Self {
+        let name = name.into();
+        let desp = format!(
+            "option `{}` must occur at least {} time(s), found {}",
+            name, min, count
+        );
+
+        Self::new(Kind::OptOccurrence)
+            .with_desp(desp)
+            .with_name(name)
+    }
+
+    pub fn sp_group_conflict<S: Into<String>>(group: impl Into<String>, names: Vec<S>) -> Self {
+        let names: Vec<_> = names.into_iter().map(Into::into).collect();
+        let desp = format!(
+            "options `{}` in group `{}` can not be used together",
+            names.join("`, `"),
+            group.into()
+        );
+
+        Self::new(Kind::OptConflict).with_desp(desp)
+    }
+
+    pub fn sp_group_require<S: Into<String>>(group: impl Into<String>, names: Vec<S>) -> Self {
+        let names: Vec<_> = names.into_iter().map(Into::into).collect();
+        let desp = format!(
+            "one of `{}` in group `{}` is required",
+            names.join("`, `"),
+            group.into()
+        );
+
+        Self::new(Kind::OptRequired).with_desp(desp)
+    }
+
+    pub fn sp_group_partial<S: Into<String>>(group: impl Into<String>, missing: Vec<S>) -> Self {
+        let missing: Vec<_> = missing.into_iter().map(Into::into).collect();
+        let desp = format!(
+            "options in group `{}` must be used together, missing `{}`",
+            group.into(),
+            missing.join("`, `")
+        );
+
+        Self::new(Kind::OptRequired).with_desp(desp)
+    }
+
+    pub fn sp_opt_require_unless<S: Into<String>>(name: impl Into<String>, others: Vec<S>) -> Self {
+        let others: Vec<_> = others.into_iter().map(Into::into).collect();
+        let desp = format!(
+            "option `{}` is required unless `{}` is set",
+            name.into(),
+            others.join("`, `")
+        );
+
+        Self::new(Kind::OptRequired).with_desp(desp)
+    }
+
     pub fn sp_cmd_require<S: Into<String>>(names: Vec<S>) -> Self {
         let names: Vec<_> = names.into_iter().map(Into::into).collect();
         let desp = match names.len() {
@@ -250,10 +508,33 @@ impl Error {
         Self::new(Kind::CmdRequired).with_desp(desp)
     }
 
+    pub fn sp_config_load(origin: impl Into<String>, hint: impl Into<String>) -> Self {
+        let desp = format!(
+            "can not load configuration `{}`: {}",
+            origin.into(),
+            hint.into()
+        );
+
+        Self::new(Kind::ConfigSource).with_desp(desp)
+    }
+
+    pub fn sp_config_value(origin: impl Into<String>, key: impl Into<String>) -> Self {
+        let desp = format!(
+            "invalid value of key `{}` in configuration `{}`",
+            key.into(),
+            origin.into()
+        );
+
+        Self::new(Kind::ConfigSource).with_desp(desp)
+    }
+
     pub fn sp_not_found(name: impl Into<String>) -> Self {
-        let desp = format!("can not find option `{}`", name.into());
+        let name = name.into();
+        let desp = format!("can not find option `{}`", name);
 
-        Self::new(Kind::OptionNotFound).with_desp(desp)
+        Self::new(Kind::OptionNotFound)
+            .with_desp(desp)
+            .with_name(name)
     }
 
     pub fn sp_extract(msg: impl Into<String>) -> Self {
@@ -1,16 +1,25 @@
 pub(crate) mod action;
+pub(crate) mod constraint;
+pub(crate) mod group;
 pub(crate) mod index;
+pub(crate) mod occurrence;
 pub(crate) mod style;
 
 use std::any::TypeId;
 use std::fmt::Debug;
 
+use crate::value::ElemSeparator;
 use crate::value::ValAccessor;
 use crate::Error;
 use crate::Uid;
 
 pub use self::action::Action;
+pub use self::constraint::Constraint;
+pub use self::constraint::ConstraintKind;
+pub use self::group::OptGroup;
 pub use self::index::Index;
+pub use self::occurrence::Duplicate;
+pub use self::occurrence::Occurrence;
 pub use self::style::Style;
 
 pub const BOOL_TRUE: &str = "true";
@@ -52,6 +61,36 @@ pub trait Opt: Debug {
     /// The alias the option.
     fn alias(&self) -> Option<&Vec<String>>;
 
+    /// The constraints between the option and other options.
+    fn constraints(&self) -> &[Constraint] {
+        &[]
+    }
+
+    fn constraints_mut(&mut self) -> &mut [Constraint] {
+        &mut []
+    }
+
+    /// The occurrence rule of option.
+    fn occurrence(&self) -> &Occurrence {
+        &Occurrence::DEFAULT
+    }
+
+    /// The argument positions where the option matched, cleared when option reset.
+    /// The value from environment variable or configuration is recorded at the position after last argument.
+    fn positions(&self) -> &[usize] {
+        &[]
+    }
+
+    /// The environment variable used as fallback value when option not matched.
+    fn env(&self) -> Option<&str> {
+        None
+    }
+
+    /// The separator used to split the value of option with [`Action::Ext`].
+    fn separator(&self) -> Option<&ElemSeparator> {
+        None
+    }
+
     fn accessor(&self) -> &ValAccessor;
 
     fn accessor_mut(&mut self) -> &mut ValAccessor;
@@ -66,6 +105,8 @@ pub trait Opt: Debug {
 
     fn set_matched(&mut self, matched: bool);
 
+    fn add_position(&mut self, _index: usize) {}
+
     fn mat_style(&self, style: Style) -> bool;
 
     fn mat_force(&self, force: bool) -> bool;
@@ -100,6 +141,22 @@ pub trait Opt: Debug {
 
     fn rem_alias(&mut self, name: &str) -> &mut Self;
 
+    fn add_constraint(&mut self, _constraint: Constraint) -> &mut Self {
+        self
+    }
+
+    fn set_occurrence(&mut self, _occurrence: Occurrence) -> &mut Self {
+        self
+    }
+
+    fn set_env(&mut self, _env: Option<String>) -> &mut Self {
+        self
+    }
+
+    fn set_separator(&mut self, _separator: Option<ElemSeparator>) -> &mut Self {
+        self
+    }
+
     fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self;
 
     fn set_ignore_alias(&mut self, ignore_alias: bool) -> &mut Self;
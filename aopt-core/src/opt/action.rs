@@ -1,8 +1,10 @@
 use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::str::FromStr;
 
 use crate::map::ErasedTy;
 use crate::value::AnyValue;
+use crate::Error;
 
 /// The default action type for option value saving, see [`process`](https://docs.rs/aopt/latest/aopt/opt/enum.Action.html#method.process).
 #[non_exhaustive]
@@ -24,6 +26,19 @@ pub enum Action {
     /// Clear the value of [`AnyValue`]
     Clr,
 
+    /// Append value into [`AnyValue`] if the raw value not saved before
+    AppUniq,
+
+    /// Split the raw value by the separator of option, append each of them into [`AnyValue`]
+    Ext,
+
+    /// Same as [`Set`](Action::Set), but the option can only appear once
+    SetOnce,
+
+    /// Flip the last boolean value of [`AnyValue`], save the value if no value saved.
+    /// Only available for [`bool`] option, the creation of other option will fail
+    Toggle,
+
     /// Do nothing
     #[default]
     Null,
@@ -54,6 +69,22 @@ impl Action {
         matches!(self, Self::Null)
     }
 
+    pub fn is_app_uniq(&self) -> bool {
+        matches!(self, Self::AppUniq)
+    }
+
+    pub fn is_ext(&self) -> bool {
+        matches!(self, Self::Ext)
+    }
+
+    pub fn is_set_once(&self) -> bool {
+        matches!(self, Self::SetOnce)
+    }
+
+    pub fn is_toggle(&self) -> bool {
+        matches!(self, Self::Toggle)
+    }
+
     /// Save the value in [`handler`](AnyValue).
     pub fn store1<U: ErasedTy>(&self, val: Option<U>, handler: &mut AnyValue) -> bool {
         crate::trace!(
@@ -65,10 +96,10 @@ impl Action {
         );
         if let Some(val) = val {
             match self {
-                Action::Set => {
+                Action::Set | Action::SetOnce => {
                     handler.set(vec![val]);
                 }
-                Action::App => {
+                Action::App | Action::AppUniq | Action::Ext => {
                     handler.push(val);
                 }
                 Action::Pop => {
@@ -80,6 +111,17 @@ impl Action {
                 Action::Clr => {
                     handler.remove::<U>();
                 }
+                Action::Toggle => {
+                    let vals = handler.entry::<U>().or_insert(vec![]);
+                    let last = vals.last_mut().map(|v| v as &mut dyn std::any::Any);
+
+                    match last.map(|v| v.downcast_mut::<bool>()) {
+                        Some(Some(last)) => *last = !*last,
+                        // not a boolean value, replace it
+                        Some(None) => *vals = vec![val],
+                        None => vals.push(val),
+                    }
+                }
                 Action::Null => {
                     // NOTHING
                 }
@@ -92,6 +134,8 @@ impl Action {
     }
 
     /// Save the value in [`handler`](AnyValue) and raw value in `raw_handler`.
+    ///
+    /// For [`AppUniq`](Action::AppUniq), the value is ignored if the raw value already in `raw_handler`.
     pub fn store2<U: ErasedTy>(
         &self,
         raw: Option<&OsStr>,
@@ -99,6 +143,9 @@ impl Action {
         raw_handler: &mut Vec<OsString>,
         handler: &mut AnyValue,
     ) -> bool {
+        if self.is_app_uniq() && raw.is_some_and(|raw| raw_handler.iter().any(|v| v == raw)) {
+            return val.is_some();
+        }
         let ret = self.store1(val, handler);
 
         if ret {
@@ -128,9 +175,42 @@ impl std::fmt::Display for Action {
             Action::Clr => {
                 write!(f, "Action::Clr")
             }
+            Action::AppUniq => {
+                write!(f, "Action::AppUniq")
+            }
+            Action::Ext => {
+                write!(f, "Action::Ext")
+            }
+            Action::SetOnce => {
+                write!(f, "Action::SetOnce")
+            }
+            Action::Toggle => {
+                write!(f, "Action::Toggle")
+            }
             Action::Null => {
                 write!(f, "Action::Null")
             }
         }
     }
 }
+
+impl FromStr for Action {
+    type Err = Error;
+
+    /// Parse the action from name, such as `set`, `append` or `uniq`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "set" => Ok(Action::Set),
+            "app" | "append" => Ok(Action::App),
+            "pop" => Ok(Action::Pop),
+            "cnt" | "count" => Ok(Action::Cnt),
+            "clr" | "clear" => Ok(Action::Clr),
+            "uniq" | "unique" => Ok(Action::AppUniq),
+            "ext" | "extend" => Ok(Action::Ext),
+            "once" => Ok(Action::SetOnce),
+            "toggle" => Ok(Action::Toggle),
+            "null" => Ok(Action::Null),
+            _ => Err(crate::error!("unknown action name `{}`", s)),
+        }
+    }
+}
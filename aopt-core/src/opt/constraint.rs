@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use crate::Uid;
+
+/// The relation between an option and the other options.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConstraintKind {
+    /// The option can not set with any of the targets.
+    Conflicts,
+
+    /// All the targets must be set if the option is set.
+    Requires,
+
+    /// The option must be set unless any of the targets is set.
+    RequiredUnless,
+}
+
+/// A cross-option rule attached to an option, evaluated after parsing.
+///
+/// The targets are the name or alias of other options, they are resolved to
+/// [`Uid`] when the options are inserted into the set.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::opt::*;
+/// #
+/// let constraint = Constraint::conflicts(["--table", "--csv"]);
+///
+/// assert_eq!(constraint.kind(), ConstraintKind::Conflicts);
+/// assert_eq!(constraint.targets(), &["--table", "--csv"]);
+/// assert_eq!(constraint.uids(), &[None, None]);
+/// assert_eq!(constraint.to_string(), "conflicts with --table, --csv");
+/// assert_eq!(Constraint::requires(["--cert"]).to_string(), "requires --cert");
+/// assert_eq!(
+///     Constraint::required_unless(["--stdin"]).to_string(),
+///     "required unless --stdin"
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constraint {
+    kind: ConstraintKind,
+
+    targets: Vec<String>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    uids: Vec<Option<Uid>>,
+}
+
+impl Constraint {
+    pub fn new<S: Into<String>>(
+        kind: ConstraintKind,
+        targets: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let targets: Vec<String> = targets.into_iter().map(Into::into).collect();
+        let uids = vec![None; targets.len()];
+
+        Self {
+            kind,
+            targets,
+            uids,
+        }
+    }
+
+    /// The option can not set with any of the `targets`.
+    pub fn conflicts<S: Into<String>>(targets: impl IntoIterator<Item = S>) -> Self {
+        Self::new(ConstraintKind::Conflicts, targets)
+    }
+
+    /// All the `targets` must be set if the option is set.
+    pub fn requires<S: Into<String>>(targets: impl IntoIterator<Item = S>) -> Self {
+        Self::new(ConstraintKind::Requires, targets)
+    }
+
+    /// The option must be set unless any of the `targets` is set.
+    pub fn required_unless<S: Into<String>>(targets: impl IntoIterator<Item = S>) -> Self {
+        Self::new(ConstraintKind::RequiredUnless, targets)
+    }
+
+    pub fn kind(&self) -> ConstraintKind {
+        self.kind
+    }
+
+    pub fn targets(&self) -> &[String] {
+        &self.targets
+    }
+
+    /// The resolved [`Uid`] of targets, it is [`None`] if the target is not resolved.
+    pub fn uids(&self) -> &[Option<Uid>] {
+        &self.uids
+    }
+
+    /// Resolve the targets not resolved yet using `f`.
+    pub fn resolve(&mut self, mut f: impl FnMut(&str) -> Option<Uid>) -> &mut Self {
+        if self.uids.len() != self.targets.len() {
+            self.uids.resize(self.targets.len(), None);
+        }
+        for (target, uid) in self.targets.iter().zip(self.uids.iter_mut()) {
+            if uid.is_none() {
+                *uid = f(target);
+            }
+        }
+        self
+    }
+
+    pub fn is_conflicts(&self) -> bool {
+        matches!(self.kind, ConstraintKind::Conflicts)
+    }
+
+    pub fn is_requires(&self) -> bool {
+        matches!(self.kind, ConstraintKind::Requires)
+    }
+
+    pub fn is_required_unless(&self) -> bool {
+        matches!(self.kind, ConstraintKind::RequiredUnless)
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let targets = self.targets.join(", ");
+
+        match self.kind {
+            ConstraintKind::Conflicts => write!(f, "conflicts with {targets}"),
+            ConstraintKind::Requires => write!(f, "requires {targets}"),
+            ConstraintKind::RequiredUnless => write!(f, "required unless {targets}"),
+        }
+    }
+}
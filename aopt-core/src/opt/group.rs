@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+/// A named group of options with a cardinality rule, evaluated after parsing.
+///
+/// The members are the name or alias of options, they are resolved to
+/// [`Uid`](crate::Uid) when the group is checked.
+///
+/// * `exclusive`: at most one of the members can be set;
+/// * `required`: at least one of the members must be set;
+/// * `all_or_none`: if any of the members is set, all of them must be set.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::opt::*;
+/// #
+/// let src = OptGroup::new("src")
+///     .with_members(["--file", "--url", "--stdin"])
+///     .with_exclusive(true)
+///     .with_required(true);
+///
+/// assert_eq!(src.rule(), "exactly one");
+/// assert_eq!(src.to_string(), "(--file | --url | --stdin)");
+/// assert_eq!(src.usage(), "(--file | --url | --stdin)");
+///
+/// let auth = OptGroup::new("auth")
+///     .with_members(["--user", "--password"])
+///     .with_all_or_none(true);
+///
+/// assert_eq!(auth.rule(), "all or none");
+/// assert_eq!(auth.to_string(), "(--user --password)");
+/// assert_eq!(auth.usage(), "[--user --password]");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OptGroup {
+    name: String,
+
+    members: Vec<String>,
+
+    exclusive: bool,
+
+    required: bool,
+
+    all_or_none: bool,
+}
+
+impl OptGroup {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_members<S: Into<String>>(mut self, members: impl IntoIterator<Item = S>) -> Self {
+        self.members = members.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_member(mut self, member: impl Into<String>) -> Self {
+        self.members.push(member.into());
+        self
+    }
+
+    pub fn with_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_all_or_none(mut self, all_or_none: bool) -> Self {
+        self.all_or_none = all_or_none;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn exclusive(&self) -> bool {
+        self.exclusive
+    }
+
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    pub fn all_or_none(&self) -> bool {
+        self.all_or_none
+    }
+
+    pub fn add_member(&mut self, member: impl Into<String>) -> &mut Self {
+        self.members.push(member.into());
+        self
+    }
+
+    pub fn set_exclusive(&mut self, exclusive: bool) -> &mut Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    pub fn set_required(&mut self, required: bool) -> &mut Self {
+        self.required = required;
+        self
+    }
+
+    pub fn set_all_or_none(&mut self, all_or_none: bool) -> &mut Self {
+        self.all_or_none = all_or_none;
+        self
+    }
+
+    /// Return the usage of group, such as `(--file | --url)` for required group,
+    /// or `[--file | --url]` for optional group.
+    pub fn usage(&self) -> String {
+        if self.required {
+            format!("({})", self.join_members())
+        } else {
+            format!("[{}]", self.join_members())
+        }
+    }
+
+    fn join_members(&self) -> String {
+        let sep = if self.all_or_none && !self.exclusive {
+            " "
+        } else {
+            " | "
+        };
+
+        self.members.join(sep)
+    }
+
+    /// Return the description of cardinality rule, such as `exactly one`.
+    pub fn rule(&self) -> &'static str {
+        match (self.exclusive, self.required, self.all_or_none) {
+            (true, true, _) => "exactly one",
+            (true, false, _) => "at most one",
+            (false, true, true) => "all",
+            (false, true, false) => "at least one",
+            (false, false, true) => "all or none",
+            (false, false, false) => "any",
+        }
+    }
+}
+
+impl Display for OptGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.join_members())
+    }
+}
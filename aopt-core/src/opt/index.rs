@@ -335,10 +335,8 @@ impl Index {
                     }
                 }
             }
-            Self::Except(list) => {
-                if noa_index < noa_count && !list.contains(&noa_index) {
-                    return Some(noa_index);
-                }
+            Self::Except(list) if noa_index < noa_count && !list.contains(&noa_index) => {
+                return Some(noa_index);
             }
             Self::Range(start, end) => match (start, end) {
                 (start, None) => {
@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+/// What the policy should do when an option occurs again.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Duplicate {
+    /// Process every occurrence according to the [`Action`](crate::opt::Action) of option.
+    #[default]
+    Allow,
+
+    /// Raise an error cites the position of first and current occurrence.
+    Error,
+
+    /// Keep the value of first occurrence, ignore the others.
+    FirstWins,
+
+    /// Clear the values of previous occurrences, keep the value of last occurrence.
+    LastWins,
+}
+
+impl Display for Duplicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Duplicate::Allow => write!(f, "allow"),
+            Duplicate::Error => write!(f, "error"),
+            Duplicate::FirstWins => write!(f, "first wins"),
+            Duplicate::LastWins => write!(f, "last wins"),
+        }
+    }
+}
+
+/// The occurrence rule of option, enforced by the policies when option matched.
+///
+/// * `max`: the option can not occur more than `max` times;
+/// * `min`: the option must occur at least `min` times if it is set or force required,
+///   checked in [`opt_check`](https://docs.rs/aopt/latest/aopt/set/trait.SetChecker.html#tymethod.opt_check);
+/// * `duplicate`: how to process the second and later occurrences, see [`Duplicate`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::opt::*;
+/// #
+/// let rule = Occurrence::default().with_max(1).with_duplicate(Duplicate::Error);
+///
+/// assert_eq!(rule.max(), Some(1));
+/// assert_eq!(rule.min(), 0);
+/// assert!(rule.exceed(1));
+/// assert!(!rule.exceed(0));
+/// assert!(Occurrence::default().with_min(2).insufficient(1));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Occurrence {
+    min: usize,
+
+    max: Option<usize>,
+
+    duplicate: Duplicate,
+}
+
+impl Occurrence {
+    /// The default rule, the option can occur any times.
+    pub const DEFAULT: Occurrence = Occurrence::new(0, None, Duplicate::Allow);
+
+    pub const fn new(min: usize, max: Option<usize>, duplicate: Duplicate) -> Self {
+        Self {
+            min,
+            max,
+            duplicate,
+        }
+    }
+
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_duplicate(mut self, duplicate: Duplicate) -> Self {
+        self.duplicate = duplicate;
+        self
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    pub fn duplicate(&self) -> Duplicate {
+        self.duplicate
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn set_max(&mut self, max: Option<usize>) -> &mut Self {
+        self.max = max;
+        self
+    }
+
+    pub fn set_duplicate(&mut self, duplicate: Duplicate) -> &mut Self {
+        self.duplicate = duplicate;
+        self
+    }
+
+    /// Return true if one more occurrence exceed the `max`, `count` is current occurrence count.
+    pub fn exceed(&self, count: usize) -> bool {
+        self.max.map(|max| count >= max).unwrap_or_default()
+    }
+
+    /// Return true if the occurrence count less than `min`.
+    pub fn insufficient(&self, count: usize) -> bool {
+        count < self.min
+    }
+}
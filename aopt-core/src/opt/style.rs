@@ -17,6 +17,7 @@ pub enum Style {
     Main,
 
     /// The style indicate option don't need argument, such as `--boolean`, `-b` or with no prefix `b`.
+    /// The value can be set explicitly, such as `--boolean=no`.
     /// Using it with [`Boolean`](https://docs.rs/aopt/latest/aopt/parser/enum.UserStyle.html#variant.Boolean).
     Boolean,
 
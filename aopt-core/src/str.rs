@@ -45,6 +45,60 @@ pub fn split_once(str: &'_ OsStr, ch: char) -> Option<(Cow<'_, OsStr>, Cow<'_, O
         })
 }
 
+/// Split the units of string by any of the separators.
+fn split_units<'a, T: PartialEq>(enc: &'a [T], seps: &[Vec<T>]) -> Vec<&'a [T]> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < enc.len() {
+        match seps.iter().find(|sep| enc[i..].starts_with(sep)) {
+            Some(sep) => {
+                parts.push(&enc[start..i]);
+                i += sep.len();
+                start = i;
+            }
+            None => i += 1,
+        }
+    }
+    parts.push(&enc[start..]);
+    parts
+}
+
+#[cfg(target_family = "windows")]
+pub fn split_all<'a>(str: &'a OsStr, seps: &[char]) -> Vec<Cow<'a, OsStr>> {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    let enc = str.encode_wide().collect::<Vec<u16>>();
+    let seps = seps
+        .iter()
+        .map(|ch| ch.encode_utf16(&mut [0; 2]).to_vec())
+        .collect::<Vec<_>>();
+
+    split_units(&enc, &seps)
+        .into_iter()
+        .map(|v| Cow::Owned(OsString::from_wide(v)))
+        .collect()
+}
+
+#[cfg(any(target_family = "wasm", target_family = "unix"))]
+pub fn split_all<'a>(str: &'a OsStr, seps: &[char]) -> Vec<Cow<'a, OsStr>> {
+    #[cfg(target_family = "unix")]
+    use std::os::unix::ffi::OsStrExt;
+    #[cfg(target_family = "wasm")]
+    use std::os::wasi::ffi::OsStrExt;
+
+    let seps = seps
+        .iter()
+        .map(|ch| ch.encode_utf8(&mut [0; 4]).as_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    split_units(str.as_bytes(), &seps)
+        .into_iter()
+        .map(|v| Cow::Borrowed(OsStr::from_bytes(v)))
+        .collect()
+}
+
 /// Convert a [`OsStr`] to [`Cow<'_, str>`].
 pub fn osstr_to_str_i<'a>(val: &[&'a OsStr], i: usize) -> Option<Cow<'a, str>> {
     val.get(i).and_then(|v| v.to_str().map(Cow::Borrowed))
@@ -66,6 +120,40 @@ pub fn display_of_osstr(val: Option<&OsStr>) -> String {
     }
 }
 
+/// Return the edit distance between `a` and `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// Return the words similar to `word`, sorted by the [`edit_distance`].
+/// The distance of similar word is not greater than a third of the length of `word`, at least 1.
+pub fn similar_words<'a>(word: &str, words: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let max = (word.chars().count() / 3).max(1);
+    let mut similar: Vec<(usize, &str)> = words
+        .into_iter()
+        .filter(|v| *v != word)
+        .map(|v| (edit_distance(word, v), v))
+        .filter(|(distance, _)| *distance <= max)
+        .collect();
+
+    similar.sort();
+    similar.dedup();
+    similar.into_iter().map(|(_, v)| v.to_string()).collect()
+}
+
 pub trait CowOsStrUtils<'a> {
     fn split_once(&self, sep: char) -> Option<(Cow<'a, OsStr>, Cow<'a, OsStr>)>;
 
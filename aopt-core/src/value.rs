@@ -1,8 +1,16 @@
 pub(crate) mod accessor;
+pub(crate) mod checks;
+pub(crate) mod color;
+pub(crate) mod file;
+pub(crate) mod format;
 pub(crate) mod initializer;
+pub(crate) mod literal;
 pub(crate) mod parser;
+pub(crate) mod source;
 pub(crate) mod storer;
+pub(crate) mod tuple;
 pub(crate) mod validator;
+pub(crate) mod verbosity;
 
 use std::any::type_name;
 use std::ffi::OsStr;
@@ -18,15 +26,31 @@ use crate::opt::Action;
 use crate::Error;
 
 pub use self::accessor::ValAccessor;
+pub use self::color::ColorChoice;
+pub use self::file::InputFile;
+pub use self::file::OutputFile;
+pub use self::file::OutputMode;
+pub use self::file::OutputWriter;
+pub use self::format::ErrorFormat;
 pub use self::initializer::InitHandler;
 pub use self::initializer::InitializeValue;
 pub use self::initializer::ValInitializer;
+pub use self::literal::BoolLiteral;
 pub use self::parser::raw2str;
 pub use self::parser::RawValParser;
+pub use self::source::ValueSource;
 pub use self::storer::StoreHandler;
 pub use self::storer::ValStorer;
+pub use self::tuple::type_hint;
+pub use self::tuple::ElemParser;
+pub use self::tuple::ElemSeparator;
+pub use self::validator::CheckHandler;
+pub use self::validator::CheckResult;
+pub use self::validator::ValHint;
 pub use self::validator::ValValidator;
 pub use self::validator::ValidatorHandler;
+pub use self::validator::DEFAULT_CHECK_MESSAGE;
+pub use self::verbosity::Verbosity;
 
 pub trait ErasedValue {
     fn initialize(&mut self) -> Result<(), Error>;
@@ -115,6 +139,10 @@ impl AnyValue {
         self.0.clear()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn contain_type<T: ErasedTy>(&self) -> bool {
         self.0.contain::<Vec<T>>()
     }
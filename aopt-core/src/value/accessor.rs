@@ -8,6 +8,7 @@ use super::RawValParser;
 use super::ValInitializer;
 use super::ValStorer;
 use super::ValValidator;
+use super::ValueSource;
 
 use crate::ctx::Ctx;
 use crate::error;
@@ -97,6 +98,8 @@ pub struct ValAccessor {
 
     rawval: Vec<OsString>,
 
+    sources: Vec<ValueSource>,
+
     storer: ValStorer,
 
     initializer: ValInitializer,
@@ -113,6 +116,7 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            sources: vec![],
             storer,
             initializer,
         }
@@ -125,6 +129,7 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            sources: vec![],
             storer: ValStorer::new_validator(validator),
             initializer,
         }
@@ -134,6 +139,7 @@ impl ValAccessor {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
+            sources: vec![],
             storer: ValStorer::fallback::<U>(),
             initializer: ValInitializer::fallback(),
         }
@@ -179,14 +185,57 @@ impl ValAccessor {
         (&mut self.rawval, &mut self.any_value)
     }
 
+    /// The source of last value.
+    pub fn source(&self) -> Option<&ValueSource> {
+        self.sources.last()
+    }
+
+    /// The sources of values, [`ValueSource::Default`] is recorded if the initializer set any value.
+    pub fn sources(&self) -> &[ValueSource] {
+        &self.sources
+    }
+
+    pub fn sources_mut(&mut self) -> &mut Vec<ValueSource> {
+        &mut self.sources
+    }
+
+    /// Record the source of value stored according to the action.
+    pub fn add_source(&mut self, source: ValueSource, act: &Action) -> &mut Self {
+        match act {
+            Action::Set | Action::SetOnce | Action::Toggle => {
+                self.sources = vec![source];
+            }
+            Action::App | Action::Cnt | Action::AppUniq | Action::Ext => {
+                self.sources.push(source);
+            }
+            Action::Pop => {
+                self.sources.pop();
+            }
+            Action::Clr => {
+                self.sources.clear();
+            }
+            Action::Null => {
+                // NOTHING
+            }
+        }
+        self
+    }
+
     /// Parsing the raw value into typed value, save the raw value and result.
-    /// The function will map the failure error to `Ok(false)`.
+    /// The error of parsing is returned, the caller can treat it as a failure.
+    ///
+    /// For [`Action::AppUniq`], the raw value already saved is ignored.
     pub fn store_all(
         &mut self,
         arg: Option<&OsStr>,
         ctx: &Ctx,
         act: &Action,
     ) -> Result<bool, Error> {
+        if let (Action::AppUniq, Some(raw)) = (act, arg) {
+            if self.rawval.iter().any(|v| v == raw) {
+                return Ok(true);
+            }
+        }
         match self.store(arg, ctx, act) {
             Ok(_) => {
                 if let Some(raw) = arg {
@@ -217,7 +266,12 @@ impl ErasedValue for ValAccessor {
     fn initialize(&mut self) -> Result<(), Error> {
         let handler = &mut self.any_value;
 
-        self.initializer.invoke(handler)
+        self.sources.clear();
+        self.initializer.invoke(handler)?;
+        if !self.any_value.is_empty() {
+            self.sources.push(ValueSource::Default);
+        }
+        Ok(())
     }
 
     fn store(&mut self, arg: Option<&OsStr>, ctx: &Ctx, act: &Action) -> Result<(), Error> {
@@ -230,26 +284,7 @@ impl ErasedValue for ValAccessor {
         let handler = &mut self.any_value;
         let value = val;
 
-        match act {
-            Action::Set => {
-                handler.set(vec![value]);
-            }
-            Action::App => {
-                handler.push(value);
-            }
-            Action::Pop => {
-                handler.pop::<U>();
-            }
-            Action::Cnt => {
-                handler.entry::<u64>().or_insert(vec![0])[0] += 1;
-            }
-            Action::Clr => {
-                handler.remove::<U>();
-            }
-            Action::Null => {
-                // NOTHING
-            }
-        }
+        act.store1(Some(value), handler);
         Ok(())
     }
 
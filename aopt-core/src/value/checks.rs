@@ -0,0 +1,280 @@
+use std::path::Path;
+
+use crate::map::ErasedTy;
+use crate::value::ValHint;
+use crate::value::ValValidator;
+use crate::value::DEFAULT_CHECK_MESSAGE;
+
+fn quote_join<S: AsRef<str>>(vals: &[S]) -> String {
+    vals.iter()
+        .map(|v| format!("`{}`", v.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl<T: ErasedTy> ValValidator<T> {
+    /// Create a validator accept the value which `func` return true.
+    /// The reason is `must be {desc}` if the value is rejected.
+    #[cfg(feature = "sync")]
+    pub fn describe(
+        desc: impl Into<String>,
+        func: impl Fn(&T) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let desc = desc.into();
+        let msg = format!("must be {desc}");
+
+        Self::from_fn(move |val| if func(val) { Ok(()) } else { Err(msg.clone()) }).with_desc(desc)
+    }
+
+    /// Create a validator accept the value which `func` return true.
+    /// The reason is `must be {desc}` if the value is rejected.
+    #[cfg(not(feature = "sync"))]
+    pub fn describe(desc: impl Into<String>, func: impl Fn(&T) -> bool + 'static) -> Self {
+        let desc = desc.into();
+        let msg = format!("must be {desc}");
+
+        Self::from_fn(move |val| if func(val) { Ok(()) } else { Err(msg.clone()) }).with_desc(desc)
+    }
+
+    /// Accept the value if both validators accept it.
+    pub fn and(self, other: Self) -> Self {
+        let hint = self.hint().or(other.hint()).cloned();
+        let desc = self
+            .desc()
+            .zip(other.desc())
+            .map(|(a, b)| format!("{a} and {b}"));
+        let mut ret = Self::from_fn(move |val| self.check(val).and_then(|_| other.check(val)));
+
+        if let Some(hint) = hint {
+            ret = ret.with_hint(hint);
+        }
+        if let Some(desc) = desc {
+            ret = ret.with_desc(desc);
+        }
+        ret
+    }
+
+    /// Accept the value if any of the validators accept it.
+    pub fn or(self, other: Self) -> Self {
+        let hint = match (self.hint(), other.hint()) {
+            (Some(ValHint::OneOf(a)), Some(ValHint::OneOf(b))) => {
+                Some(ValHint::OneOf(a.iter().chain(b.iter()).cloned().collect()))
+            }
+            (a, b) if a == b => a.cloned(),
+            _ => None,
+        };
+        let desc = self
+            .desc()
+            .zip(other.desc())
+            .map(|(a, b)| format!("{a} or {b}"));
+        let mut ret = Self::from_fn(move |val| match self.check(val) {
+            Ok(_) => Ok(()),
+            Err(a) => other.check(val).map_err(|b| format!("{a} or {b}")),
+        });
+
+        if let Some(hint) = hint {
+            ret = ret.with_hint(hint);
+        }
+        if let Some(desc) = desc {
+            ret = ret.with_desc(desc);
+        }
+        ret
+    }
+
+    /// Accept the value if the validator reject it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        let msg = self
+            .desc()
+            .map(|desc| format!("must not be {desc}"))
+            .unwrap_or_else(|| DEFAULT_CHECK_MESSAGE.to_owned());
+        let desc = self.desc().map(|desc| format!("not {desc}"));
+        let ret = Self::from_fn(move |val| match self.check(val) {
+            Ok(_) => Err(msg.clone()),
+            Err(_) => Ok(()),
+        });
+
+        match desc {
+            Some(desc) => ret.with_desc(desc),
+            None => ret,
+        }
+    }
+}
+
+/// Validators for the path value, such as [`PathBuf`](std::path::PathBuf) or [`String`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::*;
+/// #
+/// let validator = ValValidator::<String>::is_dir();
+///
+/// assert_eq!(validator.hint(), Some(&ValHint::Dir));
+/// assert!(validator.invoke(&String::from(env!("CARGO_MANIFEST_DIR"))));
+/// assert_eq!(
+///     validator.check(&String::from("Cargo.toml")),
+///     Err(String::from("must be an existing directory"))
+/// );
+///
+/// let validator = ValValidator::<String>::is_file().and(ValValidator::extension(["toml"]));
+///
+/// assert!(validator.invoke(&String::from("Cargo.toml")));
+/// assert_eq!(
+///     validator.check(&String::from("src/lib.rs")),
+///     Err(String::from("must be a path with extension one of `toml`"))
+/// );
+/// assert_eq!(
+///     ValValidator::<String>::exists().not().check(&String::from("Cargo.toml")),
+///     Err(String::from("must not be an existing path"))
+/// );
+/// ```
+impl<T: ErasedTy + AsRef<Path>> ValValidator<T> {
+    /// Accept the path exists.
+    pub fn exists() -> Self {
+        Self::describe("an existing path", |val: &T| val.as_ref().exists()).with_hint(ValHint::Path)
+    }
+
+    /// Accept the path is an existing file.
+    pub fn is_file() -> Self {
+        Self::describe("an existing file", |val: &T| val.as_ref().is_file())
+            .with_hint(ValHint::File)
+    }
+
+    /// Accept the path is an existing directory.
+    pub fn is_dir() -> Self {
+        Self::describe("an existing directory", |val: &T| val.as_ref().is_dir())
+            .with_hint(ValHint::Dir)
+    }
+
+    /// Accept the path can be opened for reading.
+    pub fn readable() -> Self {
+        Self::describe("a readable path", |val: &T| {
+            let path = val.as_ref();
+
+            if path.is_dir() {
+                std::fs::read_dir(path).is_ok()
+            } else {
+                std::fs::File::open(path).is_ok()
+            }
+        })
+        .with_hint(ValHint::Path)
+    }
+
+    /// Accept the path is writable, or it can be created in a writable directory.
+    pub fn writable() -> Self {
+        Self::describe("a writable path", |val: &T| {
+            let path = val.as_ref();
+            let writable = |path: &Path| {
+                std::fs::metadata(path)
+                    .map(|v| !v.permissions().readonly())
+                    .unwrap_or_default()
+            };
+
+            if path.exists() {
+                writable(path)
+            } else {
+                match path.parent() {
+                    Some(parent) if parent.as_os_str().is_empty() => writable(Path::new(".")),
+                    Some(parent) => writable(parent),
+                    None => false,
+                }
+            }
+        })
+        .with_hint(ValHint::Path)
+    }
+
+    /// Accept the path has an extension in `exts`, the compare is case-insensitive.
+    pub fn extension<S: Into<String>>(exts: impl IntoIterator<Item = S>) -> Self {
+        let exts: Vec<String> = exts.into_iter().map(Into::into).collect();
+        let desc = format!("a path with extension one of {}", quote_join(&exts));
+
+        Self::describe(desc, move |val: &T| {
+            val.as_ref()
+                .extension()
+                .and_then(|v| v.to_str())
+                .map(|ext| exts.iter().any(|v| v.eq_ignore_ascii_case(ext)))
+                .unwrap_or_default()
+        })
+        .with_hint(ValHint::File)
+    }
+}
+
+/// Validators for the string value.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::*;
+/// #
+/// let validator = ValValidator::<String>::one_of(["get", "put"]);
+///
+/// assert_eq!(validator.hint(), Some(&ValHint::OneOf(vec!["get".into(), "put".into()])));
+/// assert!(validator.invoke(&String::from("GET")));
+/// assert_eq!(
+///     validator.check(&String::from("post")),
+///     Err(String::from("must be one of `get`, `put`"))
+/// );
+///
+/// let validator = ValValidator::<String>::non_empty().and(ValValidator::len_range(2, 4));
+///
+/// assert!(validator.invoke(&String::from("cote")));
+/// assert_eq!(validator.check(&String::new()), Err(String::from("must be a non-empty value")));
+/// assert_eq!(
+///     validator.check(&String::from("aopt-core")),
+///     Err(String::from("must be a value with length between 2 and 4"))
+/// );
+/// ```
+impl<T: ErasedTy + AsRef<str>> ValValidator<T> {
+    /// Accept the string is not empty.
+    pub fn non_empty() -> Self {
+        Self::describe("a non-empty value", |val: &T| !val.as_ref().is_empty())
+    }
+
+    /// Accept the character count of string is in `min..=max`.
+    pub fn len_range(min: usize, max: usize) -> Self {
+        let desc = format!("a value with length between {min} and {max}");
+
+        Self::describe(desc, move |val: &T| {
+            (min..=max).contains(&val.as_ref().chars().count())
+        })
+    }
+
+    /// Accept the string is one of `vals`, the compare is case-insensitive.
+    pub fn one_of<S: Into<String>>(vals: impl IntoIterator<Item = S>) -> Self {
+        let vals: Vec<String> = vals.into_iter().map(Into::into).collect();
+        let hint = ValHint::OneOf(vals.clone());
+        let desc = format!("one of {}", quote_join(&vals));
+
+        Self::describe(desc, move |val: &T| {
+            vals.iter()
+                .any(|v| v.to_lowercase() == val.as_ref().to_lowercase())
+        })
+        .with_hint(hint)
+    }
+
+    /// Accept the string matched the regex `pattern`.
+    ///
+    /// ```rust
+    /// # use aopt_core::value::*;
+    /// #
+    /// let validator = ValValidator::<String>::regex(r"^\d+$").unwrap();
+    ///
+    /// assert!(validator.invoke(&String::from("42")));
+    /// assert_eq!(
+    ///     validator.check(&String::from("4x2")),
+    ///     Err(String::from(r"must be a value matching `^\d+$`"))
+    /// );
+    /// assert!(ValValidator::<String>::regex(r"(").is_err());
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, crate::Error> {
+        let regex = regex::Regex::new(pattern)
+            .map_err(|e| crate::error!("invalid regex `{pattern}`: {e}"))?;
+        let desc = format!("a value matching `{pattern}`");
+
+        Ok(Self::describe(desc, move |val: &T| {
+            regex.is_match(val.as_ref())
+        }))
+    }
+}
@@ -0,0 +1,160 @@
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::RawValParser;
+use crate::value::ValHint;
+use crate::value::ValStorer;
+use crate::Error;
+
+const CHOICE_NAMES: [&str; 3] = ["auto", "always", "never"];
+
+/// When to use colors in the output, it can be `auto`, `always` or `never`.
+///
+/// The `auto` choice is resolved through environment variables and terminal detection:
+/// a non-empty `NO_COLOR` disable the colors, a `CLICOLOR_FORCE` not equal to `0` enable the colors,
+/// otherwise the colors are used only if the stream is a terminal.
+///
+/// The choice is saved in the option value like other types,
+/// read it from the set when writing help message or error message.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::ColorChoice;
+/// #
+/// assert_eq!("always".parse::<ColorChoice>().unwrap(), ColorChoice::Always);
+/// assert_eq!(ColorChoice::default(), ColorChoice::Auto);
+/// assert_eq!(ColorChoice::Never.to_string(), "never");
+/// assert!(ColorChoice::Always.resolve());
+/// assert!(!ColorChoice::Never.resolve());
+/// assert!(!ColorChoice::Never.resolve_with(true));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Use colors if the environment allows it and the stream is a terminal.
+    #[default]
+    Auto,
+
+    /// Always use colors.
+    Always,
+
+    /// Never use colors.
+    Never,
+}
+
+impl ColorChoice {
+    /// The option name used by convenience registration.
+    pub const NAME: &'static str = "--color";
+
+    /// The accepted names of choice.
+    pub const CHOICES: [&'static str; 3] = CHOICE_NAMES;
+
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    /// Return true if the colors should be used when writing to stdout.
+    pub fn resolve(&self) -> bool {
+        self.resolve_for(&std::io::stdout())
+    }
+
+    /// Return true if the colors should be used when writing to `stream`.
+    pub fn resolve_for<T: IsTerminal>(&self, stream: &T) -> bool {
+        self.resolve_with(stream.is_terminal())
+    }
+
+    /// Return true if the colors should be used when writing to a stream,
+    /// `terminal` tell if the stream is a terminal.
+    pub fn resolve_with(&self, terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+                    false
+                } else if std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+                    true
+                } else {
+                    terminal
+                }
+            }
+        }
+    }
+
+    /// Create a [`ValStorer`] for [`ColorChoice`], the choices are set as hint of value.
+    pub fn storer() -> ValStorer {
+        ValStorer::new(Box::new(
+            |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let choice = ColorChoice::parse(raw, ctx)?;
+
+                act.store1(Some(choice), handler);
+                Ok(())
+            },
+        ))
+        .with_hint(ValHint::OneOf(
+            CHOICE_NAMES.iter().map(|v| v.to_string()).collect(),
+        ))
+    }
+}
+
+impl Display for ColorChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ColorChoice::Auto => CHOICE_NAMES[0],
+            ColorChoice::Always => CHOICE_NAMES[1],
+            ColorChoice::Never => CHOICE_NAMES[2],
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = Error;
+
+    /// Parse the choice from `auto`, `always` or `never`, the compare is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.eq_ignore_ascii_case(CHOICE_NAMES[0]) {
+            Ok(Self::Auto)
+        } else if s.eq_ignore_ascii_case(CHOICE_NAMES[1]) {
+            Ok(Self::Always)
+        } else if s.eq_ignore_ascii_case(CHOICE_NAMES[2]) {
+            Ok(Self::Never)
+        } else {
+            Err(crate::error!(
+                "invalid color choice `{s}`, excepted one of {}",
+                CHOICE_NAMES.join(", ")
+            ))
+        }
+    }
+}
+
+impl RawValParser for ColorChoice {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        let uid = ctx.uid()?;
+
+        raw2str(raw)?.parse::<ColorChoice>().map_err(|_| {
+            Error::sp_rawval(raw, format!("except one of {}", CHOICE_NAMES.join(", ")))
+                .with_uid(uid)
+        })
+    }
+}
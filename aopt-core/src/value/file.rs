@@ -0,0 +1,394 @@
+use std::ffi::OsStr;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Stdout;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::ctx::Ctx;
+use crate::value::RawValParser;
+use crate::Error;
+
+const STDIO: &str = "-";
+
+static TEMP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn path_err(path: &Path, hint: impl Into<String>) -> Error {
+    Error::sp_rawval(Some(path.as_os_str()), hint)
+}
+
+fn parse_path(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Option<PathBuf>, Error> {
+    let uid = ctx.uid()?;
+    let raw =
+        raw.ok_or_else(|| Error::sp_rawval(None, "except a file path or `-`").with_uid(uid))?;
+
+    if raw.is_empty() {
+        Err(Error::sp_rawval(Some(raw), "except a file path or `-`").with_uid(uid))
+    } else if raw == STDIO {
+        Ok(None)
+    } else {
+        Ok(Some(PathBuf::from(raw)))
+    }
+}
+
+/// A file path used as input, `-` means read from stdin.
+///
+/// The file is not opened until [`open`](InputFile::open) is called.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::ctx::*;
+/// # use aopt_core::value::*;
+/// # use aopt_core::Error;
+/// #
+/// # use std::ffi::OsStr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+///
+/// assert!(InputFile::parse(Some(OsStr::new("-")), &ctx)?.is_stdin());
+///
+/// let input = InputFile::parse(Some(OsStr::new("no/such/file.txt")), &ctx)?;
+///
+/// assert_eq!(input.path(), Some(std::path::Path::new("no/such/file.txt")));
+/// assert!(input.open().is_err());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum InputFile {
+    #[default]
+    Stdin,
+
+    Path(PathBuf),
+}
+
+impl InputFile {
+    /// Create an input from path, `-` is mapped to stdin.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        if path.as_os_str() == STDIO {
+            Self::Stdin
+        } else {
+            Self::Path(path)
+        }
+    }
+
+    pub fn is_stdin(&self) -> bool {
+        matches!(self, Self::Stdin)
+    }
+
+    /// Return the path of input, or None if it is stdin.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Stdin => None,
+            Self::Path(path) => Some(path),
+        }
+    }
+
+    /// Open the input for reading.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, Error> {
+        match self {
+            Self::Stdin => Ok(Box::new(std::io::stdin().lock())),
+            Self::Path(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| path_err(path, format!("can not open file: {e}"))),
+        }
+    }
+
+    /// Read all the content of input.
+    pub fn read_to_string(&self) -> Result<String, Error> {
+        let mut buf = String::default();
+        let desp = self
+            .path()
+            .map(|v| v.as_os_str())
+            .unwrap_or(OsStr::new(STDIO));
+
+        self.open()?
+            .read_to_string(&mut buf)
+            .map_err(|e| Error::sp_rawval(Some(desp), format!("can not read input: {e}")))?;
+        Ok(buf)
+    }
+}
+
+impl RawValParser for InputFile {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        Ok(parse_path(raw, ctx)?.map(Self::Path).unwrap_or(Self::Stdin))
+    }
+}
+
+/// How to open the file of [`OutputFile`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OutputMode {
+    /// Create the file if it not exist, and truncate it.
+    #[default]
+    Truncate,
+
+    /// Create the file if it not exist, and append to it.
+    Append,
+
+    /// Create the file, failed if it is already exist.
+    Create,
+}
+
+/// A file path used as output, `-` means write to stdout.
+///
+/// The file is not opened until [`open`](OutputFile::open) is called.
+/// In atomic mode the content is written into a temporary file in the same directory,
+/// which is renamed to the path when [`OutputWriter::commit`] is called.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::ctx::*;
+/// # use aopt_core::value::*;
+/// # use aopt_core::Error;
+/// #
+/// # use std::ffi::OsStr;
+/// # use std::io::Write;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+/// let path = std::env::temp_dir().join(format!("aopt-output-{}.txt", std::process::id()));
+///
+/// assert!(OutputFile::parse(Some(OsStr::new("-")), &ctx)?.is_stdout());
+///
+/// let output = OutputFile::parse(Some(path.as_os_str()), &ctx)?.with_atomic(true);
+/// let mut writer = output.open()?;
+///
+/// writeln!(writer, "first").unwrap();
+/// assert!(!path.exists());
+/// writer.commit()?;
+///
+/// let mut writer = output.with_mode(OutputMode::Append).open()?;
+///
+/// writeln!(writer, "second").unwrap();
+/// writer.commit()?;
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+/// assert!(OutputFile::new(&path).with_mode(OutputMode::Create).open().is_err());
+/// # std::fs::remove_file(&path).unwrap();
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OutputFile {
+    path: Option<PathBuf>,
+
+    mode: OutputMode,
+
+    atomic: bool,
+}
+
+impl OutputFile {
+    /// Create an output from path, `-` is mapped to stdout.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        Self {
+            path: (path.as_os_str() != STDIO).then_some(path),
+            ..Default::default()
+        }
+    }
+
+    pub fn stdout() -> Self {
+        Self::default()
+    }
+
+    pub fn with_mode(mut self, mode: OutputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    pub fn set_mode(&mut self, mode: OutputMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn set_atomic(&mut self, atomic: bool) -> &mut Self {
+        self.atomic = atomic;
+        self
+    }
+
+    pub fn is_stdout(&self) -> bool {
+        self.path.is_none()
+    }
+
+    /// Return the path of output, or None if it is stdout.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn mode(&self) -> OutputMode {
+        self.mode
+    }
+
+    pub fn atomic(&self) -> bool {
+        self.atomic
+    }
+
+    /// The temporary file is named `<name>.<pid>.<count>.tmp`,
+    /// the count make it unique for the writers in same process.
+    fn temp_path(path: &Path) -> PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        let count = TEMP_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        name.push(format!(".{}.{count}.tmp", std::process::id()));
+        path.with_file_name(name)
+    }
+
+    /// Open the output for writing, the mode is ignored for stdout.
+    pub fn open(&self) -> Result<OutputWriter, Error> {
+        let Some(path) = self.path.as_deref() else {
+            return Ok(OutputWriter {
+                inner: Some(Writer::Stdout(std::io::stdout())),
+                atomic: None,
+            });
+        };
+        let open_err =
+            |path: &Path, e: std::io::Error| path_err(path, format!("can not open file: {e}"));
+
+        if self.atomic {
+            if self.mode == OutputMode::Create && path.exists() {
+                return Err(path_err(path, "file already exists"));
+            }
+            let temp = Self::temp_path(path);
+
+            if self.mode == OutputMode::Append && path.exists() {
+                std::fs::copy(path, &temp).map_err(|e| open_err(path, e))?;
+            }
+            let file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .append(self.mode == OutputMode::Append)
+                .truncate(self.mode != OutputMode::Append)
+                .open(&temp)
+                .map_err(|e| open_err(&temp, e))?;
+
+            Ok(OutputWriter {
+                inner: Some(Writer::File(file)),
+                atomic: Some((temp, path.to_path_buf())),
+            })
+        } else {
+            let mut options = OpenOptions::new();
+
+            match self.mode {
+                OutputMode::Truncate => options.write(true).create(true).truncate(true),
+                OutputMode::Append => options.append(true).create(true),
+                OutputMode::Create => options.write(true).create_new(true),
+            };
+            Ok(OutputWriter {
+                inner: Some(Writer::File(
+                    options.open(path).map_err(|e| open_err(path, e))?,
+                )),
+                atomic: None,
+            })
+        }
+    }
+}
+
+impl RawValParser for OutputFile {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            path: parse_path(raw, ctx)?,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Writer {
+    Stdout(Stdout),
+
+    File(File),
+}
+
+/// The writer returned by [`OutputFile::open`].
+///
+/// For atomic output, the content is discarded if the writer dropped before [`commit`](OutputWriter::commit).
+#[derive(Debug)]
+pub struct OutputWriter {
+    inner: Option<Writer>,
+
+    atomic: Option<(PathBuf, PathBuf)>,
+}
+
+impl OutputWriter {
+    fn inner(&mut self) -> std::io::Result<&mut dyn Write> {
+        match self.inner.as_mut() {
+            Some(Writer::Stdout(stdout)) => Ok(stdout),
+            Some(Writer::File(file)) => Ok(file),
+            None => Err(std::io::Error::other("writer already committed")),
+        }
+    }
+
+    /// Flush the content, and rename the temporary file to the path in atomic mode.
+    /// The permissions of existing file are copied to the temporary file before rename.
+    pub fn commit(mut self) -> Result<(), Error> {
+        let desp = self
+            .atomic
+            .as_ref()
+            .map(|(_, path)| path.as_os_str())
+            .unwrap_or(OsStr::new(STDIO))
+            .to_os_string();
+
+        self.flush()
+            .map_err(|e| Error::sp_rawval(Some(&desp), format!("can not flush output: {e}")))?;
+        // close the file before rename
+        self.inner.take();
+        if let Some((temp, path)) = self.atomic.take() {
+            // keep the permissions of the file being replaced
+            if let Ok(meta) = std::fs::metadata(&path) {
+                std::fs::set_permissions(&temp, meta.permissions()).map_err(|e| {
+                    let _ = std::fs::remove_file(&temp);
+                    path_err(&path, format!("can not copy permissions: {e}"))
+                })?;
+            }
+            std::fs::rename(&temp, &path).map_err(|e| {
+                let _ = std::fs::remove_file(&temp);
+                path_err(&path, format!("can not rename temporary file: {e}"))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Write for OutputWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner()?.flush()
+    }
+}
+
+impl Drop for OutputWriter {
+    fn drop(&mut self) {
+        self.inner.take();
+        if let Some((temp, _)) = self.atomic.take() {
+            let _ = std::fs::remove_file(temp);
+        }
+    }
+}
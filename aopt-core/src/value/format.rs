@@ -0,0 +1,117 @@
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::RawValParser;
+use crate::value::ValHint;
+use crate::value::ValStorer;
+use crate::Error;
+
+const FORMAT_NAMES: [&str; 2] = ["text", "json"];
+
+/// The format of error output, it can be `text` or `json`.
+///
+/// The `json` format is a machine-readable form of [`Error`] for tools such as CI wrappers,
+/// it needs the feature `serde` for serialization.
+///
+/// The format is saved in the option value like other types,
+/// read it from the set when writing error message.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::ErrorFormat;
+/// #
+/// assert_eq!("json".parse::<ErrorFormat>().unwrap(), ErrorFormat::Json);
+/// assert_eq!(ErrorFormat::default(), ErrorFormat::Text);
+/// assert_eq!(ErrorFormat::Json.to_string(), "json");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ErrorFormat {
+    /// Human readable message with causes.
+    #[default]
+    Text,
+
+    /// Serialized [`Error`] in JSON.
+    Json,
+}
+
+impl ErrorFormat {
+    /// The option name used by convenience registration.
+    pub const NAME: &'static str = "--error-format";
+
+    /// The accepted names of format.
+    pub const CHOICES: [&'static str; 2] = FORMAT_NAMES;
+
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text)
+    }
+
+    pub fn is_json(&self) -> bool {
+        matches!(self, Self::Json)
+    }
+
+    /// Create a [`ValStorer`] for [`ErrorFormat`], the formats are set as hint of value.
+    pub fn storer() -> ValStorer {
+        ValStorer::new(Box::new(
+            |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let format = ErrorFormat::parse(raw, ctx)?;
+
+                act.store1(Some(format), handler);
+                Ok(())
+            },
+        ))
+        .with_hint(ValHint::OneOf(
+            FORMAT_NAMES.iter().map(|v| v.to_string()).collect(),
+        ))
+    }
+}
+
+impl Display for ErrorFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ErrorFormat::Text => FORMAT_NAMES[0],
+            ErrorFormat::Json => FORMAT_NAMES[1],
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for ErrorFormat {
+    type Err = Error;
+
+    /// Parse the format from `text` or `json`, the compare is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.eq_ignore_ascii_case(FORMAT_NAMES[0]) {
+            Ok(Self::Text)
+        } else if s.eq_ignore_ascii_case(FORMAT_NAMES[1]) {
+            Ok(Self::Json)
+        } else {
+            Err(crate::error!(
+                "invalid error format `{s}`, excepted one of {}",
+                FORMAT_NAMES.join(", ")
+            ))
+        }
+    }
+}
+
+impl RawValParser for ErrorFormat {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        let uid = ctx.uid()?;
+
+        raw2str(raw)?.parse::<ErrorFormat>().map_err(|_| {
+            Error::sp_rawval(raw, format!("except one of {}", FORMAT_NAMES.join(", ")))
+                .with_uid(uid)
+        })
+    }
+}
@@ -1,4 +1,7 @@
+use std::any::Any;
+use std::ffi::OsString;
 use std::fmt::Debug;
+use std::path::PathBuf;
 
 use crate::map::ErasedTy;
 use crate::Error;
@@ -61,43 +64,112 @@ pub use __initializer::InitHandler;
 pub use __initializer::InitializeValue;
 
 /// A type using for initialize the option value.
-pub struct ValInitializer(InitHandler<AnyValue>);
+///
+/// The initializer can carry a display string of default value, it is displayed as
+/// `[default: ..]` in the help message.
+/// The display string is generated by [`new_value`](ValInitializer::new_value) and
+/// [`new_values`](ValInitializer::new_values) if the type of value is primitive type, [`String`],
+/// [`PathBuf`](std::path::PathBuf) or [`OsString`](std::ffi::OsString).
+/// Using [`with_display`](ValInitializer::with_display) set a custom display string or remove it.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::ValInitializer;
+/// #
+/// assert_eq!(ValInitializer::new_value(30).display(), Some("30"));
+/// assert_eq!(
+///     ValInitializer::new_values(vec!["a".to_owned(), "b".to_owned()]).display(),
+///     Some("a, b")
+/// );
+/// assert_eq!(
+///     ValInitializer::new_value(30)
+///         .with_display(Some("30s".to_owned()))
+///         .display(),
+///     Some("30s")
+/// );
+/// assert_eq!(ValInitializer::new_value(30).with_display(None).display(), None);
+/// assert_eq!(ValInitializer::fallback().display(), None);
+/// ```
+pub struct ValInitializer {
+    handler: InitHandler<AnyValue>,
+
+    display: Option<String>,
+}
 
 impl Debug for ValInitializer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("ValInitializer").field(&"{...}").finish()
+        f.debug_struct("ValInitializer")
+            .field("handler", &"{...}")
+            .field("display", &self.display)
+            .finish()
     }
 }
 
 impl ValInitializer {
     pub fn new<U: ErasedTy>(mut init: impl InitializeValue<Vec<U>> + 'static) -> Self {
-        Self(Box::new(move |erased_val| {
+        Self::from_handler(Box::new(move |erased_val| {
             erased_val.set(init.prepare_value().map_err(Into::into)?);
             Ok(())
         }))
     }
 
     pub fn new_value<U: Clone + ErasedTy>(val: U) -> Self {
-        Self(Box::new(move |erased_val| {
+        let display = display_of(&val);
+
+        Self::from_handler(Box::new(move |erased_val| {
             erased_val.set(vec![val.clone()]);
             Ok(())
         }))
+        .with_display(display)
     }
 
     pub fn new_values<U: Clone + ErasedTy>(vals: Vec<U>) -> Self {
-        Self(Box::new(move |erased_val| {
+        let display = vals
+            .iter()
+            .map(display_of)
+            .collect::<Option<Vec<_>>>()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.join(", "));
+
+        Self::from_handler(Box::new(move |erased_val| {
             erased_val.set(vals.clone());
             Ok(())
         }))
+        .with_display(display)
     }
 
     /// Default value initializer, do nothing.
     pub fn fallback() -> Self {
-        Self(Box::new(|_| Ok(())))
+        Self::from_handler(Box::new(|_| Ok(())))
+    }
+
+    fn from_handler(handler: InitHandler<AnyValue>) -> Self {
+        Self {
+            handler,
+            display: None,
+        }
+    }
+
+    /// Set the display string of default value, `None` means not display it.
+    pub fn with_display(mut self, display: Option<String>) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Set the display string of default value, `None` means not display it.
+    pub fn set_display(&mut self, display: Option<String>) -> &mut Self {
+        self.display = display;
+        self
+    }
+
+    /// The display string of default value.
+    pub fn display(&self) -> Option<&str> {
+        self.display.as_deref()
     }
 
     pub fn invoke(&mut self, arg: &mut AnyValue) -> Result<(), Error> {
-        (self.0)(arg)
+        (self.handler)(arg)
     }
 
     /// Get the default value.
@@ -108,10 +180,53 @@ impl ValInitializer {
     }
 }
 
+/// Return the display string of value if it is a type known to be displayable.
+fn display_of<U: ErasedTy>(val: &U) -> Option<String> {
+    let val = val as &dyn Any;
+
+    macro_rules! display_as {
+        ($($ty:ty),+) => {
+            $(
+                if let Some(val) = val.downcast_ref::<$ty>() {
+                    return Some(val.to_string());
+                }
+            )+
+        };
+    }
+
+    display_as!(
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        f32,
+        f64,
+        bool,
+        char,
+        String,
+        &'static str
+    );
+    if let Some(val) = val.downcast_ref::<PathBuf>() {
+        return Some(val.display().to_string());
+    }
+    if let Some(val) = val.downcast_ref::<OsString>() {
+        return Some(val.to_string_lossy().to_string());
+    }
+    None
+}
+
 #[cfg(not(feature = "sync"))]
 impl<T: FnMut(&mut AnyValue) -> Result<(), Error> + 'static> From<T> for ValInitializer {
     fn from(value: T) -> Self {
-        Self(Box::new(value))
+        Self::from_handler(Box::new(value))
     }
 }
 
@@ -120,6 +235,6 @@ impl<T: FnMut(&mut AnyValue) -> Result<(), Error> + Send + Sync + 'static> From<
     for ValInitializer
 {
     fn from(value: T) -> Self {
-        Self(Box::new(value))
+        Self::from_handler(Box::new(value))
     }
 }
@@ -0,0 +1,127 @@
+use std::sync::OnceLock;
+
+use crate::opt::BOOL_FALSE;
+use crate::opt::BOOL_TRUE;
+
+/// The words accepted when parsing a [`bool`] from string, the compare is case-insensitive.
+///
+/// In default it accept `true`/`false`, `yes`/`no`, `on`/`off` and `1`/`0`.
+/// The literal of [`Ctx`](crate::ctx::Ctx) is used by the [`RawValParser`](crate::value::RawValParser)
+/// implementation of [`bool`], the parser copies it from the option set before parsing.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::ctx::*;
+/// # use aopt_core::value::*;
+/// # use aopt_core::Error;
+/// #
+/// # use std::ffi::OsStr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+/// let literal = BoolLiteral::default();
+///
+/// assert_eq!(literal.parse("Yes"), Some(true));
+/// assert_eq!(literal.parse("OFF"), Some(false));
+/// assert_eq!(literal.parse("enable"), None);
+///
+/// assert_eq!(bool::parse(Some(OsStr::new("on")), &ctx)?, true);
+/// assert!(bool::parse(Some(OsStr::new("enable")), &ctx).is_err());
+///
+/// let ctx = ctx.with_bool_literal(Some(
+///     BoolLiteral::default()
+///         .with_true("enable")
+///         .with_false("disable"),
+/// ));
+///
+/// assert_eq!(bool::parse(Some(OsStr::new("Enable")), &ctx)?, true);
+/// assert_eq!(bool::parse(Some(OsStr::new("disable")), &ctx)?, false);
+/// assert_eq!(bool::parse(Some(OsStr::new("1")), &ctx)?, true);
+/// assert!(bool::parse(Some(OsStr::new("maybe")), &ctx).is_err());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolLiteral {
+    trues: Vec<String>,
+
+    falses: Vec<String>,
+}
+
+impl Default for BoolLiteral {
+    fn default() -> Self {
+        Self::new(
+            vec![BOOL_TRUE, "yes", "on", "1"],
+            vec![BOOL_FALSE, "no", "off", "0"],
+        )
+    }
+}
+
+impl BoolLiteral {
+    /// Return the default literal, it is built once and shared by all the parsers.
+    pub fn default_ref() -> &'static Self {
+        static DEFAULT: OnceLock<BoolLiteral> = OnceLock::new();
+
+        DEFAULT.get_or_init(Self::default)
+    }
+
+    pub fn new(trues: Vec<impl Into<String>>, falses: Vec<impl Into<String>>) -> Self {
+        Self {
+            trues: trues.into_iter().map(Into::into).collect(),
+            falses: falses.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn with_true(mut self, word: impl Into<String>) -> Self {
+        self.add_true(word);
+        self
+    }
+
+    pub fn with_false(mut self, word: impl Into<String>) -> Self {
+        self.add_false(word);
+        self
+    }
+
+    pub fn add_true(&mut self, word: impl Into<String>) -> &mut Self {
+        self.trues.push(word.into());
+        self
+    }
+
+    pub fn add_false(&mut self, word: impl Into<String>) -> &mut Self {
+        self.falses.push(word.into());
+        self
+    }
+
+    /// The words map to `true`.
+    pub fn trues(&self) -> &[String] {
+        &self.trues
+    }
+
+    /// The words map to `false`.
+    pub fn falses(&self) -> &[String] {
+        &self.falses
+    }
+
+    /// Iterate over all the accepted words, the words map to `true` first.
+    pub fn literals(&self) -> impl Iterator<Item = &str> {
+        self.trues
+            .iter()
+            .chain(self.falses.iter())
+            .map(String::as_str)
+    }
+
+    /// Return the value of `val`, or None if it is not an accepted word.
+    pub fn parse(&self, val: &str) -> Option<bool> {
+        let mat = |word: &String| word.eq_ignore_ascii_case(val);
+
+        if self.trues.iter().any(mat) {
+            Some(true)
+        } else if self.falses.iter().any(mat) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
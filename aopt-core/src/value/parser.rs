@@ -4,6 +4,7 @@ use std::io::Stdin;
 use std::path::PathBuf;
 
 use crate::ctx::Ctx;
+use crate::value::BoolLiteral;
 use crate::Error;
 
 /// Implement this if you want parsing the raw value into your type.
@@ -96,10 +97,22 @@ impl RawValParser for bool {
     fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
         let val = raw2str(raw)?;
 
+        // the value passed by Boolean style is always accepted
         match val {
-            crate::opt::BOOL_TRUE => Ok(true),
-            crate::opt::BOOL_FALSE => Ok(false),
-            _ => Err(Error::sp_rawval(raw, "except true or false").with_uid(ctx.uid()?)),
+            crate::opt::BOOL_TRUE => return Ok(true),
+            crate::opt::BOOL_FALSE => return Ok(false),
+            _ => {}
+        }
+
+        let literal = ctx.bool_literal().unwrap_or(BoolLiteral::default_ref());
+
+        match literal.parse(val) {
+            Some(val) => Ok(val),
+            None => {
+                let words = literal.literals().collect::<Vec<_>>().join(", ");
+
+                Err(Error::sp_rawval(raw, format!("except one of {words}")).with_uid(ctx.uid()?))
+            }
         }
     }
 }
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+/// Where the value of option come from.
+///
+/// The policies record the source for each value stored in [`ValAccessor`](crate::value::ValAccessor),
+/// the handler can get the source of current value from [`Ctx::source`](crate::ctx::Ctx::source).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::ValueSource;
+/// #
+/// assert_eq!(ValueSource::Default.to_string(), "default");
+/// assert_eq!(ValueSource::CommandLine { index: 2 }.to_string(), "command line @ 2");
+/// assert_eq!(
+///     ValueSource::Env { name: "JOBS".to_owned() }.to_string(),
+///     "env `JOBS`"
+/// );
+/// assert!(ValueSource::CommandLine { index: 1 }.is_cmdline());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValueSource {
+    /// The value is set by [`ValInitializer`](crate::value::ValInitializer).
+    Default,
+
+    /// The value is read from environment variable `name`.
+    Env { name: String },
+
+    /// The value is read from `key` of configuration `origin`.
+    Config { origin: String, key: String },
+
+    /// The value is passed in command line, `index` is the index of argument.
+    /// For positionals and commands it is the index of NOA arguments.
+    CommandLine { index: usize },
+}
+
+impl ValueSource {
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Default)
+    }
+
+    pub fn is_env(&self) -> bool {
+        matches!(self, Self::Env { .. })
+    }
+
+    pub fn is_config(&self) -> bool {
+        matches!(self, Self::Config { .. })
+    }
+
+    pub fn is_cmdline(&self) -> bool {
+        matches!(self, Self::CommandLine { .. })
+    }
+}
+
+impl Display for ValueSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueSource::Default => write!(f, "default"),
+            ValueSource::Env { name } => write!(f, "env `{name}`"),
+            ValueSource::Config { origin, key } => {
+                write!(f, "configuration `{origin}` key `{key}`")
+            }
+            ValueSource::CommandLine { index } => write!(f, "command line @ {index}"),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::Error;
 
 use super::AnyValue;
 use super::RawValParser;
+use super::ValHint;
 use super::ValValidator;
 
 #[cfg(feature = "sync")]
@@ -20,28 +21,55 @@ pub type StoreHandler<T> =
     Box<dyn FnMut(Option<&OsStr>, &Ctx, &Action, &mut T) -> Result<(), Error>>;
 
 /// [`ValStorer`] perform the value storing action.
-pub struct ValStorer(StoreHandler<AnyValue>);
+pub struct ValStorer {
+    handler: StoreHandler<AnyValue>,
+
+    hint: Option<ValHint>,
+}
 
 impl Debug for ValStorer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("WriterHandler").field(&"{...}").finish()
+        f.debug_struct("ValStorer")
+            .field("handler", &"{...}")
+            .field("hint", &self.hint)
+            .finish()
     }
 }
 
 impl ValStorer {
     pub fn new(handler: StoreHandler<AnyValue>) -> Self {
-        Self(handler)
+        Self {
+            handler,
+            hint: None,
+        }
     }
 
     pub fn fallback<U: ErasedTy + RawValParser>() -> Self {
-        Self(Self::fallback_handler::<U>())
+        Self::new(Self::fallback_handler::<U>())
+    }
+
+    /// Set the hint of value, it is used in help message and completion.
+    pub fn with_hint(mut self, hint: ValHint) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// The hint of value accepted by storer, it is copied from the [`ValValidator`].
+    pub fn hint(&self) -> Option<&ValHint> {
+        self.hint.as_ref()
     }
 
     /// Create a [`ValStorer`] with a value validator.
     /// The [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure)
-    /// if value check failed.
+    /// with the reason returned by [`check`](ValValidator::check) if value check failed.
     pub fn new_validator<U: ErasedTy + RawValParser>(validator: ValValidator<U>) -> Self {
-        Self(Self::validator(validator))
+        let hint = validator.hint().cloned();
+        let storer = Self::new(Self::validator(validator));
+
+        match hint {
+            Some(hint) => storer.with_hint(hint),
+            None => storer,
+        }
     }
 
     /// Invoke the inner value store handler on [`AnyValue`].
@@ -53,7 +81,7 @@ impl ValStorer {
         arg: &mut AnyValue,
     ) -> Result<(), Error> {
         crate::trace!("saving raw value({:?}) for {}", raw, ctx.uid()?);
-        (self.0)(raw, ctx, act, arg)
+        (self.handler)(raw, ctx, act, arg)
     }
 
     pub fn validator<U: ErasedTy + RawValParser>(
@@ -63,7 +91,7 @@ impl ValStorer {
             move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
                 let val = U::parse(raw, ctx).map_err(Into::into)?;
 
-                if !validator.invoke(&val) {
+                if let Err(msg) = validator.check(&val) {
                     let uid = ctx.uid()?;
 
                     trace!(
@@ -71,10 +99,9 @@ impl ValStorer {
                         raw,
                         val
                     );
-                    Err(
-                        crate::failure!("value check failed: `{:?}`", ctx.inner_ctx().ok(),)
-                            .with_uid(uid),
-                    )
+                    let raw = raw.map(|v| v.to_string_lossy()).unwrap_or_default();
+
+                    Err(crate::failure!("invalid value `{raw}`: {msg}").with_uid(uid))
                 } else {
                     trace!(
                         "validator value storer okay, parsing {:?} -> {:?}",
@@ -0,0 +1,250 @@
+use std::ffi::OsStr;
+
+use crate::ctx::Ctx;
+use crate::map::ErasedTy;
+use crate::opt::Action;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::RawValParser;
+use crate::value::ValStorer;
+use crate::Error;
+
+/// The separators used when parsing tuple or array, such as `800x600` or `1,2,3`.
+///
+/// In default it only accept `,`, add other separators such as `x` for the option if needed.
+/// The first separator which split the value into exactly the number of elements is used.
+/// The [`RawValParser`] implementation of tuple and array using the default separator,
+/// create a [`storer`](ElemSeparator::storer) for the option using other separators.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::ctx::*;
+/// # use aopt_core::value::*;
+/// # use aopt_core::Error;
+/// #
+/// # use std::ffi::OsStr;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let ctx = Ctx::default().with_inner_ctx(InnerCtx::default());
+///
+/// assert_eq!(<(u32, u32)>::parse(Some(OsStr::new("800,600")), &ctx)?, (800, 600));
+/// assert!(<(u32, u32)>::parse(Some(OsStr::new("800x600")), &ctx).is_err());
+/// assert_eq!(
+///     <(String, String)>::parse(Some(OsStr::new("xml,box.txt")), &ctx)?,
+///     (String::from("xml"), String::from("box.txt"))
+/// );
+/// assert_eq!(<[i64; 3]>::parse(Some(OsStr::new("1,2,-3")), &ctx)?, [1, 2, -3]);
+/// assert_eq!(
+///     <(String, bool)>::parse(Some(OsStr::new("debug,yes")), &ctx)?,
+///     (String::from("debug"), true)
+/// );
+///
+/// let err = <(u32, u32)>::parse(Some(OsStr::new("800,abc")), &ctx).unwrap_err();
+///
+/// assert!(err.to_string().contains("element 2 `abc`"));
+///
+/// let sep = ElemSeparator::from(':');
+///
+/// assert_eq!(<(u32, u32)>::parse_elems(Some(OsStr::new("8:6")), &ctx, &sep)?, (8, 6));
+/// assert!(<(u32, u32)>::parse_elems(Some(OsStr::new("8x6")), &ctx, &sep).is_err());
+/// assert_eq!(sep.join(&["INT", "INT"]), "<INT>:<INT>");
+///
+/// let sep = ElemSeparator::default().with_sep('x');
+///
+/// assert_eq!(<(u32, u32)>::parse_elems(Some(OsStr::new("800x600")), &ctx, &sep)?, (800, 600));
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElemSeparator {
+    seps: Vec<char>,
+}
+
+impl Default for ElemSeparator {
+    fn default() -> Self {
+        Self::new(vec![','])
+    }
+}
+
+impl ElemSeparator {
+    pub fn new(seps: Vec<char>) -> Self {
+        Self { seps }
+    }
+
+    pub fn with_sep(mut self, sep: char) -> Self {
+        self.add_sep(sep);
+        self
+    }
+
+    pub fn add_sep(&mut self, sep: char) -> &mut Self {
+        self.seps.push(sep);
+        self
+    }
+
+    pub fn seps(&self) -> &[char] {
+        &self.seps
+    }
+
+    /// Split the `val` into `n` elements, return None if no separator matched.
+    pub fn split<'a>(&self, val: &'a str, n: usize) -> Option<Vec<&'a str>> {
+        if n == 1 {
+            return Some(vec![val]);
+        }
+        self.seps
+            .iter()
+            .find(|sep| val.split(**sep).count() == n)
+            .map(|sep| val.split(*sep).collect())
+    }
+
+    /// Join the hints of elements with the first separator, such as `<INT>,<INT>`.
+    pub fn join(&self, hints: &[impl AsRef<str>]) -> String {
+        let sep = self.seps.first().map(char::to_string).unwrap_or_default();
+
+        hints
+            .iter()
+            .map(|v| format!("<{}>", v.as_ref()))
+            .collect::<Vec<_>>()
+            .join(&sep)
+    }
+
+    /// Create a [`ValStorer`] parsing the value of tuple or array with current separator.
+    pub fn storer<U: ErasedTy + ElemParser>(self) -> ValStorer {
+        ValStorer::new(Box::new(
+            move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                let val = U::parse_elems(raw, ctx, &self)?;
+
+                act.store1(Some(val), handler);
+                Ok(())
+            },
+        ))
+    }
+}
+
+impl From<char> for ElemSeparator {
+    fn from(sep: char) -> Self {
+        Self::new(vec![sep])
+    }
+}
+
+impl<const N: usize> From<[char; N]> for ElemSeparator {
+    fn from(seps: [char; N]) -> Self {
+        Self::new(seps.to_vec())
+    }
+}
+
+impl From<Vec<char>> for ElemSeparator {
+    fn from(seps: Vec<char>) -> Self {
+        Self::new(seps)
+    }
+}
+
+/// Parse the value of tuple or array from elements split by given [`ElemSeparator`].
+pub trait ElemParser: Sized {
+    fn parse_elems(raw: Option<&OsStr>, ctx: &Ctx, sep: &ElemSeparator) -> Result<Self, Error>;
+}
+
+/// Return the hint of value type `T` used in help message, such as `INT` or `PATH`.
+pub fn type_hint<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    let name = name.rsplit("::").next().unwrap_or(name);
+
+    match name {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => "INT".to_owned(),
+        "f32" | "f64" => "FLOAT".to_owned(),
+        "String" | "OsString" | "str" => "STR".to_owned(),
+        "PathBuf" | "Path" => "PATH".to_owned(),
+        name => name.to_uppercase(),
+    }
+}
+
+fn split_elems<'a>(
+    raw: Option<&'a OsStr>,
+    n: usize,
+    ctx: &Ctx,
+    separator: &ElemSeparator,
+) -> Result<Vec<&'a str>, Error> {
+    let uid = ctx.uid()?;
+
+    separator.split(raw2str(raw)?, n).ok_or_else(|| {
+        let seps = separator
+            .seps()
+            .iter()
+            .map(|v| format!("`{v}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Error::sp_rawval(
+            raw,
+            format!("except {n} elements separated by one of {seps}"),
+        )
+        .with_uid(uid)
+    })
+}
+
+fn parse_elem<T: RawValParser>(
+    raw: Option<&OsStr>,
+    (idx, elem): (usize, &str),
+    ctx: &Ctx,
+) -> Result<T, Error> {
+    let uid = ctx.uid()?;
+
+    T::parse(Some(OsStr::new(elem)), ctx).map_err(|e| {
+        let hint = type_hint::<T>();
+
+        Error::sp_rawval(
+            raw,
+            format!("can not parse element {} `{elem}` as {hint}", idx + 1),
+        )
+        .with_uid(uid)
+        .cause_by(e.into())
+    })
+}
+
+macro_rules! impl_tuple_parser {
+    ($n:literal, $($ty:ident),+) => {
+        impl<$($ty: RawValParser),+> ElemParser for ($($ty,)+) {
+            fn parse_elems(raw: Option<&OsStr>, ctx: &Ctx, sep: &ElemSeparator) -> Result<Self, Error> {
+                let mut elems = split_elems(raw, $n, ctx, sep)?.into_iter().enumerate();
+
+                Ok(($(parse_elem::<$ty>(raw, elems.next().unwrap(), ctx)?,)+))
+            }
+        }
+
+        impl<$($ty: RawValParser),+> RawValParser for ($($ty,)+) {
+            type Error = Error;
+
+            fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+                Self::parse_elems(raw, ctx, &ElemSeparator::default())
+            }
+        }
+    };
+}
+
+impl_tuple_parser!(2, T1, T2);
+impl_tuple_parser!(3, T1, T2, T3);
+impl_tuple_parser!(4, T1, T2, T3, T4);
+
+impl<T: RawValParser, const N: usize> ElemParser for [T; N] {
+    fn parse_elems(raw: Option<&OsStr>, ctx: &Ctx, sep: &ElemSeparator) -> Result<Self, Error> {
+        let vals = split_elems(raw, N, ctx, sep)?
+            .into_iter()
+            .enumerate()
+            .map(|elem| parse_elem::<T>(raw, elem, ctx))
+            .collect::<Result<Vec<T>, Error>>()?;
+
+        vals.try_into()
+            .map_err(|_| Error::sp_rawval(raw, format!("except {N} elements")))
+    }
+}
+
+impl<T: RawValParser, const N: usize> RawValParser for [T; N] {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        Self::parse_elems(raw, ctx, &ElemSeparator::default())
+    }
+}
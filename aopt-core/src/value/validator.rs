@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use crate::map::ErasedTy;
 
 #[cfg(feature = "sync")]
@@ -6,41 +8,177 @@ pub type ValidatorHandler<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
 #[cfg(not(feature = "sync"))]
 pub type ValidatorHandler<T> = Box<dyn Fn(&T) -> bool>;
 
-pub struct ValValidator<T>(ValidatorHandler<T>);
+#[cfg(feature = "sync")]
+pub type CheckHandler<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type CheckHandler<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+/// The message used when validator not provide the reason.
+pub const DEFAULT_CHECK_MESSAGE: &str = "value check failed";
+
+/// The return value of validator function, can be a [`bool`] or a [`Result`] with reason.
+pub trait CheckResult {
+    fn into_result(self) -> Result<(), String>;
+}
+
+impl CheckResult for bool {
+    fn into_result(self) -> Result<(), String> {
+        self.then_some(())
+            .ok_or_else(|| DEFAULT_CHECK_MESSAGE.to_owned())
+    }
+}
+
+impl<E: Display> CheckResult for Result<(), E> {
+    fn into_result(self) -> Result<(), String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// The hint of value accepted by [`ValValidator`], used in help message and completion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValHint {
+    /// An existing path, complete with file paths.
+    Path,
+
+    /// An existing file, complete with file paths.
+    File,
+
+    /// An existing directory, complete with directory paths.
+    Dir,
+
+    /// One of the values, complete with the values.
+    OneOf(Vec<String>),
+
+    /// A custom hint, such as `<PORT>`.
+    Other(String),
+}
+
+impl Display for ValHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValHint::Path => write!(f, "<PATH>"),
+            ValHint::File => write!(f, "<FILE>"),
+            ValHint::Dir => write!(f, "<DIR>"),
+            ValHint::OneOf(vals) => write!(f, "<{}>", vals.join("|")),
+            ValHint::Other(hint) => write!(f, "{hint}"),
+        }
+    }
+}
+
+/// Check the value of option, return the reason if the value is rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::*;
+/// #
+/// let validator = ValValidator::from_fn(|val: &i64| {
+///     if (5..=30).contains(val) {
+///         Ok(())
+///     } else {
+///         Err("must be between 5 and 30")
+///     }
+/// });
+///
+/// assert!(validator.invoke(&5));
+/// assert_eq!(validator.check(&42), Err(String::from("must be between 5 and 30")));
+///
+/// let validator = ValValidator::range_from(5).with_msg("must be greater than or equal to 5");
+///
+/// assert_eq!(validator.check(&6), Ok(()));
+/// assert_eq!(
+///     validator.check(&4),
+///     Err(String::from("must be greater than or equal to 5"))
+/// );
+/// ```
+pub struct ValValidator<T> {
+    handler: CheckHandler<T>,
+
+    hint: Option<ValHint>,
+
+    desc: Option<String>,
+}
 
 impl<T> std::fmt::Debug for ValValidator<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("ValValidator").field(&"{...}").finish()
+        f.debug_struct("ValValidator")
+            .field("handler", &"{...}")
+            .field("hint", &self.hint)
+            .field("desc", &self.desc)
+            .finish()
     }
 }
 
 impl<T: ErasedTy> ValValidator<T> {
     pub fn new(handler: ValidatorHandler<T>) -> Self {
-        Self(handler)
+        Self::new_check(Box::new(move |val| handler(val).into_result()))
+    }
+
+    pub fn new_check(handler: CheckHandler<T>) -> Self {
+        Self {
+            handler,
+            hint: None,
+            desc: None,
+        }
     }
 
     pub fn invoke(&self, val: &T) -> bool {
-        (self.0)(val)
+        self.check(val).is_ok()
+    }
+
+    /// Check the value, return the reason if the value is rejected.
+    pub fn check(&self, val: &T) -> Result<(), String> {
+        (self.handler)(val)
+    }
+
+    /// Replace the reason when the value is rejected.
+    pub fn with_msg(mut self, msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        let handler = self.handler;
+
+        self.handler = Box::new(move |val| handler(val).map_err(|_| msg.clone()));
+        self
+    }
+
+    /// Set the hint of value accepted by validator.
+    pub fn with_hint(mut self, hint: ValHint) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Set the description of value accepted by validator, such as `an existing file`.
+    pub fn with_desc(mut self, desc: impl Into<String>) -> Self {
+        self.desc = Some(desc.into());
+        self
+    }
+
+    pub fn hint(&self) -> Option<&ValHint> {
+        self.hint.as_ref()
+    }
+
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_deref()
     }
 
     #[cfg(feature = "sync")]
-    pub fn from_fn(func: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+    pub fn from_fn<R: CheckResult>(func: impl Fn(&T) -> R + Send + Sync + 'static) -> Self {
+        Self::new_check(Box::new(move |val| func(val).into_result()))
     }
 
     #[cfg(not(feature = "sync"))]
-    pub fn from_fn(func: impl Fn(&T) -> bool + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+    pub fn from_fn<R: CheckResult>(func: impl Fn(&T) -> R + 'static) -> Self {
+        Self::new_check(Box::new(move |val| func(val).into_result()))
     }
 }
 
 impl<T: ErasedTy + PartialEq> ValValidator<T> {
     pub fn equal(val: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val == &val))
+        Self::from_fn(move |inner_val| inner_val == &val)
     }
 
     pub fn contains(vals: Vec<T>) -> Self {
-        Self(Box::new(move |inner_val| vals.contains(inner_val)))
+        Self::from_fn(move |inner_val| vals.contains(inner_val))
     }
 }
 
@@ -49,31 +187,27 @@ impl<T: ErasedTy> ValValidator<T> {
     where
         K: ErasedTy + PartialEq<T>,
     {
-        Self(Box::new(move |inner_val| &val == inner_val))
+        Self::from_fn(move |inner_val| &val == inner_val)
     }
 
     pub fn contains2<K>(vals: Vec<K>) -> Self
     where
         K: ErasedTy + PartialEq<T>,
     {
-        Self(Box::new(move |inner_val| {
-            vals.iter().any(|v| PartialEq::eq(v, inner_val))
-        }))
+        Self::from_fn(move |inner_val| vals.iter().any(|v| PartialEq::eq(v, inner_val)))
     }
 }
 
 impl<T: ErasedTy + PartialOrd> ValValidator<T> {
     pub fn range_full(start: T, end: T) -> Self {
-        Self(Box::new(move |inner_val| {
-            inner_val >= &start && inner_val <= &end
-        }))
+        Self::from_fn(move |inner_val| inner_val >= &start && inner_val <= &end)
     }
 
     pub fn range_from(start: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val >= &start))
+        Self::from_fn(move |inner_val| inner_val >= &start)
     }
 
     pub fn range_to(end: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val <= &end))
+        Self::from_fn(move |inner_val| inner_val <= &end)
     }
 }
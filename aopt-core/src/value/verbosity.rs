@@ -0,0 +1,246 @@
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::opt::BOOL_FALSE;
+use crate::opt::BOOL_TRUE;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::RawValParser;
+use crate::value::ValStorer;
+use crate::Error;
+
+const LEVEL_NAMES: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+/// The verbosity level controlled by a pair of increase and decrease options, such as `-v` and `-q`.
+///
+/// The level is `default + verbose - quiet`, clamped to `0..=5`,
+/// the levels are `off`, `error`, `warn`, `info`, `debug` and `trace`.
+/// With feature `log`, it can be converted to `tracing::Level` and `tracing::level_filters::LevelFilter`.
+///
+/// The increase option such as `-v` using the [`storer`](Verbosity::storer),
+/// the decrease option such as `-q` is a separate option, its handler
+/// call [`update`](Verbosity::update) on the value of increase option.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt_core::value::Verbosity;
+/// #
+/// let mut verbosity = Verbosity::new(Verbosity::INFO);
+///
+/// verbosity.increase();
+/// assert_eq!(verbosity.level(), Verbosity::DEBUG);
+/// assert_eq!(verbosity.to_string(), "debug");
+///
+/// verbosity.decrease();
+/// verbosity.decrease();
+/// assert_eq!(verbosity.level(), Verbosity::WARN);
+/// assert_eq!("trace".parse::<Verbosity>().unwrap().level(), Verbosity::TRACE);
+///
+/// #[cfg(feature = "log")]
+/// assert_eq!(verbosity.tracing_level(), Some(tracing::Level::WARN));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Verbosity {
+    default: u8,
+
+    verbose: u8,
+
+    quiet: u8,
+}
+
+impl Default for Verbosity {
+    fn default() -> Self {
+        Self::new(Self::WARN)
+    }
+}
+
+impl Verbosity {
+    pub const OFF: u8 = 0;
+
+    pub const ERROR: u8 = 1;
+
+    pub const WARN: u8 = 2;
+
+    pub const INFO: u8 = 3;
+
+    pub const DEBUG: u8 = 4;
+
+    pub const TRACE: u8 = 5;
+
+    pub fn new(default: u8) -> Self {
+        Self {
+            default: default.min(Self::TRACE),
+            verbose: 0,
+            quiet: 0,
+        }
+    }
+
+    pub fn with_verbose(mut self, verbose: u8) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn with_quiet(mut self, quiet: u8) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// The default level when no option set.
+    pub fn default_level(&self) -> u8 {
+        self.default
+    }
+
+    /// The count of increase option.
+    pub fn verbose(&self) -> u8 {
+        self.verbose
+    }
+
+    /// The count of decrease option.
+    pub fn quiet(&self) -> u8 {
+        self.quiet
+    }
+
+    pub fn increase(&mut self) -> &mut Self {
+        self.verbose = self.verbose.saturating_add(1);
+        self
+    }
+
+    pub fn decrease(&mut self) -> &mut Self {
+        self.quiet = self.quiet.saturating_add(1);
+        self
+    }
+
+    /// Return the current level in `0..=5`.
+    pub fn level(&self) -> u8 {
+        let level = self.default as i16 + self.verbose as i16 - self.quiet as i16;
+
+        level.clamp(Self::OFF as i16, Self::TRACE as i16) as u8
+    }
+
+    /// Return the name of current level, such as `warn`.
+    pub fn level_name(&self) -> &'static str {
+        LEVEL_NAMES[self.level() as usize]
+    }
+
+    pub fn is_off(&self) -> bool {
+        self.level() == Self::OFF
+    }
+
+    /// Create a [`ValStorer`] for [`Verbosity`], each occurrence increase the level.
+    /// A level name such as `debug` set the default level, it is useful for
+    /// environment variable and configuration.
+    pub fn storer() -> ValStorer {
+        ValStorer::new(Box::new(
+            |raw: Option<&OsStr>, _: &Ctx, _: &Action, handler: &mut AnyValue| {
+                let level = raw
+                    .and_then(|v| v.to_str())
+                    .filter(|v| *v != BOOL_TRUE && *v != BOOL_FALSE)
+                    .map(|v| v.parse::<Verbosity>())
+                    .transpose()?;
+
+                if let Some(level) = level {
+                    handler.set(vec![level]);
+                } else {
+                    Self::update(handler, false);
+                }
+                Ok(())
+            },
+        ))
+    }
+
+    /// Increase or decrease the last [`Verbosity`] saved in `handler`,
+    /// a default [`Verbosity`] is saved first if no value.
+    pub fn update(handler: &mut AnyValue, decrease: bool) {
+        let vals = handler.entry::<Verbosity>().or_default();
+
+        if vals.is_empty() {
+            vals.push(Verbosity::default());
+        }
+        if let Some(last) = vals.last_mut() {
+            if decrease {
+                last.decrease();
+            } else {
+                last.increase();
+            }
+        }
+        crate::trace!("update verbosity, decrease = {decrease}: {vals:?}");
+    }
+
+    /// Return the [`tracing::Level`] of current level, or None if it is `off`.
+    #[cfg(feature = "log")]
+    pub fn tracing_level(&self) -> Option<tracing::Level> {
+        match self.level() {
+            Self::OFF => None,
+            Self::ERROR => Some(tracing::Level::ERROR),
+            Self::WARN => Some(tracing::Level::WARN),
+            Self::INFO => Some(tracing::Level::INFO),
+            Self::DEBUG => Some(tracing::Level::DEBUG),
+            _ => Some(tracing::Level::TRACE),
+        }
+    }
+
+    /// Return the [`LevelFilter`](tracing::level_filters::LevelFilter) of current level.
+    #[cfg(feature = "log")]
+    pub fn level_filter(&self) -> tracing::level_filters::LevelFilter {
+        tracing::level_filters::LevelFilter::from(self.tracing_level())
+    }
+}
+
+impl Display for Verbosity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.level_name())
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = Error;
+
+    /// Parse the level from name or number, such as `info` or `3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        LEVEL_NAMES
+            .iter()
+            .position(|v| v.eq_ignore_ascii_case(s))
+            .map(|v| v as u8)
+            .or_else(|| s.parse::<u8>().ok().filter(|v| *v <= Self::TRACE))
+            .map(Self::new)
+            .ok_or_else(|| {
+                crate::error!(
+                    "invalid verbosity level `{s}`, excepted one of {}",
+                    LEVEL_NAMES.join(", ")
+                )
+            })
+    }
+}
+
+impl RawValParser for Verbosity {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        let uid = ctx.uid()?;
+
+        raw2str(raw)?.parse::<Verbosity>().map_err(|_| {
+            Error::sp_rawval(raw, format!("except one of {}", LEVEL_NAMES.join(", "))).with_uid(uid)
+        })
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<Verbosity> for Option<tracing::Level> {
+    fn from(value: Verbosity) -> Self {
+        value.tracing_level()
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<Verbosity> for tracing::level_filters::LevelFilter {
+    fn from(value: Verbosity) -> Self {
+        value.level_filter()
+    }
+}
@@ -17,6 +17,8 @@ pub struct Block<'a, T> {
 
     head: Cow<'a, str>,
 
+    usage: Cow<'a, str>,
+
     stores: Vec<T>,
 }
 
@@ -28,6 +30,7 @@ impl<'a, T> Block<'a, T> {
             help: help.into(),
             foot: foot.into(),
             head: head.into(),
+            usage: Cow::default(),
             stores: vec![],
         }
     }
@@ -52,6 +55,11 @@ impl<'a, T> Block<'a, T> {
         self.head.clone()
     }
 
+    /// The usage of block, it will replace the usage of stores in block if it is not empty.
+    pub fn usage(&self) -> Cow<'a, str> {
+        self.usage.clone()
+    }
+
     pub fn attach(&mut self, store: T) -> &mut Self {
         self.stores.push(store);
         self
@@ -81,6 +89,11 @@ impl<'a, T> Block<'a, T> {
         self.head = header.into();
         self
     }
+
+    pub fn set_usage<S: Into<Cow<'a, str>>>(&mut self, usage: S) -> &mut Self {
+        self.usage = usage.into();
+        self
+    }
 }
 
 impl<T> Deref for Block<'_, T> {
@@ -75,7 +75,12 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
     ) -> (Vec<String>, Vec<String>) {
         let mut usages = vec![];
         let mut args = vec![];
+        let usage = item.usage();
 
+        if !usage.is_empty() {
+            usages.push(usage.to_string());
+            return (usages, args);
+        }
         for store in item.iter() {
             if let Some(store) = stores.iter().find(|v| &v.name() == store) {
                 let hint = store.hint();
@@ -131,7 +136,7 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
             }
         }
 
-        let mut ret = String::from("Usage: ");
+        let mut ret = format!("{} ", self.style.paint(Style::BOLD_UNDERLINE, "Usage:"));
         let usage = usages.join(" ");
         let block_hint = block_hint.join(" ");
         let args = args.join(" ");
@@ -170,7 +175,13 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
         let head = item.head();
         let foot = item.foot();
         let line_spacing = &"\n".repeat(1 + style.line_spacing);
-        let mut output = if head.is_empty() { vec![] } else { vec![head] };
+        let mut output = if head.is_empty() {
+            vec![]
+        } else {
+            vec![Cow::Owned(
+                style.paint(Style::BOLD_UNDERLINE, &head).into_owned(),
+            )]
+        };
         let mut data: Vec<Vec<Cow<'a, str>>> = vec![vec![]; count];
         let blocks = item.as_slice();
         let styles = &self.styles;
@@ -180,7 +191,7 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
             for store in stores {
                 if store.name() == blocks[idx] {
                     let hint = store.hint();
-                    let help = store.help();
+                    let help = store.help_with_default();
 
                     if !hint.is_empty() {
                         data[idx].push(hint);
@@ -213,10 +224,15 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
             let first_line_spacing = &"\n".repeat(1 + first_style.line_spacing);
 
             for i in 0..max_len {
-                let rows = &wrapped_line
+                let mut rows = wrapped_line
                     .iter()
                     .map(|v| v.get_line(i))
                     .collect::<Vec<String>>();
+
+                // the first column is hint if there are more than one column
+                if rows.len() > 1 {
+                    rows[0] = style.paint(Style::BOLD, &rows[0]).into_owned();
+                }
                 let mut line = rows.join(first_row_spacing);
 
                 line.push_str(first_line_spacing);
@@ -318,7 +334,12 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
     ) -> (Vec<String>, Vec<String>) {
         let mut usages = vec![];
         let mut args = vec![];
+        let usage = item.usage();
 
+        if !usage.is_empty() {
+            usages.push(usage.to_string());
+            return (usages, args);
+        }
         for store in item.iter() {
             if let Some(store) = stores.iter().find(|v| &v.name() == store) {
                 let hint = store.hint();
@@ -375,7 +396,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
             }
         }
 
-        let mut ret = String::from("Usage: ");
+        let mut ret = format!("{} ", app.style().paint(Style::BOLD_UNDERLINE, "Usage:"));
         // all the option usage
         let global_usage = usages.join(" ");
         let block_hint = block_hint.join(" ");
@@ -416,7 +437,8 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         let count = block.len();
         let mut data = vec![vec![]; count];
         let styles = &self.styles;
-        let line_spacing = &"\n".repeat(1 + app.style().line_spacing);
+        let style = app.style();
+        let line_spacing = &"\n".repeat(1 + style.line_spacing);
         let mut any_filled = false;
 
         if block.is_empty() {
@@ -459,10 +481,15 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
             let first_line_spacing = &"\n".repeat(1 + first_style.line_spacing);
 
             for i in 0..max_len {
-                let rows = &wrapped_line
+                let mut rows = wrapped_line
                     .iter()
                     .map(|v| v.get_line(i))
                     .collect::<Vec<String>>();
+
+                // the first column is hint if there are more than one column
+                if rows.len() > 1 {
+                    rows[0] = style.paint(Style::BOLD, &rows[0]).into_owned();
+                }
                 let mut line = rows.join(first_row_spacing);
 
                 line.push_str(first_line_spacing);
@@ -479,7 +506,9 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         let wrapped_output = wrapped_lines.join("");
 
         if !head.is_empty() {
-            usages.push(head);
+            usages.push(Cow::Owned(
+                style.paint(Style::BOLD_UNDERLINE, &head).into_owned(),
+            ));
         }
         if !foot.is_empty() {
             usages.push(foot);
@@ -502,7 +531,13 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         let head = item.head();
         let foot = item.foot();
         let line_spacing = &"\n".repeat(1 + style.line_spacing);
-        let mut output = if head.is_empty() { vec![] } else { vec![head] };
+        let mut output = if head.is_empty() {
+            vec![]
+        } else {
+            vec![Cow::Owned(
+                style.paint(Style::BOLD_UNDERLINE, &head).into_owned(),
+            )]
+        };
         let mut data: Vec<Vec<Cow<'a, str>>> = vec![vec![]; count];
         let blocks = item.as_slice();
         let styles = &self.styles;
@@ -515,7 +550,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
             for store in stores {
                 if store.name() == blocks[idx] {
                     let hint = store.hint();
-                    let help = store.help();
+                    let help = store.help_with_default();
 
                     if !hint.is_empty() {
                         data[idx].push(hint);
@@ -548,10 +583,15 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
             let first_line_spacing = &"\n".repeat(1 + first_style.line_spacing);
 
             for i in 0..max_len {
-                let rows = &wrapped_line
+                let mut rows = wrapped_line
                     .iter()
                     .map(|v| v.get_line(i))
                     .collect::<Vec<String>>();
+
+                // the first column is hint if there are more than one column
+                if rows.len() > 1 {
+                    rows[0] = style.paint(Style::BOLD, &rows[0]).into_owned();
+                }
                 let mut line = rows.join(first_row_spacing);
 
                 line.push_str(first_line_spacing);
@@ -12,6 +12,8 @@ pub struct Store<'a> {
 
     r#type: Cow<'a, str>,
 
+    default_value: Cow<'a, str>,
+
     optional: bool,
 
     position: bool,
@@ -31,6 +33,7 @@ impl<'a> Store<'a> {
             hint: hint.into(),
             help: help.into(),
             r#type: r#type.into(),
+            default_value: Cow::default(),
             optional,
             position,
         }
@@ -60,6 +63,20 @@ impl<'a> Store<'a> {
         self.r#type.clone()
     }
 
+    /// The display string of default value, empty means no default value.
+    pub fn default_value(&self) -> Cow<'a, str> {
+        self.default_value.clone()
+    }
+
+    /// The help message with default value, such as `Number of jobs [default: 4]`.
+    pub fn help_with_default(&self) -> Cow<'a, str> {
+        match (self.help.is_empty(), self.default_value.is_empty()) {
+            (_, true) => self.help.clone(),
+            (true, false) => format!("[default: {}]", self.default_value).into(),
+            (false, false) => format!("{} [default: {}]", self.help, self.default_value).into(),
+        }
+    }
+
     pub fn set_name<S: Into<Cow<'a, str>>>(&mut self, name: S) -> &mut Self {
         self.name = name.into();
         self
@@ -89,6 +106,11 @@ impl<'a> Store<'a> {
         self.r#type = type_name.into();
         self
     }
+
+    pub fn set_default_value<S: Into<Cow<'a, str>>>(&mut self, default_value: S) -> &mut Self {
+        self.default_value = default_value.into();
+        self
+    }
 }
 
 impl HelpDisplay for Store<'_> {
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 #[derive(Debug, Default, Clone)]
 pub enum Align {
     #[default]
@@ -20,6 +22,8 @@ pub struct Style {
     pub line_spacing: usize,
 
     pub block_spacing: usize,
+
+    color: bool,
 }
 
 impl Default for Style {
@@ -32,12 +36,55 @@ impl Default for Style {
             row_spacing: 4,
             line_spacing: 0,
             block_spacing: 1,
+            color: false,
         }
     }
 }
 
 impl Style {
+    pub const BOLD: &'static str = "\x1b[1m";
+
+    pub const BOLD_UNDERLINE: &'static str = "\x1b[1;4m";
+
+    pub const GREEN: &'static str = "\x1b[32m";
+
+    pub const RESET: &'static str = "\x1b[0m";
+
     pub fn take(&mut self) -> Self {
         std::mem::take(self)
     }
+
+    /// Decorate the usage, heads and hints with ANSI escape sequences.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn set_color(&mut self, color: bool) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn color(&self) -> bool {
+        self.color
+    }
+
+    /// Wrap the text with escape sequence `code` if [`color`](Style::color()) is enabled,
+    /// the leading and trailing whitespaces are kept outside so the alignment is not changed.
+    pub fn paint<'a>(&self, code: &str, text: &'a str) -> Cow<'a, str> {
+        let content = text.trim();
+
+        if !self.color || content.is_empty() {
+            return Cow::Borrowed(text);
+        }
+        let start = text.len() - text.trim_start().len();
+        let end = start + content.len();
+
+        Cow::Owned(format!(
+            "{}{code}{content}{}{}",
+            &text[..start],
+            Self::RESET,
+            &text[end..]
+        ))
+    }
 }
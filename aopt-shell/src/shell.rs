@@ -3,14 +3,23 @@ pub(crate) mod fish;
 pub(crate) mod ps1;
 pub(crate) mod zsh;
 
+use std::any::TypeId;
+use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::io::Write;
 
 use crate::acore::opt::Opt;
 use crate::acore::opt::Style;
 use crate::acore::trace;
+use crate::acore::value::BoolLiteral;
+use crate::acore::value::InputFile;
+use crate::acore::value::OutputFile;
+use crate::acore::value::ValHint;
 use crate::acore::HashMap;
 use crate::acore::Uid;
+use crate::value::dir_paths;
+use crate::value::file_paths;
 use crate::value::Values;
 use crate::Error;
 
@@ -75,7 +84,7 @@ where
     I: Iterator<Item = &'a O>,
     F: FnMut(&OsStr, &O) -> Result<(), Error>,
 {
-    complete_eq(arg, bytes, opts, values, |_, val, opt| f(val, opt))
+    complete_values(arg, bytes, opts, values, None, |_, val, opt| f(val, opt))
 }
 
 pub fn complete_eq<'a, O, I, F>(
@@ -83,6 +92,73 @@ pub fn complete_eq<'a, O, I, F>(
     bytes: &[u8],
     opts: I,
     values: &HashMap<Uid, Box<dyn Values<O, Err = Error>>>,
+    f: F,
+) -> Result<bool, Error>
+where
+    O: Opt + 'a,
+    I: Iterator<Item = &'a O>,
+    F: FnMut(&str, &OsStr, &O) -> Result<(), Error>,
+{
+    complete_eq_with(arg, bytes, opts, values, BoolLiteral::default_ref(), f)
+}
+
+/// Same as [`complete_eq`], the boolean option is completed with the words of `literal`.
+pub fn complete_eq_with<'a, O, I, F>(
+    arg: &str,
+    bytes: &[u8],
+    opts: I,
+    values: &HashMap<Uid, Box<dyn Values<O, Err = Error>>>,
+    literal: &BoolLiteral,
+    f: F,
+) -> Result<bool, Error>
+where
+    O: Opt + 'a,
+    I: Iterator<Item = &'a O>,
+    F: FnMut(&str, &OsStr, &O) -> Result<(), Error>,
+{
+    complete_values(arg, bytes, opts, values, Some(literal), f)
+}
+
+/// Return the values of option used when no values set for it.
+///
+/// The values are words of `boolean` for boolean option if it is not None,
+/// and file paths start with `prefix` for option of [`InputFile`] or [`OutputFile`].
+pub fn default_values<O: Opt>(
+    opt: &O,
+    prefix: &[u8],
+    boolean: Option<&BoolLiteral>,
+) -> Result<Option<Vec<OsString>>, Error> {
+    let type_id = opt.r#type();
+    let prefix_str = || std::str::from_utf8(prefix).ok();
+
+    if let Some(hint) = opt.accessor().storer().hint() {
+        match hint {
+            ValHint::Dir => return prefix_str().map(dir_paths).transpose(),
+            ValHint::File | ValHint::Path => return prefix_str().map(file_paths).transpose(),
+            ValHint::OneOf(vals) => return Ok(Some(vals.iter().map(OsString::from).collect())),
+            ValHint::Other(_) => {}
+        }
+    }
+    if let Some(literal) = boolean.filter(|_| is_bool(opt)) {
+        Ok(Some(literal.literals().map(OsString::from).collect()))
+    } else if type_id == &TypeId::of::<InputFile>() || type_id == &TypeId::of::<OutputFile>() {
+        prefix_str().map(file_paths).transpose()
+    } else {
+        Ok(None)
+    }
+}
+
+fn is_bool<O: Opt>(opt: &O) -> bool {
+    opt.mat_style(Style::Boolean) && opt.r#type() == &TypeId::of::<bool>()
+}
+
+/// Complete the values of option, the boolean option only accept value in `--bool=value` style.
+fn complete_values<'a, O, I, F>(
+    arg: &str,
+    bytes: &[u8],
+    opts: I,
+    values: &HashMap<Uid, Box<dyn Values<O, Err = Error>>>,
+    boolean: Option<&BoolLiteral>,
     mut f: F,
 ) -> Result<bool, Error>
 where
@@ -92,11 +168,17 @@ where
 {
     let mut found = false;
 
-    for opt in opts.filter(|v| v.mat_style(Style::Argument)) {
+    for opt in opts.filter(|v| v.mat_style(Style::Argument) || (boolean.is_some() && is_bool(*v))) {
         for name in name_iter!(opt).filter(|v| v == &arg) {
             if name == arg {
-                if let Some(getter) = values.get(&opt.uid()) {
-                    for val in getter.get_values(opt)? {
+                let vals = if let Some(getter) = values.get(&opt.uid()) {
+                    Some(getter.get_values(opt)?)
+                } else {
+                    default_values(opt, bytes, boolean)?
+                };
+
+                if let Some(vals) = vals {
+                    for val in vals {
                         if !val.is_empty() && bytes.is_empty()
                             || bytes
                                 .iter()
@@ -116,6 +198,40 @@ where
     Ok(found)
 }
 
+/// Return true if `opt` conflicts with `other`, or `other` conflicts with `opt`.
+pub fn is_conflict<O: Opt>(opt: &O, other: &O) -> bool {
+    let conflict = |a: &O, b: &O| {
+        a.constraints()
+            .iter()
+            .filter(|v| v.is_conflicts())
+            .flat_map(|v| v.targets())
+            .any(|v| b.mat_name(Some(v)) || b.mat_alias(v))
+    };
+
+    conflict(opt, other) || conflict(other, opt)
+}
+
+/// Return the options set in `args`, the `args` can be in `--opt=val` style.
+pub fn used_opts<'a, O, I>(args: &[OsString], opts: I) -> Vec<&'a O>
+where
+    O: Opt + 'a,
+    I: Iterator<Item = &'a O> + Clone,
+{
+    args.iter()
+        .filter_map(|arg| {
+            crate::acore::str::split_once(arg, '=')
+                .map(|(arg, _)| arg)
+                .unwrap_or(Cow::Borrowed(arg.as_os_str()))
+                .to_str()
+                .map(String::from)
+        })
+        .flat_map(|arg| {
+            opts.clone()
+                .filter(move |v| v.mat_name(Some(&arg)) || v.mat_alias(&arg))
+        })
+        .collect()
+}
+
 pub fn complete_opt<'a, O, I, F>(arg: &str, opts: I, mut f: F) -> Result<bool, Error>
 where
     O: Opt + 'a,
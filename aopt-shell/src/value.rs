@@ -196,3 +196,56 @@ impl<O> Values<O> for RepeatValues<O> {
         (self.handler)(opt)
     }
 }
+
+/// Return the file paths start with `prefix`, the directory has a trailing separator.
+///
+/// The hidden files are listed only when the `prefix` of name starts with `.`.
+/// It returns an empty list if the directory not exists or can not be read.
+pub fn file_paths(prefix: &str) -> Result<Vec<OsString>, Error> {
+    list_paths(prefix, false)
+}
+
+/// Return the directory paths start with `prefix`, each path has a trailing separator.
+///
+/// The hidden directories are listed only when the `prefix` of name starts with `.`.
+/// It returns an empty list if the directory not exists or can not be read.
+pub fn dir_paths(prefix: &str) -> Result<Vec<OsString>, Error> {
+    list_paths(prefix, true)
+}
+
+fn list_paths(prefix: &str, dir_only: bool) -> Result<Vec<OsString>, Error> {
+    let (dir, name) = match prefix.rfind(std::path::is_separator) {
+        Some(pos) => prefix.split_at(pos + 1),
+        None => ("", prefix),
+    };
+    // the path is half-typed, so the directory may not exist
+    let Ok(entries) = std::fs::read_dir(if dir.is_empty() { "." } else { dir }) else {
+        crate::acore::trace!("can not read directory `{dir}`, no path available");
+        return Ok(vec![]);
+    };
+    let mut paths = vec![];
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+
+        if let Some(file_name) = file_name.to_str() {
+            if file_name.starts_with(name) && (name.starts_with('.') || !file_name.starts_with('.'))
+            {
+                let is_dir = entry.file_type().map(|v| v.is_dir()).unwrap_or_default();
+
+                if dir_only && !is_dir {
+                    continue;
+                }
+                let mut path = OsString::from(dir);
+
+                path.push(file_name);
+                if is_dir {
+                    path.push(std::path::MAIN_SEPARATOR_STR);
+                }
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
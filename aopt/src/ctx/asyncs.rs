@@ -0,0 +1,376 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use crate::ctx::Ctx;
+use crate::ctx::Store;
+use crate::map::ErasedTy;
+use crate::opt::Opt;
+use crate::set::SetExt;
+use crate::set::SetOpt;
+use crate::Error;
+use crate::Uid;
+
+/// The boxed future returned by [`AsyncHandler`].
+#[cfg(not(feature = "sync"))]
+pub type BoxedFuture<'x, T> = Pin<Box<dyn Future<Output = T> + 'x>>;
+
+/// The boxed future returned by [`AsyncHandler`].
+#[cfg(feature = "sync")]
+pub type BoxedFuture<'x, T> = Pin<Box<dyn Future<Output = T> + Send + 'x>>;
+
+/// Handler type using for async callback.
+#[cfg(not(feature = "sync"))]
+pub type AsyncInvokeHandler<'a, Set> = Box<dyn AsyncHandler<Set> + 'a>;
+
+/// Handler type using for async callback.
+#[cfg(feature = "sync")]
+pub type AsyncInvokeHandler<'a, Set> = Box<dyn AsyncHandler<Set> + Send + Sync + 'a>;
+
+/// The async version of option handler, it return a future instead of calling the handler directly.
+pub trait AsyncHandler<Set> {
+    fn invoke<'x>(
+        &'x mut self,
+        set: &'x mut Set,
+        ctx: &'x mut Ctx<'_>,
+    ) -> BoxedFuture<'x, Result<bool, Error>>;
+}
+
+/// Wrap an async handler and the [`Store`] saving its return value.
+pub struct AsyncWrapper<H, T, O> {
+    handler: H,
+
+    store: T,
+
+    marker: PhantomData<fn() -> O>,
+}
+
+impl<H, T, O> AsyncWrapper<H, T, O> {
+    pub fn new(handler: H, store: T) -> Self {
+        Self {
+            handler,
+            store,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(not(feature = "sync"))]
+impl<Set, H, T, O> AsyncHandler<Set> for AsyncWrapper<H, T, O>
+where
+    O: ErasedTy,
+    T: Store<Set, O, Ret = bool, Error = Error>,
+    H: AsyncFnMut(&mut Set, &mut Ctx) -> Result<Option<O>, Error>,
+{
+    fn invoke<'x>(
+        &'x mut self,
+        set: &'x mut Set,
+        ctx: &'x mut Ctx<'_>,
+    ) -> BoxedFuture<'x, Result<bool, Error>> {
+        Box::pin(async move {
+            let val = (self.handler)(&mut *set, &mut *ctx).await?;
+            let arg = ctx.arg()?.map(|v| v.as_ref());
+            let uid = ctx.uid()?;
+
+            self.store.process(uid, set, arg, val)
+        })
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<Set, H, T, O> AsyncHandler<Set> for AsyncWrapper<H, T, O>
+where
+    O: ErasedTy,
+    Set: Send,
+    T: Store<Set, O, Ret = bool, Error = Error> + Send,
+    H: for<'x> FnMut(&'x mut Set, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>> + Send,
+{
+    fn invoke<'x>(
+        &'x mut self,
+        set: &'x mut Set,
+        ctx: &'x mut Ctx<'_>,
+    ) -> BoxedFuture<'x, Result<bool, Error>> {
+        Box::pin(async move {
+            let val = (self.handler)(&mut *set, &mut *ctx).await?;
+            let arg = ctx.arg()?.map(|v| v.as_ref());
+            let uid = ctx.uid()?;
+
+            self.store.process(uid, set, arg, val)
+        })
+    }
+}
+
+/// The default store of async handler, it call the [`process`](Store::process) of option's action.
+pub struct ActionStore;
+
+impl<Set, Val> Store<Set, Val> for ActionStore
+where
+    Val: ErasedTy,
+    SetOpt<Set>: Opt,
+    Set: crate::set::Set,
+{
+    type Ret = bool;
+
+    type Error = Error;
+
+    fn process(
+        &mut self,
+        uid: Uid,
+        set: &mut Set,
+        raw: Option<&std::ffi::OsStr>,
+        val: Option<Val>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let mut act = *set.opt(uid)?.action();
+
+        act.process(uid, set, raw, val)
+    }
+}
+
+/// Keep the async handlers and invoke them with the context saved in parsing.
+pub trait AsyncHandlerCollection<'a, Set> {
+    #[cfg(not(feature = "sync"))]
+    fn register_async<H: AsyncHandler<Set> + 'a>(&mut self, uid: Uid, handler: H);
+
+    #[cfg(feature = "sync")]
+    fn register_async<H: AsyncHandler<Set> + Send + Sync + 'a>(&mut self, uid: Uid, handler: H);
+
+    fn get_async_handler(&mut self, uid: &Uid) -> Option<&mut AsyncInvokeHandler<'a, Set>>;
+
+    fn has_async(&self, uid: Uid) -> bool;
+
+    /// Invoke the async handler of given `uid`, return an error if handler not exist.
+    fn invoke_async<'x>(
+        &'x mut self,
+        uid: &Uid,
+        set: &'x mut Set,
+        ctx: &'x mut Ctx<'_>,
+    ) -> BoxedFuture<'x, Result<bool, Error>>
+    where
+        'a: 'x,
+    {
+        let uid = *uid;
+
+        match self.get_async_handler(&uid) {
+            Some(handler) => handler.invoke(set, ctx),
+            None => Box::pin(async move { Err(crate::error!("no async handler of {uid}")) }),
+        }
+    }
+}
+
+/// Register the async handler with a [`Store`], call [`then`](AsyncHandlerEntry::then) or [`submit`](AsyncHandlerEntry::submit) finish the registration.
+#[must_use = "call `then` or `submit` register the async handler"]
+pub struct AsyncHandlerEntry<'a, 'b, I, Set, H, O> {
+    invoker: &'b mut I,
+
+    handler: H,
+
+    uid: Uid,
+
+    marker: PhantomData<(&'a (), O, Set)>,
+}
+
+impl<'b, I, Set, H, O> AsyncHandlerEntry<'_, 'b, I, Set, H, O> {
+    pub fn new(invoker: &'b mut I, uid: Uid, handler: H) -> Self {
+        Self {
+            invoker,
+            handler,
+            uid,
+            marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_async_entry {
+    ({ $($handler:tt)* }, { $($bound:tt)* }) => {
+        impl<'a, I, Set, H, O> AsyncHandlerEntry<'a, '_, I, Set, H, O>
+        where
+            O: ErasedTy,
+            Set: crate::set::Set $($bound)* + 'a,
+            SetOpt<Set>: Opt,
+            I: AsyncHandlerCollection<'a, Set>,
+            H: $($handler)* $($bound)* + 'a,
+        {
+            /// Register the handler with given `store`.
+            /// The `store` will be used save the return value of option handler.
+            pub fn then(self, store: impl Store<Set, O, Ret = bool, Error = Error> $($bound)* + 'a) -> Uid {
+                self.invoker
+                    .register_async(self.uid, AsyncWrapper::new(self.handler, store));
+                self.uid
+            }
+
+            /// Register the handler, the return value is saved by the action of option.
+            pub fn submit(self) -> Uid {
+                self.then(ActionStore)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "sync"))]
+impl_async_entry!({ AsyncFnMut(&mut Set, &mut Ctx) -> Result<Option<O>, Error> }, {});
+
+#[cfg(feature = "sync")]
+impl_async_entry!(
+    { for<'x> FnMut(&'x mut Set, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>> },
+    { + Send + Sync }
+);
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::task::Context;
+    use std::task::Poll;
+    use std::task::Waker;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = std::pin::pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(ret) = fut.as_mut().poll(&mut cx) {
+                return ret;
+            }
+        }
+    }
+
+    // the handler of `sync` feature need return a `Send` boxed future
+    #[cfg(not(feature = "sync"))]
+    macro_rules! handler {
+        (|$set:ident, $ctx:ident| $body:block) => {
+            async |$set: &mut AHCSet, $ctx: &mut Ctx| $body
+        };
+    }
+
+    #[cfg(feature = "sync")]
+    macro_rules! handler {
+        (|$set:ident, $ctx:ident| $body:block) => {
+            |$set: &mut AHCSet, $ctx: &mut Ctx| Box::pin(async move $body)
+        };
+    }
+
+    #[test]
+    fn async_handler() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.set_app_data(Vec::<String>::new());
+        parser
+            .add_opt("--name=s")?
+            .on(|set: &mut AHCSet, ctx: &mut Ctx| {
+                let name = ctx.value::<String>()?;
+
+                set.app_data_mut::<Vec<String>>()?
+                    .push(format!("sync {name}"));
+                Ok(Some(name))
+            })?;
+        parser
+            .add_opt("--size=i")?
+            .on_async(handler!(|set, ctx| {
+                let size = ctx.value::<i64>()?;
+
+                set.app_data_mut::<Vec<String>>()?
+                    .push(format!("async {size}"));
+                Ok(Some(size * 2))
+            }))?
+            .submit();
+        parser
+            .add_opt("--tag=s%app")?
+            .on_async(handler!(|set, ctx| {
+                let tag = ctx.value::<String>()?;
+
+                set.app_data_mut::<Vec<String>>()?
+                    .push(format!("async {tag}"));
+                Ok(Some(vec![tag]))
+            }))?
+            .then(VecStore);
+        parser
+            .add_opt("--id=i!")?
+            .on_async(handler!(|_set, ctx| {
+                let id = ctx.value::<i64>()?;
+
+                Ok((id > 0).then_some(id))
+            }))?
+            .submit();
+
+        let args = [
+            "app", "--size=2", "--name", "a", "--tag=x", "--size=4", "--id=1",
+        ];
+
+        // the sync parse not support async handler
+        assert!(parser.parse(Args::from(args)).is_err());
+
+        let ret = block_on(parser.parse_async(Args::from(args)))?;
+
+        assert!(ret.status());
+        assert_eq!(
+            parser.take_app_data::<Vec<String>>()?,
+            ["async 2", "sync a", "async x", "async 4"]
+        );
+        assert_eq!(parser.find_val::<i64>("--size")?, &8);
+        assert_eq!(parser.find_val::<String>("--name")?, "a");
+        assert_eq!(parser.find_vals::<String>("--tag")?, &["x"]);
+        assert_eq!(parser.find_val::<i64>("--id")?, &1);
+
+        // the checks of policy see the result of async handler
+        parser.set_app_data(Vec::<String>::new());
+        assert!(!block_on(parser.parse_async(Args::from(["app", "--id=0"])))?.status());
+        Ok(())
+    }
+
+    #[test]
+    fn async_middleware() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.set_app_data(Vec::<String>::new());
+        for name in ["--size=i", "--skip=i"] {
+            parser
+                .add_opt(name)?
+                .on_async(handler!(|set, ctx| {
+                    let size = ctx.value::<i64>()?;
+
+                    set.app_data_mut::<Vec<String>>()?
+                        .push(format!("async {size}"));
+                    Ok(Some(size))
+                }))?
+                .submit();
+        }
+        parser
+            .invoker_mut()?
+            .add_before(|uid, set: &mut AHCSet, _: &mut Ctx| {
+                let name = set.opt(uid)?.name().to_owned();
+
+                set.app_data_mut::<Vec<String>>()?
+                    .push(format!("before {name}"));
+                // skip the async handler of `--skip`
+                Ok((name == "--skip").then_some(true))
+            })
+            .add_after(
+                |uid, set: &mut AHCSet, _: &mut Ctx, ret: Result<bool, Error>| {
+                    let name = set.opt(uid)?.name().to_owned();
+
+                    set.app_data_mut::<Vec<String>>()?
+                        .push(format!("after {name}"));
+                    ret
+                },
+            );
+
+        let ret = block_on(parser.parse_async(Args::from(["app", "--size=2", "--skip=4"])))?;
+
+        assert!(ret.status());
+        assert_eq!(parser.find_val::<i64>("--size")?, &2);
+        assert!(parser.find_val::<i64>("--skip").is_err());
+        assert_eq!(
+            parser.take_app_data::<Vec<String>>()?,
+            [
+                "before --size",
+                "async 2",
+                "after --size",
+                // the later middlewares are skipped too
+                "before --skip"
+            ]
+        );
+        Ok(())
+    }
+}
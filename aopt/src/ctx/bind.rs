@@ -0,0 +1,236 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::map::ErasedTy;
+use crate::Error;
+
+/// The variable type can be bound to an option, see [`bind`](crate::parser::ParserCommit::bind).
+///
+/// The variable is updated from the values of option after each occurrence,
+/// so the [`Action`](crate::opt::Action) of option is honoured:
+///
+/// * scalar type, such as `i64` or `String`, is set to the last value.
+///
+/// * `bool` is set to the last value, a flag with [`Action::Toggle`](crate::opt::Action::Toggle) also works.
+///
+/// * `u64` can be used for the count of [`Action::Cnt`](crate::opt::Action::Cnt).
+///
+/// * [`Option<T>`] is set to the last value, or `None` if the option has no value.
+///
+/// * [`Vec<T>`] is set to all the values of option.
+pub trait BindValue {
+    type Val: ErasedTy;
+
+    fn update(&mut self, vals: &[Self::Val]);
+}
+
+macro_rules! impl_bind_value {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl BindValue for $ty {
+                type Val = $ty;
+
+                fn update(&mut self, vals: &[Self::Val]) {
+                    if let Some(val) = vals.last() {
+                        *self = val.clone();
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_bind_value!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String,
+    OsString, PathBuf
+);
+
+impl<T: ErasedTy + Clone> BindValue for Option<T> {
+    type Val = T;
+
+    fn update(&mut self, vals: &[Self::Val]) {
+        *self = vals.last().cloned();
+    }
+}
+
+impl<T: ErasedTy + Clone> BindValue for Vec<T> {
+    type Val = T;
+
+    fn update(&mut self, vals: &[Self::Val]) {
+        *self = vals.to_vec();
+    }
+}
+
+/// The target of binding, it can be a mutable reference,
+/// a reference of [`Cell`], [`RefCell`] or [`Mutex`] to a [`BindValue`] variable.
+/// Only the mutable reference and [`Mutex`] can be used with feature `sync`.
+pub trait BindTarget {
+    type Val: ErasedTy;
+
+    fn update(&mut self, vals: &[Self::Val]) -> Result<(), Error>;
+}
+
+impl<T: BindValue> BindTarget for &mut T {
+    type Val = T::Val;
+
+    fn update(&mut self, vals: &[Self::Val]) -> Result<(), Error> {
+        BindValue::update(&mut **self, vals);
+        Ok(())
+    }
+}
+
+impl<T: BindValue + Default> BindTarget for &Cell<T> {
+    type Val = T::Val;
+
+    fn update(&mut self, vals: &[Self::Val]) -> Result<(), Error> {
+        let mut value = self.take();
+
+        value.update(vals);
+        self.set(value);
+        Ok(())
+    }
+}
+
+impl<T: BindValue> BindTarget for &RefCell<T> {
+    type Val = T::Val;
+
+    fn update(&mut self, vals: &[Self::Val]) -> Result<(), Error> {
+        self.try_borrow_mut()
+            .map_err(|e| crate::error!("can not update the bound variable: {e}"))?
+            .update(vals);
+        Ok(())
+    }
+}
+
+impl<T: BindValue> BindTarget for &Mutex<T> {
+    type Val = T::Val;
+
+    fn update(&mut self, vals: &[Self::Val]) -> Result<(), Error> {
+        self.lock()
+            .map_err(|e| crate::error!("can not update the bound variable: {e}"))?
+            .update(vals);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn bind() {
+        assert!(bind_impl().is_ok());
+    }
+
+    fn bind_impl() -> Result<(), Error> {
+        let mut name = String::from("default");
+        let mut size = None::<i64>;
+        let mut debug = false;
+        let mut quiet = true;
+        let mut count = 0u64;
+        let files = Mutex::new(Vec::<String>::new());
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--name=s")?.bind(&mut name)?;
+        parser.add_opt("--size=i")?.bind(&mut size)?;
+        parser.add_opt("--debug=b")?.bind(&mut debug)?;
+        parser
+            .add_opt("--quiet=b")?
+            .set_action(Action::Toggle)
+            .set_value_t(true)
+            .bind(&mut quiet)?;
+        parser
+            .add_opt("-v=b")?
+            .set_action(Action::Cnt)
+            .bind(&mut count)?;
+        parser.add_opt("--file=s")?.bind(&files)?;
+
+        let args = [
+            "app", "--size=1", "-v", "--file=a", "--debug", "--quiet", "-v", "--file=b", "-v",
+            "--size=8",
+        ];
+        let ret = parser.parse_once(Args::from(args))?;
+
+        assert!(ret.status());
+        assert_eq!(name, "default");
+        assert_eq!(size, Some(8));
+        assert!(debug);
+        assert!(!quiet);
+        assert_eq!(count, 3);
+        assert_eq!(files.lock().unwrap().as_slice(), ["a", "b"]);
+
+        let mut depth = 0i64;
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--depth=i")?.bind(&mut depth)?;
+
+        // the value failed to parse is a failure, the variable not changed
+        let ret = parser.parse_once(Args::from(["app", "--depth=x"]))?;
+
+        assert!(!ret.status());
+        assert_eq!(depth, 0);
+
+        let mut level = 0i64;
+        let mut limit = 0i64;
+        let mut parser = AFwdParser::default();
+
+        // the binding is chained with the handler, no matter which one registered first
+        parser
+            .add_opt("--level=i")?
+            .on(|_: &mut AHCSet, ctx: &mut Ctx| Ok(Some(ctx.value::<i64>()? * 10)))?;
+        let uid = parser.add_opt("--limit=i")?.bind(&mut limit)?;
+
+        parser
+            .invoker_mut()?
+            .entry(uid)
+            .on(|_: &mut AHCSet, ctx: &mut Ctx| Ok(Some(ctx.value::<i64>()? + 1)));
+        let uid = parser.find_uid("--level")?;
+
+        parser.invoker_mut()?.bind(uid, &mut level);
+
+        let ret = parser.parse_once(Args::from(["app", "--level=4", "--limit=4"]))?;
+
+        assert!(ret.status());
+        assert_eq!(level, 40);
+        assert_eq!(limit, 5);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "sync"))]
+    #[test]
+    fn bind_cell() {
+        assert!(bind_cell_impl().is_ok());
+    }
+
+    #[cfg(not(feature = "sync"))]
+    fn bind_cell_impl() -> Result<(), Error> {
+        use std::cell::Cell;
+        use std::cell::RefCell;
+
+        let count = Cell::new(0u64);
+        let files = RefCell::new(Vec::<String>::new());
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("-v=b")?
+            .set_action(Action::Cnt)
+            .bind(&count)?;
+        parser.add_opt("--file=s")?.bind(&files)?;
+
+        // the variables can be read while the parser alive
+        parser.parse(Args::from(["app", "-v", "--file=a", "-v"]))?;
+        assert_eq!(count.get(), 2);
+        assert_eq!(files.borrow().as_slice(), ["a"]);
+
+        parser.parse(Args::from(["app", "--file=b", "-v"]))?;
+        assert_eq!(count.get(), 3);
+        assert_eq!(files.borrow().as_slice(), ["a", "b"]);
+        Ok(())
+    }
+}
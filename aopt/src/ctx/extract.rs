@@ -0,0 +1,302 @@
+use std::any::type_name;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::ctx::Ctx;
+use crate::map::ErasedTy;
+use crate::opt::ConfigValue;
+use crate::parser::AppStorage;
+use crate::set::SetCfg;
+use crate::set::SetValueFindExt;
+use crate::value::RawValParser;
+use crate::Error;
+use crate::Uid;
+
+/// Extract a value from the set and context before invoking the handler.
+///
+/// The parameters of handler wrapped by [`extract_handler`] are resolved by this trait.
+pub trait Extract<Set>: Sized {
+    fn extract(set: &Set, ctx: &Ctx) -> Result<Self, Error>;
+}
+
+/// Name of option using by [`OptVal`], see [`opt_name`](crate::opt_name).
+pub trait OptName {
+    const NAME: &'static str;
+}
+
+/// Declare the types implemented [`OptName`], which can be used in [`OptVal`].
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// #
+/// aopt::opt_name!(pub Depth = "--depth"; Debug = "-d");
+///
+/// assert_eq!(Depth::NAME, "--depth");
+/// assert_eq!(Debug::NAME, "-d");
+/// ```
+#[macro_export]
+macro_rules! opt_name {
+    ($($(#[$meta:meta])* $vis:vis $name:ident = $opt:literal);+ $(;)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, Default)]
+            $vis struct $name;
+
+            impl $crate::ctx::OptName for $name {
+                const NAME: &'static str = $opt;
+            }
+        )+
+    };
+}
+
+/// The value parsed from current argument by [`RawValParser`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Value<T>(pub T);
+
+/// The value of option `N` in the set, the value is cloned from the option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OptVal<N, T>(pub T, PhantomData<N>);
+
+/// The app data saved in [`AppServices`](crate::parser::AppServices), the value is cloned from the service.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Service<T>(pub T);
+
+macro_rules! impl_extractor {
+    ($name:ident < $($gen:ident),+ > . $field:tt) => {
+        impl<$($gen),+> $name<$($gen),+> {
+            pub fn into_inner(self) -> T {
+                self.$field
+            }
+        }
+
+        impl<$($gen),+> Deref for $name<$($gen),+> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$field
+            }
+        }
+
+        impl<$($gen),+> DerefMut for $name<$($gen),+> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field
+            }
+        }
+    };
+}
+
+impl_extractor!(Value<T>.0);
+impl_extractor!(OptVal<N, T>.0);
+impl_extractor!(Service<T>.0);
+
+impl<Set, T: RawValParser> Extract<Set> for Value<T> {
+    fn extract(_: &Set, ctx: &Ctx) -> Result<Self, Error> {
+        ctx.value::<T>().map(Self)
+    }
+}
+
+impl<Set, N, T> Extract<Set> for OptVal<N, T>
+where
+    N: OptName,
+    T: ErasedTy + Clone,
+    Set: SetValueFindExt,
+    SetCfg<Set>: ConfigValue + Default,
+{
+    fn extract(set: &Set, ctx: &Ctx) -> Result<Self, Error> {
+        set.find_val::<T>(N::NAME)
+            .map(|v| Self(v.clone(), PhantomData))
+            .map_err(|e| {
+                Error::sp_extract(format!("{} of option {}", type_name::<T>(), N::NAME))
+                    .with_uid(ctx.uid().unwrap_or_default())
+                    .cause_by(e)
+            })
+    }
+}
+
+impl<Set, T> Extract<Set> for Service<T>
+where
+    Set: AppStorage,
+    T: ErasedTy + Clone,
+{
+    fn extract(set: &Set, ctx: &Ctx) -> Result<Self, Error> {
+        set.app_data::<T>().map(|v| Self(v.clone())).map_err(|e| {
+            Error::sp_extract(format!("service {}", type_name::<T>()))
+                .with_uid(ctx.uid().unwrap_or_default())
+                .cause_by(e)
+        })
+    }
+}
+
+impl<Set> Extract<Set> for Uid {
+    fn extract(_: &Set, ctx: &Ctx) -> Result<Self, Error> {
+        ctx.uid()
+    }
+}
+
+/// Return `None` if the extraction failed.
+impl<Set, T: Extract<Set>> Extract<Set> for Option<T> {
+    fn extract(set: &Set, ctx: &Ctx) -> Result<Self, Error> {
+        Ok(T::extract(set, ctx).ok())
+    }
+}
+
+/// The handler which parameters are resolved by [`Extract`], `Args` is tuple of parameter types.
+pub trait ExtractHandler<Set, Args> {
+    type Output;
+
+    fn call(&mut self, set: &mut Set, ctx: &mut Ctx) -> Result<Option<Self::Output>, Error>;
+}
+
+macro_rules! impl_extract_handler {
+    ($($arg:ident),+) => {
+        impl<Set, Func, Out, $($arg),+> ExtractHandler<Set, ($($arg,)+)> for Func
+        where
+            Func: FnMut($($arg),+) -> Result<Option<Out>, Error>,
+            $($arg: Extract<Set>,)+
+        {
+            type Output = Out;
+
+            #[allow(non_snake_case)]
+            fn call(&mut self, set: &mut Set, ctx: &mut Ctx) -> Result<Option<Self::Output>, Error> {
+                $(let $arg = $arg::extract(set, ctx)?;)+
+
+                (self)($($arg),+)
+            }
+        }
+    };
+}
+
+impl_extract_handler!(A);
+impl_extract_handler!(A, B);
+impl_extract_handler!(A, B, C);
+impl_extract_handler!(A, B, C, D);
+impl_extract_handler!(A, B, C, D, E);
+impl_extract_handler!(A, B, C, D, E, F);
+impl_extract_handler!(A, B, C, D, E, F, G);
+impl_extract_handler!(A, B, C, D, E, F, G, H);
+
+/// Convert the handler which parameters are [`Extract`] types into a normal handler.
+/// The parameters are extracted in order, the first extraction failure is returned as error,
+/// the failure of [`OptVal`] and [`Service`] is a [`sp_extract`](Error::sp_extract) error.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::Value;
+/// # use aopt::ctx::OptVal;
+/// # use aopt::ctx::Service;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// aopt::opt_name!(Depth = "--depth");
+///
+/// let mut parser = AFwdParser::default();
+///
+/// parser.set_app_data(String::from("db"));
+/// parser.add_opt("--depth=i")?;
+/// parser.add_opt("--name=s")?.on(extract_handler(
+///     |name: Value<String>, depth: OptVal<Depth, i64>, db: Service<String>| {
+///         Ok(Some(format!("{}@{}:{}", *name, *db, *depth)))
+///     },
+/// ))?;
+///
+/// parser.parse(Args::from(["app", "--depth=2", "--name", "foo"]))?;
+///
+/// assert_eq!(parser.find_val::<String>("--name")?, "foo@db:2");
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+#[cfg(not(feature = "sync"))]
+pub fn extract_handler<'a, Set, Args, H>(
+    mut handler: H,
+) -> impl FnMut(&mut Set, &mut Ctx) -> Result<Option<H::Output>, Error> + 'a
+where
+    H: ExtractHandler<Set, Args> + 'a,
+{
+    move |set: &mut Set, ctx: &mut Ctx| handler.call(set, ctx)
+}
+
+/// Convert the handler which parameters are [`Extract`] types into a normal handler.
+/// The parameters are extracted in order, the first extraction failure is returned as error,
+/// the failure of [`OptVal`] and [`Service`] is a [`sp_extract`](Error::sp_extract) error.
+#[cfg(feature = "sync")]
+pub fn extract_handler<'a, Set, Args, H>(
+    mut handler: H,
+) -> impl FnMut(&mut Set, &mut Ctx) -> Result<Option<H::Output>, Error> + Send + Sync + 'a
+where
+    H: ExtractHandler<Set, Args> + Send + Sync + 'a,
+{
+    move |set: &mut Set, ctx: &mut Ctx| handler.call(set, ctx)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ctx::OptVal;
+    use crate::ctx::Service;
+    use crate::ctx::Value;
+    use crate::prelude::*;
+    use crate::Error;
+
+    crate::opt_name!(Depth = "--depth"; Size = "--size");
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Db(Vec<String>);
+
+    #[test]
+    fn extract() {
+        assert!(extract_impl().is_ok());
+    }
+
+    fn build_parser<'a>() -> Result<AFwdParser<'a>, Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.set_app_data(Db(vec!["a".to_owned(), "b".to_owned()]));
+        parser.add_opt("--depth=i")?;
+        parser.add_opt("--size=i")?;
+        parser.add_opt("--name=s")?.on(extract_handler(
+            |name: Value<String>, depth: OptVal<Depth, i64>, db: Service<Db>| {
+                Ok(Some(format!("{}-{}-{}", name.0, depth.0, db.0 .0.join(""))))
+            },
+        ))?;
+
+        let count = parser.add_opt("--count=i")?.run()?;
+
+        parser.invoker_mut()?.entry(count).on(extract_handler(
+            move |cnt: Value<i64>, size: Option<OptVal<Size, i64>>, uid: Uid| {
+                assert_eq!(uid, count);
+                Ok(Some(cnt.into_inner() + size.map(|v| *v).unwrap_or(0)))
+            },
+        ));
+        parser
+            .add_opt("--db=b")?
+            .on(extract_handler(|_: Service<String>| Ok(Some(true))))?;
+        Ok(parser)
+    }
+
+    fn extract_impl() -> Result<(), Error> {
+        let mut parser = build_parser()?;
+
+        parser.parse(Args::from(["app", "--depth=4", "--name", "x", "--count=2"]))?;
+        assert_eq!(parser.find_val::<String>("--name")?, "x-4-ab");
+        assert_eq!(parser.find_val::<i64>("--count")?, &2);
+
+        let mut parser = build_parser()?;
+
+        parser.parse(Args::from(["app", "--size=4", "--count=2"]))?;
+        assert_eq!(parser.find_val::<i64>("--count")?, &6);
+
+        // `--depth` has no value
+        let ret = build_parser()?.parse(Args::from(["app", "--name", "y"]))?;
+
+        assert!(!ret.status());
+
+        // `String` is not in the services
+        let ret = build_parser()?.parse(Args::from(["app", "--db"]))?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+}
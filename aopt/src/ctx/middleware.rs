@@ -0,0 +1,174 @@
+use crate::ctx::Ctx;
+use crate::Error;
+use crate::Uid;
+
+/// Middleware type using for wrapping handler invocation.
+#[cfg(not(feature = "sync"))]
+pub type InvokeMiddleware<'a, Set> = Box<dyn Middleware<Set> + 'a>;
+
+/// Middleware type using for wrapping handler invocation.
+#[cfg(feature = "sync")]
+pub type InvokeMiddleware<'a, Set> = Box<dyn Middleware<Set> + Send + Sync + 'a>;
+
+/// The middleware wrap every handler invocation of [`Invoker`](crate::ctx::Invoker),
+/// including the [`fallback`](crate::ctx::Invoker::fallback) and the async handlers.
+///
+/// The [`before`](Middleware::before) are called in the order of registration,
+/// the [`after`](Middleware::after) are called in reverse order.
+pub trait Middleware<Set> {
+    /// Called before the handler of option `uid`.
+    /// Return `Some` or an error will skip the handler and the later middlewares, the value is used as result.
+    fn before(&mut self, _uid: Uid, _set: &mut Set, _ctx: &mut Ctx) -> Result<Option<bool>, Error> {
+        Ok(None)
+    }
+
+    /// Called after the handler of option `uid` with the result, the return value replace the result.
+    fn after(
+        &mut self,
+        _uid: Uid,
+        _set: &mut Set,
+        _ctx: &mut Ctx,
+        ret: Result<bool, Error>,
+    ) -> Result<bool, Error> {
+        ret
+    }
+}
+
+/// A [`Middleware`] only has [`before`](Middleware::before).
+pub struct Before<F>(pub F);
+
+impl<Set, F> Middleware<Set> for Before<F>
+where
+    F: FnMut(Uid, &mut Set, &mut Ctx) -> Result<Option<bool>, Error>,
+{
+    fn before(&mut self, uid: Uid, set: &mut Set, ctx: &mut Ctx) -> Result<Option<bool>, Error> {
+        (self.0)(uid, set, ctx)
+    }
+}
+
+/// A [`Middleware`] only has [`after`](Middleware::after).
+pub struct After<F>(pub F);
+
+impl<Set, F> Middleware<Set> for After<F>
+where
+    F: FnMut(Uid, &mut Set, &mut Ctx, Result<bool, Error>) -> Result<bool, Error>,
+{
+    fn after(
+        &mut self,
+        uid: Uid,
+        set: &mut Set,
+        ctx: &mut Ctx,
+        ret: Result<bool, Error>,
+    ) -> Result<bool, Error> {
+        (self.0)(uid, set, ctx, ret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use crate::ctx::Middleware;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[derive(Clone, Default)]
+    struct Audit(Arc<Mutex<Vec<String>>>);
+
+    impl Audit {
+        fn push(&self, msg: String) {
+            self.0.lock().unwrap().push(msg);
+        }
+
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    impl<Set: crate::set::Set> Middleware<Set> for Audit {
+        fn before(&mut self, uid: Uid, set: &mut Set, _: &mut Ctx) -> Result<Option<bool>, Error> {
+            self.push(format!("before {}", set.opt(uid)?.name()));
+            Ok(None)
+        }
+
+        fn after(
+            &mut self,
+            uid: Uid,
+            set: &mut Set,
+            ctx: &mut Ctx,
+            ret: Result<bool, Error>,
+        ) -> Result<bool, Error> {
+            let name = set.opt(uid)?.name();
+            let arg = ctx.arg()?.map(|v| v.to_string_lossy().to_string());
+
+            // redact the secret in the audit log
+            if name == "--token" {
+                self.push(format!("after {name} = ***"));
+            } else {
+                self.push(format!("after {name} = {arg:?}"));
+            }
+            ret
+        }
+    }
+
+    #[test]
+    fn middleware() {
+        assert!(middleware_impl().is_ok());
+    }
+
+    fn middleware_impl() -> Result<(), Error> {
+        let audit = Audit::default();
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--token=s")?;
+        parser
+            .add_opt("--count=i")?
+            .on(|_: &mut AHCSet, ctx: &mut Ctx| {
+                let cnt = ctx.value::<i64>()?;
+
+                if cnt < 0 {
+                    Err(crate::error!("count must be positive"))
+                } else {
+                    Ok(Some(cnt))
+                }
+            })?;
+        parser.add_opt("--dry=b")?;
+
+        let inv = parser.invoker_mut()?;
+
+        inv.add_middleware(audit.clone())
+            // skip the handler of `--dry`
+            .add_before(|uid, set: &mut AHCSet, _: &mut Ctx| {
+                Ok((set.opt(uid)?.name() == "--dry").then_some(true))
+            })
+            // convert the error into a failure
+            .add_after(|_, _: &mut AHCSet, _: &mut Ctx, ret: Result<bool, Error>| {
+                ret.map_err(|e| Error::sp_extract(e.to_string()))
+            });
+
+        let ret = parser.parse(Args::from(["app", "--token=abc", "--count=2", "--dry"]))?;
+
+        assert!(ret.status());
+        assert_eq!(parser.find_val::<String>("--token")?, "abc");
+        assert_eq!(parser.find_val::<i64>("--count")?, &2);
+        assert_eq!(parser.find_val::<bool>("--dry")?, &false);
+        assert_eq!(
+            audit.take(),
+            [
+                "before --token",
+                "after --token = ***",
+                "before --count",
+                r#"after --count = Some("2")"#,
+                "before --dry",
+                r#"after --dry = Some("true")"#
+            ]
+        );
+
+        let ret = parser.parse(Args::from(["app", "--count=-1"]))?;
+
+        // the error converted to failure by middleware
+        assert!(!ret.status());
+        Ok(())
+    }
+}
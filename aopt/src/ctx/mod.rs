@@ -1,13 +1,45 @@
+pub(crate) mod asyncs;
+pub(crate) mod bind;
+pub(crate) mod extract;
 #[cfg_attr(feature = "sync", path = "../sync/ctx/invoke.rs")]
 #[cfg_attr(not(feature = "sync"), path = "invoke.rs")]
 pub(crate) mod invoke;
+pub(crate) mod middleware;
 pub(crate) mod store;
 
+pub use self::asyncs::ActionStore;
+pub use self::asyncs::AsyncHandler;
+pub use self::asyncs::AsyncHandlerCollection;
+pub use self::asyncs::AsyncHandlerEntry;
+pub use self::asyncs::AsyncInvokeHandler;
+pub use self::asyncs::AsyncWrapper;
+pub use self::asyncs::BoxedFuture;
+pub use self::bind::BindTarget;
+pub use self::bind::BindValue;
+
+pub use self::extract::extract_handler;
+pub use self::extract::Extract;
+pub use self::extract::ExtractHandler;
+pub use self::extract::OptName;
+pub use self::extract::OptVal;
+pub use self::extract::Service;
+pub use self::extract::Value;
+
 pub use self::invoke::HandlerCollection;
 pub use self::invoke::HandlerEntry;
 pub use self::invoke::HandlerEntryThen;
 pub use self::invoke::InvokeHandler;
 pub use self::invoke::Invoker;
+pub use self::middleware::After;
+pub use self::middleware::Before;
+pub use self::middleware::InvokeMiddleware;
+pub use self::middleware::Middleware;
+pub use self::store::ChannelSender;
+pub use self::store::ChannelStore;
+pub use self::store::InsertStore;
+pub use self::store::KeyedStore;
+pub use self::store::MapStore;
+pub use self::store::MutexStore;
 pub use self::store::NullStore;
 pub use self::store::Store;
 pub use self::store::VecStore;
@@ -1,4 +1,8 @@
 use std::ffi::OsStr;
+use std::marker::PhantomData;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
 
 use crate::acore::opt::Action;
 use crate::map::ErasedTy;
@@ -81,7 +85,7 @@ impl<Set, Value> Store<Set, Value> for NullStore {
 }
 
 /// Vector store, append the value to the [`ValStorer`](crate::value::ValStorer)
-/// if option's action is Action::App.
+/// if option's action is Action::App or Action::Ext.
 /// See [`Action`](crate::opt::Action) for default store.
 pub struct VecStore;
 
@@ -109,7 +113,7 @@ where
                 let act = *opt.action();
                 let (raw_handler, handler) = opt.accessor_mut().handlers();
 
-                if act.is_app() {
+                if act.is_app() || act.is_ext() {
                     if let Some(raw) = raw {
                         raw_handler.push(raw.to_os_string());
                     }
@@ -126,6 +130,337 @@ where
     }
 }
 
+fn lock<T>(val: &Mutex<T>) -> Result<std::sync::MutexGuard<'_, T>, Error> {
+    val.lock()
+        .map_err(|e| crate::error!("can not lock the value of store: {e:?}"))
+}
+
+/// Insert store, insert the value into a shared collection such as [`HashSet`](std::collections::HashSet)
+/// or [`BTreeSet`](std::collections::BTreeSet).
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # use std::sync::Arc;
+/// # use std::sync::Mutex;
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::InsertStore;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let tags = Arc::new(Mutex::new(BTreeSet::new()));
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--tag=s")?
+///     .on(|_, ctx: &mut Ctx| ctx.value::<String>().map(Some))?
+///     .then(InsertStore::new(tags.clone()));
+/// parser.parse(Args::from(["app", "--tag=b", "--tag=a", "--tag=b"]))?;
+///
+/// assert_eq!(*tags.lock().unwrap(), BTreeSet::from(["a".to_owned(), "b".to_owned()]));
+/// #   Ok(())
+/// # }
+/// ```
+pub struct InsertStore<C>(Arc<Mutex<C>>);
+
+impl<C> InsertStore<C> {
+    pub fn new(collection: Arc<Mutex<C>>) -> Self {
+        Self(collection)
+    }
+}
+
+impl<Set, Value, C> Store<Set, Value> for InsertStore<C>
+where
+    C: Extend<Value>,
+{
+    type Ret = bool;
+
+    type Error = Error;
+
+    fn process(
+        &mut self,
+        _: Uid,
+        _: &mut Set,
+        _: Option<&OsStr>,
+        val: Option<Value>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let has_value = val.is_some();
+
+        lock(&self.0)?.extend(val);
+        Ok(has_value)
+    }
+}
+
+/// Keyed store, insert the value into a shared map such as [`HashMap`](std::collections::HashMap),
+/// the key is the name of option.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use std::sync::Mutex;
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::KeyedStore;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let limits = Arc::new(Mutex::new(HashMap::new()));
+/// let mut parser = AFwdParser::default();
+///
+/// for name in ["--cpu=i", "--mem=i"] {
+///     parser
+///         .add_opt(name)?
+///         .on(|_, ctx: &mut Ctx| ctx.value::<i64>().map(Some))?
+///         .then(KeyedStore::new(limits.clone()));
+/// }
+/// parser.parse(Args::from(["app", "--cpu=4", "--mem=1024"]))?;
+///
+/// let limits = limits.lock().unwrap();
+///
+/// assert_eq!(limits.get("--cpu"), Some(&4));
+/// assert_eq!(limits.get("--mem"), Some(&1024));
+/// #   Ok(())
+/// # }
+/// ```
+pub struct KeyedStore<M>(Arc<Mutex<M>>);
+
+impl<M> KeyedStore<M> {
+    pub fn new(map: Arc<Mutex<M>>) -> Self {
+        Self(map)
+    }
+}
+
+impl<Set, Value, M> Store<Set, Value> for KeyedStore<M>
+where
+    Set: crate::set::Set,
+    SetOpt<Set>: Opt,
+    M: Extend<(String, Value)>,
+{
+    type Ret = bool;
+
+    type Error = Error;
+
+    fn process(
+        &mut self,
+        uid: Uid,
+        set: &mut Set,
+        _: Option<&OsStr>,
+        val: Option<Value>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let has_value = val.is_some();
+
+        if let Some(val) = val {
+            let name = set.opt(uid)?.name().to_string();
+
+            lock(&self.0)?.extend([(name, val)]);
+        }
+        Ok(has_value)
+    }
+}
+
+/// Mutex store, write the value into a shared value.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use std::sync::Mutex;
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::MutexStore;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let level = Arc::new(Mutex::new(0));
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--level=i")?
+///     .on(|_, ctx: &mut Ctx| ctx.value::<i64>().map(Some))?
+///     .then(MutexStore::new(level.clone()));
+/// parser.parse(Args::from(["app", "--level=2", "--level=3"]))?;
+///
+/// assert_eq!(*level.lock().unwrap(), 3);
+/// #   Ok(())
+/// # }
+/// ```
+pub struct MutexStore<T>(Arc<Mutex<T>>);
+
+impl<T> MutexStore<T> {
+    pub fn new(value: Arc<Mutex<T>>) -> Self {
+        Self(value)
+    }
+}
+
+impl<Set, Value> Store<Set, Value> for MutexStore<Value> {
+    type Ret = bool;
+
+    type Error = Error;
+
+    fn process(
+        &mut self,
+        _: Uid,
+        _: &mut Set,
+        _: Option<&OsStr>,
+        val: Option<Value>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let has_value = val.is_some();
+
+        if let Some(val) = val {
+            *lock(&self.0)? = val;
+        }
+        Ok(has_value)
+    }
+}
+
+/// The sender can be used in [`ChannelStore`].
+pub trait ChannelSender<T> {
+    fn send_value(&self, val: T) -> Result<(), Error>;
+}
+
+impl<T> ChannelSender<T> for mpsc::Sender<T> {
+    fn send_value(&self, val: T) -> Result<(), Error> {
+        self.send(val)
+            .map_err(|_| crate::error!("can not send value, the receiver is disconnected"))
+    }
+}
+
+impl<T> ChannelSender<T> for mpsc::SyncSender<T> {
+    fn send_value(&self, val: T) -> Result<(), Error> {
+        self.send(val)
+            .map_err(|_| crate::error!("can not send value, the receiver is disconnected"))
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<T> ChannelSender<T> for crossbeam_channel::Sender<T> {
+    fn send_value(&self, val: T) -> Result<(), Error> {
+        self.send(val)
+            .map_err(|_| crate::error!("can not send value, the receiver is disconnected"))
+    }
+}
+
+/// Channel store, send the value into a channel.
+/// It support [`Sender`](std::sync::mpsc::Sender), [`SyncSender`](std::sync::mpsc::SyncSender),
+/// and the `Sender` of `crossbeam-channel` if feature `crossbeam` enabled.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::mpsc;
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::ChannelStore;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let (tx, rx) = mpsc::channel();
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--url=s")?
+///     .on(|_, ctx: &mut Ctx| ctx.value::<String>().map(Some))?
+///     .then(ChannelStore::new(tx));
+/// parser.parse(Args::from(["app", "--url=a", "--url=b"]))?;
+///
+/// assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["a", "b"]);
+/// #   Ok(())
+/// # }
+/// ```
+pub struct ChannelStore<T>(T);
+
+impl<T> ChannelStore<T> {
+    pub fn new(sender: T) -> Self {
+        Self(sender)
+    }
+}
+
+impl<Set, Value, T> Store<Set, Value> for ChannelStore<T>
+where
+    T: ChannelSender<Value>,
+{
+    type Ret = bool;
+
+    type Error = Error;
+
+    fn process(
+        &mut self,
+        _: Uid,
+        _: &mut Set,
+        _: Option<&OsStr>,
+        val: Option<Value>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let has_value = val.is_some();
+
+        if let Some(val) = val {
+            self.0.send_value(val)?;
+        }
+        Ok(has_value)
+    }
+}
+
+/// Map store, transform the return value of handler before passing it to the inner store.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::ctx::MapStore;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser
+///     .add_opt("--name=s")?
+///     .on(|_, ctx: &mut Ctx| ctx.value::<String>().map(Some))?
+///     .then(MapStore::new(|val: String| val.to_uppercase(), Action::Set));
+/// parser.parse(Args::from(["app", "--name=foo"]))?;
+///
+/// assert_eq!(parser.find_val::<String>("--name")?, "FOO");
+/// #   Ok(())
+/// # }
+/// ```
+pub struct MapStore<F, S, T> {
+    mapper: F,
+
+    store: S,
+
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<F, S, T> MapStore<F, S, T> {
+    pub fn new(mapper: F, store: S) -> Self {
+        Self {
+            mapper,
+            store,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Set, Value, F, S, T> Store<Set, Value> for MapStore<F, S, T>
+where
+    F: FnMut(Value) -> T,
+    S: Store<Set, T>,
+{
+    type Ret = S::Ret;
+
+    type Error = S::Error;
+
+    fn process(
+        &mut self,
+        uid: Uid,
+        set: &mut Set,
+        raw: Option<&OsStr>,
+        val: Option<Value>,
+    ) -> Result<Self::Ret, Self::Error> {
+        let val = val.map(&mut self.mapper);
+
+        self.store.process(uid, set, raw, val)
+    }
+}
+
 /// Default store using for store value to [`ValStorer`](crate::value::ValStorer).
 /// It will store `OsString` and `Val` if `val` is `Some(Val)`, otherwise do nothing.
 ///
@@ -141,6 +476,14 @@ where
 ///
 /// * [`Action::Clr`] : Clear all the value from value vector.
 ///
+/// * [`Action::AppUniq`] : Append the value to value vector if the raw value not saved before.
+///
+/// * [`Action::Ext`] : Append the value to value vector.
+///
+/// * [`Action::SetOnce`] : Same as [`Action::Set`], the policy will raise an error on second occurrence.
+///
+/// * [`Action::Toggle`] : Flip the last boolean value, or save `vec![true]` if no value, only available for [`bool`].
+///
 /// * [`Action::Null`] : Do nothing.
 impl<Set, Val> Store<Set, Val> for Action
 where
@@ -4,16 +4,20 @@ use std::ffi::OsStr;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
+use crate::opt::Opt;
 use crate::opt::Style;
 use crate::opt::BOOL_TRUE;
 use crate::parser::FailManager;
 use crate::parser::UserStyle;
 use crate::set::OptValidator;
+use crate::set::SetExt;
 use crate::str::CowStrUtils;
 use crate::trace;
 use crate::Error;
 
+use super::invoke_handler;
 use super::process_handler_ret;
+use super::process_occurrence;
 use super::style::*;
 use super::GuessPolicy;
 use super::InnerCtxSaver;
@@ -163,7 +167,7 @@ where
     S: crate::set::Set + OptValidator,
     Inv: HandlerCollection<'c, S>,
 {
-    pub fn guess_and_invoke(
+    pub async fn guess_and_invoke(
         &mut self,
         style: &UserStyle,
         overload: bool,
@@ -177,7 +181,7 @@ where
                     GuessPolicy::<MainStyle, SingleNonOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, true)?;
+                        matched = self.invoke(&mut policy, true).await?;
                     }
                 }
             }
@@ -186,7 +190,7 @@ where
                     GuessPolicy::<PosStyle, SingleNonOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, true)?;
+                        matched = self.invoke(&mut policy, true).await?;
                     }
                 }
             }
@@ -195,7 +199,7 @@ where
                     GuessPolicy::<CmdStyle, SingleNonOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, true)?;
+                        matched = self.invoke(&mut policy, true).await?;
                     }
                 }
             }
@@ -204,7 +208,7 @@ where
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        matched = self.invoke(&mut policy, false).await?;
                     }
                 }
             }
@@ -214,7 +218,7 @@ where
                 {
                     consume = true;
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        matched = self.invoke(&mut policy, false).await?;
                     }
                 }
             }
@@ -223,7 +227,7 @@ where
                     GuessPolicy::<EmbeddedValueStyle, SingleOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        matched = self.invoke(&mut policy, false).await?;
                     }
                 }
             }
@@ -234,7 +238,7 @@ where
                 >::guess_policy(self)?
                 {
                     if self.match_multi(&mut policy, overload, consume)? {
-                        matched = self.invoke_multi(&mut policy, false)?;
+                        matched = self.invoke_multi(&mut policy, false).await?;
                     }
                 }
             }
@@ -245,7 +249,7 @@ where
                 >::guess_policy(self)?
                 {
                     if self.match_multi(&mut policy, overload, consume)? {
-                        matched = self.invoke_multi(&mut policy, false)?;
+                        matched = self.invoke_multi(&mut policy, false).await?;
                     }
                 }
             }
@@ -254,7 +258,7 @@ where
                     GuessPolicy::<BooleanStyle, SingleOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        matched = self.invoke(&mut policy, false).await?;
                     }
                 }
             }
@@ -263,7 +267,7 @@ where
                     GuessPolicy::<FlagStyle, SingleOpt<S>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        matched = self.invoke(&mut policy, false).await?;
                     }
                 }
             }
@@ -593,19 +597,21 @@ where
     type Error = Error;
 
     fn guess_policy(&mut self) -> Result<Option<T>, Self::Error> {
-        let arg = Some(Cow::Borrowed(OsStr::new(BOOL_TRUE)));
-
-        if self.arg.is_none() {
-            if let Some(name) = &self.name {
-                return Ok(Some(
-                    T::default()
-                        .with_idx(self.idx)
-                        .with_tot(self.total)
-                        .with_name(Some(name.clone()))
-                        .with_arg(arg)
-                        .with_style(Style::Boolean),
-                ));
-            }
+        // using the value if user set it explicitly, such as `--bool=no`
+        let arg = self
+            .arg
+            .clone()
+            .or_else(|| Some(Cow::Borrowed(OsStr::new(BOOL_TRUE))));
+
+        if let Some(name) = &self.name {
+            return Ok(Some(
+                T::default()
+                    .with_idx(self.idx)
+                    .with_tot(self.total)
+                    .with_name(Some(name.clone()))
+                    .with_arg(arg)
+                    .with_style(Style::Boolean),
+            ));
         }
         Ok(None)
     }
@@ -773,7 +779,7 @@ where
         Ok(policy.matched())
     }
 
-    pub fn invoke<T>(&mut self, policy: &mut T, all: bool) -> Result<bool, Error>
+    pub async fn invoke<T>(&mut self, policy: &mut T, all: bool) -> Result<bool, Error>
     where
         T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
     {
@@ -783,6 +789,7 @@ where
             .with_name(policy.name().cloned())
             .with_arg(policy.arg().cloned())
             .with_style(policy.style());
+        let source = inner_ctx.source();
         let uids = policy.uids().to_vec();
         let mut result = false;
 
@@ -790,9 +797,26 @@ where
             self.ctx
                 .set_inner_ctx(Some(inner_ctx.clone().with_uid(uid)));
 
+            // ignore the occurrence, but treat it as matched
+            if !process_occurrence(self.set, uid, policy.idx())? {
+                result = true;
+                policy.apply(uid, self.set).map_err(Into::into)?;
+                if !all {
+                    break;
+                }
+                continue;
+            }
+
             // invoke the handler of `uid`
-            let invoke_ret = self.inv.invoke_fb(&uid, self.set, self.ctx);
-            let when_fail = |e| {
+            let invoke_ret = invoke_handler(self.set, self.inv, self.ctx, uid).await;
+            let when_fail = |mut e: Error| {
+                // keep the argument and option caused the failure
+                if e.index().is_none() {
+                    e = e.with_index(policy.idx());
+                }
+                if e.name().is_none() {
+                    e = e.with_name(self.set.opt(uid)?.name());
+                }
                 self.fail.push(e);
                 Ok(())
             };
@@ -800,6 +824,11 @@ where
             // return first index if handler success
             if process_handler_ret(invoke_ret, |_| Ok(()), when_fail)? {
                 result = true;
+                let opt = self.set.opt_mut(uid)?;
+                let act = *opt.action();
+
+                opt.add_position(policy.idx());
+                opt.accessor_mut().add_source(source.clone(), &act);
                 policy.apply(uid, self.set).map_err(Into::into)?;
                 if !all {
                     // may return if first matched, for option
@@ -835,7 +864,7 @@ where
         })
     }
 
-    pub fn invoke_multi<T>(
+    pub async fn invoke_multi<T>(
         &mut self,
         policy: &mut MultiOpt<T, Set>,
         all: bool,
@@ -847,7 +876,7 @@ where
         let any_match = policy.any_match();
 
         for sub_policy in policy.sub_policys_mut().iter_mut() {
-            if self.invoke(sub_policy, all)? {
+            if self.invoke(sub_policy, all).await? {
                 matched = true;
                 if any_match {
                     // any match, return current
@@ -6,9 +6,20 @@ pub mod style;
 
 use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::ffi::OsString;
 
+use crate::ctx::Ctx;
+use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
+use crate::opt::Duplicate;
+use crate::opt::Opt;
 use crate::opt::Style;
+use crate::set::Set;
+use crate::set::SetExt;
+use crate::set::SetOpt;
+use crate::str::split_all;
+use crate::trace;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -189,3 +200,232 @@ pub fn process_handler_ret(
         }
     }
 }
+
+/// Invoke the handler of option `uid` with the argument saved in `ctx`.
+///
+/// For option with [`Action::Ext`](crate::opt::Action::Ext), the argument is split by the
+/// [`separator`](Opt::separator) of option (`,` if not set), and the handler is invoked for each part.
+pub async fn invoke_handler<'b, S, I>(
+    set: &mut S,
+    inv: &mut I,
+    ctx: &mut Ctx<'_>,
+    uid: Uid,
+) -> Result<bool, Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+    I: HandlerCollection<'b, S>,
+{
+    let opt = set.opt(uid)?;
+
+    if !opt.action().is_ext() {
+        return inv.invoke_fb_async(&uid, set, ctx).await;
+    }
+    let Some(arg) = ctx.arg()?.cloned() else {
+        return inv.invoke_fb_async(&uid, set, ctx).await;
+    };
+    let seps = opt
+        .separator()
+        .map(|v| v.seps().to_vec())
+        .unwrap_or_else(|| vec![',']);
+    let mut matched = false;
+
+    for part in split_all(&arg, &seps) {
+        ctx.set_arg(Some(Cow::Owned(part.into_owned())))?;
+        matched |= inv.invoke_fb_async(&uid, set, ctx).await?;
+    }
+    ctx.set_arg(Some(arg))?;
+    Ok(matched)
+}
+
+/// Check the [`Occurrence`](crate::opt::Occurrence) rule of option before invoke the handler,
+/// `idx` is the position of current argument.
+/// Return false if current occurrence should be ignored, such as [`FirstWins`](Duplicate::FirstWins).
+pub fn process_occurrence<S: Set>(set: &mut S, uid: Uid, idx: usize) -> Result<bool, Error> {
+    let opt = set.opt_mut(uid)?;
+    let rule = opt.occurrence().clone();
+    let positions = opt.positions();
+    // the option with `Action::SetOnce` can not appear twice
+    let duplicate = if opt.action().is_set_once() {
+        Duplicate::Error
+    } else {
+        rule.duplicate()
+    };
+
+    trace!(
+        "checking occurrence {rule:?} of `{}` @ {idx}: {positions:?}",
+        opt.name()
+    );
+    if let Some(&first) = positions.first() {
+        match duplicate {
+            Duplicate::Error => {
+                return Err(Error::sp_opt_duplicate(opt.name(), first, idx).with_uid(uid));
+            }
+            Duplicate::FirstWins => return Ok(false),
+            Duplicate::LastWins | Duplicate::Allow => {}
+        }
+    }
+    if let (Some(max), Some(&last)) = (rule.max(), positions.last()) {
+        if rule.exceed(positions.len()) {
+            return Err(Error::sp_opt_too_many(opt.name(), max, last, idx).with_uid(uid));
+        }
+    }
+    if !positions.is_empty() && duplicate == Duplicate::LastWins {
+        let accessor = opt.accessor_mut();
+
+        accessor.sources_mut().clear();
+        let (raw, val) = accessor.handlers();
+
+        raw.clear();
+        val.clear();
+    }
+    Ok(true)
+}
+
+/// Return the style used to invoke the option if it is an unmatched option,
+/// which can accept the value from environment variable or configuration.
+fn fallback_style<O: Opt>(opt: &O) -> Option<Style> {
+    let like_opt = [
+        Style::Argument,
+        Style::Boolean,
+        Style::Combined,
+        Style::Flag,
+    ]
+    .into_iter()
+    .any(|style| opt.mat_style(style));
+
+    (like_opt && !opt.matched()).then(|| {
+        if opt.mat_style(Style::Argument) {
+            Style::Argument
+        } else {
+            Style::Boolean
+        }
+    })
+}
+
+async fn invoke_fallback<'a, 'b, S, I>(
+    set: &mut S,
+    inv: &mut I,
+    ctx: &mut Ctx<'a>,
+    uid: Uid,
+    style: Style,
+    val: OsString,
+    source: ValueSource,
+) -> Result<bool, Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+    I: HandlerCollection<'b, S>,
+{
+    let name = set.opt(uid)?.name().to_owned();
+    // the value not from command line is recorded at the position after last argument
+    let position = ctx.orig().len();
+
+    ctx.set_inner_ctx(Some(
+        InnerCtx::default()
+            .with_uid(uid)
+            .with_name(Some(Cow::Owned(name)))
+            .with_style(style)
+            .with_arg(Some(Cow::Owned(val)))
+            .with_source(Some(source.clone())),
+    ));
+    let matched = invoke_handler(set, inv, ctx, uid).await?;
+
+    if matched {
+        let opt = set.opt_mut(uid)?;
+        let act = *opt.action();
+
+        opt.add_position(position);
+        opt.accessor_mut().add_source(source, &act);
+    }
+    Ok(matched)
+}
+
+/// Invoke the handler of options those are not matched but have an environment variable set,
+/// the value of environment variable is passed as the argument of option.
+/// The option will be treated as matched if the handler returns true,
+/// the failure of handler is returned directly because the value is not come from command line.
+pub async fn process_env<'a, 'b, S, I>(
+    set: &mut S,
+    inv: &mut I,
+    ctx: &mut Ctx<'a>,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+    I: HandlerCollection<'b, S>,
+{
+    let mut envs = vec![];
+
+    for opt in set.iter() {
+        if let Some(style) = fallback_style(opt) {
+            if let Some(env) = opt.env() {
+                if let Some(val) = std::env::var_os(env) {
+                    envs.push((opt.uid(), style, env.to_owned(), val));
+                }
+            }
+        }
+    }
+    for (uid, style, name, val) in envs {
+        trace!("using environment value {val:?} for {uid}");
+        let source = ValueSource::Env { name };
+
+        if invoke_fallback(set, inv, ctx, uid, style, val, source).await? {
+            set.opt_mut(uid)?.set_matched(true);
+        }
+    }
+    Ok(())
+}
+
+/// Invoke the handler of options those are not matched with the values of
+/// [`ConfigLayer`](crate::set::ConfigLayer)s, the layer added later has higher priority.
+/// Every value of the key is passed to the option like it appears multiple times.
+/// The failure of handler is returned with the origin and key of the value.
+pub async fn process_layers<'a, 'b, S, I>(
+    set: &mut S,
+    inv: &mut I,
+    ctx: &mut Ctx<'a>,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+    I: HandlerCollection<'b, S>,
+{
+    let mut values = vec![];
+
+    for opt in set.iter() {
+        if let Some(style) = fallback_style(opt) {
+            let mut names = vec![opt.name()];
+
+            if let Some(alias) = opt.alias() {
+                names.extend(alias.iter().map(String::as_str));
+            }
+            if let Some((origin, key, vals)) = set.layers().iter().rev().find_map(|layer| {
+                layer
+                    .find(&names)
+                    .map(|(key, vals)| (layer.origin(), layer.key_path(key), vals))
+            }) {
+                values.push((opt.uid(), style, origin.to_owned(), key, vals.to_vec()));
+            }
+        }
+    }
+    for (uid, style, origin, key, vals) in values {
+        let mut matched = false;
+
+        trace!("using values {vals:?} of `{key}` in `{origin}` for {uid}");
+        for val in vals {
+            let source = ValueSource::Config {
+                origin: origin.clone(),
+                key: key.clone(),
+            };
+
+            matched |= invoke_fallback(set, inv, ctx, uid, style, val.into(), source)
+                .await
+                .map_err(|e| Error::sp_config_value(&origin, &key).cause_by(e))?;
+        }
+        if matched {
+            set.opt_mut(uid)?.set_matched(true);
+        }
+    }
+    Ok(())
+}
@@ -41,6 +41,14 @@ pub struct GetoptRes<R, T> {
 
 /// Parse the string sequence with given [`Parser`](crate::parser::Parser).
 ///
+/// Each [`Parser`](crate::parser::Parser) is scored by [`score`](crate::parser::Parser::score),
+/// which parse in a [`Transaction`](crate::parser::Transaction) without invoking the handlers and roll it back,
+/// the return value of [`Policy`](crate::parser::Policy) must implement [`Scoring`](crate::parser::Scoring).
+/// Then the [`Parser`](crate::parser::Parser)s parse the arguments by [`parse_tx`](crate::parser::Parser::parse_tx)
+/// in descending order of [`Score`](crate::parser::Score), the first one declared is tried first if the scores are same,
+/// until one of them successes.
+/// The values of failed parsers are rolled back, their bound variables and [`Deferred`](crate::parser::Deferred) works are dropped.
+///
 /// # Returns
 ///
 /// For style `getopt!(..., &mut parser1, &mut parser2)`,
@@ -48,7 +56,8 @@ pub struct GetoptRes<R, T> {
 /// For style `getopt!(..., "first" => &mut parser1, "second" => &mut parser2)`,
 /// will return an Ok([`GetoptRes`]\(T is the literal type\)) if any [`Parser`](crate::parser::Parser) parsing successed.
 ///
-/// Will return Err([`Error::no_parser_matched()`]) if all [`Parser`](crate::parser::Parser) parsing failed, otherwise return Err(_).
+/// Will return Err([`Error::sp_no_parser_matched()`]) if all [`Parser`](crate::parser::Parser) parsing failed,
+/// the reason of each parser can be accessed by [`fails`](Error::fails).
 /// # Example
 ///
 /// ```rust
@@ -134,75 +143,64 @@ macro_rules! getopt {
         getopt!($args, $(&mut $parser_left)+)
     };
     ($args:expr, $(&mut $parser_left:path),+) => {
-        {
-            fn __check_p<'b, S: $crate::prelude::Set, P: $crate::prelude::Policy<Set = S, Error = $crate::Error>>
-                (p: &'b mut $crate::prelude::Parser<S, P>) -> &'b mut $crate::prelude::Parser<S, P>
-                { p }
-            fn __check_a(a: $crate::prelude::Args) -> $crate::prelude::Args { a }
-
-            let mut ret = $crate::Error::no_parser_matched();
-            let args = __check_a($args);
-
-            loop {
-                $(
-                    let parser = __check_p(&mut $parser_left);
-
-                    match $crate::parser::Parser::parse(parser, args.clone()) {
-                        Ok(mut parser_ret) => {
-                            if let Some(error) = parser_ret.take_failure() {
-                                ret = error;
-                            }
-                            else {
-                                break Ok($crate::GetoptRes {
-                                    ret: parser_ret,
-                                    parser: parser,
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            ret = e;
-                        }
-                    }
-                )+
-                break Err(ret);
-            }
-        }
+        getopt!(@select $args, __parser, $(stringify!($parser_left) => &mut $parser_left => __parser),+)
     };
     ($args:expr, $($parser_name:literal => $parser_left:path),+) => {
         getopt!($args, $($parser_name => &mut $parser_left)+)
     };
     ($args:expr, $($parser_name:literal => &mut $parser_left:path),+) => {
+        getopt!(@select $args, __parser, $($parser_name => &mut $parser_left => $parser_name),+)
+    };
+    (@select $args:expr, $parser:ident, $($parser_name:expr => &mut $parser_left:path => $parser_ret:expr),+) => {
         {
-            fn __check_p<'b, S: $crate::prelude::Set, P: $crate::prelude::Policy<Set = S, Error = $crate::Error>>
-                (p: &'b mut $crate::prelude::Parser<S, P>) -> &'b mut $crate::prelude::Parser<S, P>
+            fn __check_p<S: $crate::prelude::Set, P: $crate::prelude::Policy<Set = S, Error = $crate::Error>>
+                (p: &mut $crate::prelude::Parser<S, P>) -> &mut $crate::prelude::Parser<S, P>
                 { p }
             fn __check_a(a: $crate::prelude::Args) -> $crate::prelude::Args { a }
 
-            let mut ret = $crate::Error::no_parser_matched();
             let args = __check_a($args);
+            let mut scores = vec![];
+            let mut fails = vec![];
+            let mut index = 0..;
+
+            // score each parser without invoking the handlers, the parsing is rolled back
+            $(
+                let idx = index.next().unwrap();
+
+                match $crate::parser::Parser::score(__check_p(&mut $parser_left), args.clone()) {
+                    Ok(score) => scores.push((idx, score)),
+                    Err(e) => fails.push((format!("{}", $parser_name), e)),
+                }
+            )+
+
+            // try the parsers in descending order of score, keep the order of same score
+            scores.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+            let mut scores = scores.into_iter();
 
             loop {
+                let Some((best, _)) = scores.next() else {
+                    break Err($crate::Error::sp_no_parser_matched(fails));
+                };
+                let mut index = 0..;
+
                 $(
-                    let parser = __check_p(&mut $parser_left);
+                    if index.next() == Some(best) {
+                        let $parser = __check_p(&mut $parser_left);
 
-                    match $crate::parser::Parser::parse(parser, args.clone()) {
-                        Ok(mut parser_ret) => {
-                            if let Some(error) = parser_ret.take_failure() {
-                                ret = error;
-                            }
-                            else {
-                                break Ok($crate::GetoptRes {
+                        match $crate::parser::Parser::parse_tx($parser, args.clone()) {
+                            Ok(mut parser_ret) => match $crate::parser::Scoring::take_failure(&mut parser_ret) {
+                                Some(e) => fails.push((format!("{}", $parser_name), e)),
+                                None => break Ok($crate::GetoptRes {
                                     ret: parser_ret,
-                                    parser: $parser_name,
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            ret = e;
+                                    parser: $parser_ret,
+                                }),
+                            },
+                            Err(e) => fails.push((format!("{}", $parser_name), e)),
                         }
+                        continue;
                     }
                 )+
-                break Err(ret);
             }
         }
     };
@@ -210,14 +208,24 @@ macro_rules! getopt {
 
 pub mod prelude {
     pub use crate::args::Args;
+    pub use crate::ctx::extract_handler;
     pub use crate::ctx::wrap_handler;
     pub use crate::ctx::wrap_handler_action;
     pub use crate::ctx::wrap_handler_fallback_action;
+    pub use crate::ctx::AsyncHandlerCollection;
+    pub use crate::ctx::ChannelStore;
     pub use crate::ctx::Ctx;
+    pub use crate::ctx::Extract;
     pub use crate::ctx::HandlerCollection;
     pub use crate::ctx::InnerCtx;
+    pub use crate::ctx::InsertStore;
     pub use crate::ctx::Invoker;
+    pub use crate::ctx::KeyedStore;
+    pub use crate::ctx::MapStore;
+    pub use crate::ctx::Middleware;
+    pub use crate::ctx::MutexStore;
     pub use crate::ctx::NullStore;
+    pub use crate::ctx::OptName;
     pub use crate::ctx::Store;
     pub use crate::ctx::VecStore;
     pub use crate::getopt;
@@ -232,15 +240,19 @@ pub mod prelude {
     pub use crate::opt::ConfigBuilder;
     pub use crate::opt::ConfigBuilderWith;
     pub use crate::opt::ConfigValue;
+    pub use crate::opt::Constraint;
     pub use crate::opt::ConstrctInfo;
     pub use crate::opt::Creator;
+    pub use crate::opt::Duplicate;
     pub use crate::opt::Help;
     pub use crate::opt::Index;
     pub use crate::opt::Information;
     pub use crate::opt::Main;
     pub use crate::opt::MutOpt;
+    pub use crate::opt::Occurrence;
     pub use crate::opt::Opt;
     pub use crate::opt::OptConfig;
+    pub use crate::opt::OptGroup;
     pub use crate::opt::OptParser;
     pub use crate::opt::OptValueExt;
     pub use crate::opt::Pos;
@@ -267,6 +279,7 @@ pub mod prelude {
     pub use crate::parser::UsrValService;
     pub use crate::set::ctor_default_name;
     pub use crate::set::Commit;
+    pub use crate::set::ConfigLayer;
     pub use crate::set::Ctor;
     pub use crate::set::Filter;
     pub use crate::set::FilterMatcher;
@@ -284,7 +297,10 @@ pub mod prelude {
     pub use crate::set::SetOpt;
     pub use crate::set::SetValueFindExt;
     pub use crate::value::AnyValue;
+    pub use crate::value::BoolLiteral;
+    pub use crate::value::ColorChoice;
     pub use crate::value::ErasedValue;
+    pub use crate::value::ErrorFormat;
     pub use crate::value::Infer;
     pub use crate::value::InitializeValue;
     pub use crate::value::RawValParser;
@@ -292,6 +308,8 @@ pub mod prelude {
     pub use crate::value::ValInitializer;
     pub use crate::value::ValStorer;
     pub use crate::value::ValValidator;
+    pub use crate::value::ValueSource;
+    pub use crate::value::Verbosity;
     pub use crate::ARef;
     pub use crate::GetoptRes;
     pub use crate::Uid;
@@ -4,16 +4,19 @@ use crate::error;
 use crate::opt::Action;
 #[allow(unused)]
 use crate::opt::Cmd;
+use crate::opt::Constraint;
 #[allow(unused)]
 use crate::opt::Creator;
 use crate::opt::Help;
 use crate::opt::Index;
 #[allow(unused)]
 use crate::opt::Main;
+use crate::opt::Occurrence;
 use crate::opt::Opt;
 #[allow(unused)]
 use crate::opt::Pos;
 use crate::opt::Style;
+use crate::value::ElemSeparator;
 use crate::value::ErasedValue;
 use crate::value::ValAccessor;
 use crate::Error;
@@ -68,6 +71,16 @@ pub struct AOpt {
 
     alias: Option<Vec<String>>,
 
+    constraints: Vec<Constraint>,
+
+    occurrence: Occurrence,
+
+    positions: Vec<usize>,
+
+    env: Option<String>,
+
+    separator: Option<ElemSeparator>,
+
     action: Action,
 
     matched: bool,
@@ -95,6 +108,11 @@ impl AOpt {
             index: None,
             accessor,
             alias: None,
+            constraints: vec![],
+            occurrence: Occurrence::default(),
+            positions: vec![],
+            env: None,
+            separator: None,
             ignore_name: false,
             ignore_alias: false,
             ignore_index: false,
@@ -185,6 +203,30 @@ impl AOpt {
         self
     }
 
+    /// Set the constraints between the option and other options.
+    pub fn with_constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Set the occurrence rule of option.
+    pub fn with_occurrence(mut self, occurrence: Occurrence) -> Self {
+        self.occurrence = occurrence;
+        self
+    }
+
+    /// Set the environment variable used as fallback value of option.
+    pub fn with_env(mut self, env: Option<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Set the separator used to split the value of option.
+    pub fn with_separator(mut self, separator: Option<ElemSeparator>) -> Self {
+        self.separator = separator;
+        self
+    }
+
     /// Set the value accessor of option, it will used by [`Policy`](crate::parser::Policy);
     pub fn with_accessor(mut self, value: ValAccessor) -> Self {
         self.accessor = value;
@@ -254,6 +296,26 @@ impl AOpt {
         self
     }
 
+    pub fn add_constraint(&mut self, constraint: Constraint) -> &mut Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn set_occurrence(&mut self, occurrence: Occurrence) -> &mut Self {
+        self.occurrence = occurrence;
+        self
+    }
+
+    pub fn set_env(&mut self, env: Option<String>) -> &mut Self {
+        self.env = env;
+        self
+    }
+
+    pub fn set_separator(&mut self, separator: Option<ElemSeparator>) -> &mut Self {
+        self.separator = separator;
+        self
+    }
+
     pub fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self {
         self.ignore_name = ignore_name;
         self
@@ -273,6 +335,7 @@ impl AOpt {
 impl Opt for AOpt {
     fn reset(&mut self) {
         self.set_matched(false);
+        self.positions.clear();
     }
 
     fn uid(&self) -> Uid {
@@ -319,6 +382,30 @@ impl Opt for AOpt {
         self.alias.as_ref()
     }
 
+    fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    fn constraints_mut(&mut self) -> &mut [Constraint] {
+        &mut self.constraints
+    }
+
+    fn occurrence(&self) -> &Occurrence {
+        &self.occurrence
+    }
+
+    fn env(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+
+    fn separator(&self) -> Option<&ElemSeparator> {
+        self.separator.as_ref()
+    }
+
+    fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+
     fn accessor(&self) -> &ValAccessor {
         &self.accessor
     }
@@ -347,6 +434,10 @@ impl Opt for AOpt {
         self.matched = matched;
     }
 
+    fn add_position(&mut self, index: usize) {
+        self.positions.push(index);
+    }
+
     fn mat_style(&self, style: Style) -> bool {
         self.styles.iter().any(|v| v == &style)
     }
@@ -379,6 +470,7 @@ impl Opt for AOpt {
     }
 
     fn init(&mut self) -> Result<(), Error> {
+        self.positions.clear();
         self.accessor.initialize()
     }
 
@@ -443,6 +535,26 @@ impl Opt for AOpt {
         self
     }
 
+    fn add_constraint(&mut self, constraint: Constraint) -> &mut Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    fn set_occurrence(&mut self, occurrence: Occurrence) -> &mut Self {
+        self.occurrence = occurrence;
+        self
+    }
+
+    fn set_env(&mut self, env: Option<String>) -> &mut Self {
+        self.env = env;
+        self
+    }
+
+    fn set_separator(&mut self, separator: Option<ElemSeparator>) -> &mut Self {
+        self.separator = separator;
+        self
+    }
+
     fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self {
         self.ignore_name = ignore_name;
         self
@@ -459,7 +571,7 @@ impl Opt for AOpt {
     }
 }
 
-fn gen_hint(
+pub(crate) fn gen_hint(
     hint: Option<impl Into<String>>,
     n: &str,
     idx: Option<&Index>,
@@ -510,6 +622,10 @@ impl TryFrom<OptConfig> for AOpt {
         let storer = value.take_storer();
         let styles = value.take_style();
         let initializer = value.take_initializer();
+        let constraints = value.take_constraints();
+        let occurrence = value.take_occurrence();
+        let env = value.take_env();
+        let separator = value.take_separator();
         let ignore_name = value.ignore_name();
         let ignore_alias = value.ignore_alias();
         let ignore_index = value.ignore_index();
@@ -521,10 +637,24 @@ impl TryFrom<OptConfig> for AOpt {
             .ok_or_else(|| error!("incomplete configuration: missing ValInitializer"))?;
         let styles = styles.ok_or_else(|| error!("incomplete configuration: missing Style"))?;
         let name = name.ok_or_else(|| error!("incomplete configuration: missing option name"))?;
-        let hint = gen_hint(hint.as_ref(), &name, index.as_ref(), alias.as_ref());
+        let hint = match (hint, storer.hint()) {
+            (None, Some(val_hint)) => {
+                let names = gen_hint(None::<String>, &name, index.as_ref(), alias.as_ref());
+
+                format!("{names} {val_hint}")
+            }
+            (hint, _) => gen_hint(hint.as_ref(), &name, index.as_ref(), alias.as_ref()),
+        };
         let help = help.unwrap_or_default();
         let r#type =
             r#type.ok_or_else(|| error!("incomplete configuration: missing option value type"))?;
+
+        if action.is_toggle() && r#type != TypeId::of::<bool>() {
+            return Err(error!(
+                "can not create option `{}`: Action::Toggle only available for bool",
+                name
+            ));
+        }
         let help = Help::default().with_help(help).with_hint(hint);
 
         if ignore_alias {
@@ -559,6 +689,10 @@ impl TryFrom<OptConfig> for AOpt {
                 .with_idx(index)
                 .with_action(action)
                 .with_alias(alias)
+                .with_constraints(constraints)
+                .with_occurrence(occurrence)
+                .with_env(env)
+                .with_separator(separator)
                 .with_style(styles)
                 .with_opt_help(help)
                 .with_ignore_name(ignore_name)
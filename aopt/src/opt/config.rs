@@ -4,10 +4,13 @@ use std::marker::PhantomData;
 
 use crate::err::Error;
 use crate::opt::Action;
+use crate::opt::Constraint;
 use crate::opt::Index;
 use crate::opt::Information;
+use crate::opt::Occurrence;
 use crate::opt::OptParser;
 use crate::typeid;
+use crate::value::ElemSeparator;
 use crate::value::Placeholder;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
@@ -53,6 +56,12 @@ impl<C: ConfigValue + Default> ConfigBuild<C> for &'_ str {
         if let Some(v) = output.take_alias() {
             ret.set_alias(v);
         }
+        if let Some(v) = output.take_env() {
+            ret.set_env(v);
+        }
+        if let Some(v) = output.take_action() {
+            ret.set_action(v);
+        }
         Ok(ret)
     }
 }
@@ -130,6 +139,14 @@ where
         merge!(has_storer, set_storer, take_storer);
         merge!(has_style, set_style, take_style);
         merge!(has_initializer, set_initializer, take_initializer);
+        merge!(has_env, set_env, take_env);
+        merge!(has_separator, set_separator, take_separator);
+        for constraint in init.take_constraints() {
+            config.add_constraint(constraint);
+        }
+        if config.occurrence() == &Occurrence::default() {
+            config.set_occurrence(init.take_occurrence());
+        }
         config.set_ignore_name(config.ignore_name() || init.ignore_name());
         config.set_ignore_alias(config.ignore_alias() || init.ignore_alias());
         config.set_ignore_index(config.ignore_index() || init.ignore_index());
@@ -174,6 +191,21 @@ pub trait ConfigValue {
     /// Value initializer for option.
     fn initializer(&self) -> Option<&ValInitializer>;
 
+    /// The constraints between the option and other options.
+    fn constraints(&self) -> &[Constraint] {
+        &[]
+    }
+
+    /// The occurrence rule of option.
+    fn occurrence(&self) -> &Occurrence {
+        &Occurrence::DEFAULT
+    }
+
+    /// The environment variable used as fallback value of option.
+    fn env(&self) -> Option<&str> {
+        None
+    }
+
     /// The creator name of option.
     fn ctor_mut(&mut self) -> Option<&mut String>;
 
@@ -240,6 +272,10 @@ pub trait ConfigValue {
 
     fn has_initializer(&self) -> bool;
 
+    fn has_env(&self) -> bool {
+        self.env().is_some()
+    }
+
     fn set_ctor(&mut self, ctor: impl Into<String>) -> &mut Self;
 
     fn set_type<T: 'static>(&mut self) -> &mut Self;
@@ -272,6 +308,18 @@ pub trait ConfigValue {
 
     fn set_initializer(&mut self, initializer: ValInitializer) -> &mut Self;
 
+    fn add_constraint(&mut self, _constraint: Constraint) -> &mut Self {
+        self
+    }
+
+    fn set_occurrence(&mut self, _occurrence: Occurrence) -> &mut Self {
+        self
+    }
+
+    fn set_env(&mut self, _env: impl Into<String>) -> &mut Self {
+        self
+    }
+
     fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self;
 
     fn set_ignore_alias(&mut self, ignore_alias: bool) -> &mut Self;
@@ -302,6 +350,18 @@ pub trait ConfigValue {
 
     fn take_initializer(&mut self) -> Option<ValInitializer>;
 
+    fn take_constraints(&mut self) -> Vec<Constraint> {
+        vec![]
+    }
+
+    fn take_occurrence(&mut self) -> Occurrence {
+        Occurrence::default()
+    }
+
+    fn take_env(&mut self) -> Option<String> {
+        None
+    }
+
     fn infer_builtin_ty(&mut self);
 
     fn with_index(self, index: Index) -> Self;
@@ -333,6 +393,56 @@ pub trait ConfigValue {
     fn with_ignore_name(self, ignore_name: bool) -> Self;
 
     fn with_initializer(self, initializer: ValInitializer) -> Self;
+
+    fn with_constraint(mut self, constraint: Constraint) -> Self
+    where
+        Self: Sized,
+    {
+        self.add_constraint(constraint);
+        self
+    }
+
+    fn with_occurrence(mut self, occurrence: Occurrence) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_occurrence(occurrence);
+        self
+    }
+
+    fn with_env(mut self, env: impl Into<String>) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_env(env);
+        self
+    }
+
+    /// The separator of elements used by tuple and array, such as `x` in `800x600`.
+    fn separator(&self) -> Option<&ElemSeparator> {
+        None
+    }
+
+    fn has_separator(&self) -> bool {
+        self.separator().is_some()
+    }
+
+    /// Set the separator of elements, the default implementation ignore it.
+    fn set_separator(&mut self, _separator: ElemSeparator) -> &mut Self {
+        self
+    }
+
+    fn take_separator(&mut self) -> Option<ElemSeparator> {
+        None
+    }
+
+    fn with_separator(mut self, separator: ElemSeparator) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_separator(separator);
+        self
+    }
 }
 
 /// Contain the information used for create option instance.
@@ -367,6 +477,14 @@ pub struct OptConfig {
     ignore_index: bool,
 
     styles: Option<Vec<Style>>,
+
+    constraints: Vec<Constraint>,
+
+    occurrence: Occurrence,
+
+    env: Option<String>,
+
+    separator: Option<ElemSeparator>,
 }
 
 impl ConfigValue for OptConfig {
@@ -418,6 +536,18 @@ impl ConfigValue for OptConfig {
         self.initializer.as_ref()
     }
 
+    fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    fn occurrence(&self) -> &Occurrence {
+        &self.occurrence
+    }
+
+    fn env(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+
     fn ctor_mut(&mut self) -> Option<&mut String> {
         self.ctor.as_mut()
     }
@@ -526,6 +656,10 @@ impl ConfigValue for OptConfig {
         self.initializer.is_some()
     }
 
+    fn has_env(&self) -> bool {
+        self.env.is_some()
+    }
+
     fn set_ctor(&mut self, ctor: impl Into<String>) -> &mut Self {
         self.ctor = Some(ctor.into());
         self
@@ -617,6 +751,21 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn add_constraint(&mut self, constraint: Constraint) -> &mut Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    fn set_occurrence(&mut self, occurrence: Occurrence) -> &mut Self {
+        self.occurrence = occurrence;
+        self
+    }
+
+    fn set_env(&mut self, env: impl Into<String>) -> &mut Self {
+        self.env = Some(env.into());
+        self
+    }
+
     fn set_ignore_name(&mut self, ignore_name: bool) -> &mut Self {
         self.ignore_name = ignore_name;
         self
@@ -680,6 +829,18 @@ impl ConfigValue for OptConfig {
         self.initializer.take()
     }
 
+    fn take_constraints(&mut self) -> Vec<Constraint> {
+        std::mem::take(&mut self.constraints)
+    }
+
+    fn take_occurrence(&mut self) -> Occurrence {
+        std::mem::take(&mut self.occurrence)
+    }
+
+    fn take_env(&mut self) -> Option<String> {
+        self.env.take()
+    }
+
     fn infer_builtin_ty(&mut self) {
         if let Some(ctor) = self.ctor() {
             let cid = Cid::from(ctor);
@@ -776,6 +937,34 @@ impl ConfigValue for OptConfig {
         self.initializer = Some(initializer);
         self
     }
+
+    fn with_constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    fn with_occurrence(mut self, occurrence: Occurrence) -> Self {
+        self.occurrence = occurrence;
+        self
+    }
+
+    fn with_env(mut self, env: impl Into<String>) -> Self {
+        self.env = Some(env.into());
+        self
+    }
+
+    fn separator(&self) -> Option<&ElemSeparator> {
+        self.separator.as_ref()
+    }
+
+    fn set_separator(&mut self, separator: ElemSeparator) -> &mut Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    fn take_separator(&mut self) -> Option<ElemSeparator> {
+        self.separator.take()
+    }
 }
 
 pub trait ConfigBuildMutable {
@@ -883,6 +1072,12 @@ pub trait ConfigBuildWith {
     fn with_ignore_name(self, ignore_name: bool) -> Self::Output;
 
     fn with_style(self, styles: Vec<Style>) -> Self::Output;
+
+    fn with_constraint(self, constraint: Constraint) -> Self::Output;
+
+    fn with_occurrence(self, occurrence: Occurrence) -> Self::Output;
+
+    fn with_env(self, env: impl Into<String>) -> Self::Output;
 }
 
 impl<T> ConfigBuildWith for T
@@ -961,6 +1156,21 @@ where
         self.config_mut().set_style(styles);
         self
     }
+
+    fn with_constraint(mut self, constraint: Constraint) -> Self::Output {
+        self.config_mut().add_constraint(constraint);
+        self
+    }
+
+    fn with_occurrence(mut self, occurrence: Occurrence) -> Self::Output {
+        self.config_mut().set_occurrence(occurrence);
+        self
+    }
+
+    fn with_env(mut self, env: impl Into<String>) -> Self::Output {
+        self.config_mut().set_env(env);
+        self
+    }
 }
 
 pub struct ConfigBuilder<C, I> {
@@ -1173,6 +1383,24 @@ macro_rules! def_help_for {
                     ConfigBuilder::new(OptConfig::default().with_style(styles)),
                 )
             }
+
+            fn with_constraint(self, constraint: Constraint) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_constraint(constraint)),
+                )
+            }
+
+            fn with_occurrence(self, occurrence: Occurrence) -> Self::Output {
+                ConfigBuilderWith::new(
+                    self,
+                    ConfigBuilder::new(OptConfig::default().with_occurrence(occurrence)),
+                )
+            }
+
+            fn with_env(self, env: impl Into<String>) -> Self::Output {
+                ConfigBuilderWith::new(self, ConfigBuilder::new(OptConfig::default().with_env(env)))
+            }
         }
     };
 }
@@ -1,3 +1,4 @@
+use super::Action;
 use super::Index;
 
 pub trait Information {
@@ -13,6 +14,14 @@ pub trait Information {
 
     fn has_ctor(&self) -> bool;
 
+    fn has_env(&self) -> bool {
+        self.env().is_some()
+    }
+
+    fn has_action(&self) -> bool {
+        self.action().is_some()
+    }
+
     fn name(&self) -> Option<&str>;
 
     fn force(&self) -> Option<bool>;
@@ -25,6 +34,14 @@ pub trait Information {
 
     fn ctor(&self) -> Option<&str>;
 
+    fn env(&self) -> Option<&str> {
+        None
+    }
+
+    fn action(&self) -> Option<&Action> {
+        None
+    }
+
     fn take_name(&mut self) -> Option<String>;
 
     fn take_force(&mut self) -> Option<bool>;
@@ -36,6 +53,14 @@ pub trait Information {
     fn take_help(&mut self) -> Option<String>;
 
     fn take_ctor(&mut self) -> Option<String>;
+
+    fn take_env(&mut self) -> Option<String> {
+        None
+    }
+
+    fn take_action(&mut self) -> Option<Action> {
+        None
+    }
 }
 
 /// Parsing result of option constructor string.
@@ -52,6 +77,10 @@ pub struct ConstrctInfo {
     pub(crate) help: Option<String>,
 
     pub(crate) ctor: Option<String>,
+
+    pub(crate) env: Option<String>,
+
+    pub(crate) action: Option<Action>,
 }
 
 impl ConstrctInfo {
@@ -84,6 +113,16 @@ impl ConstrctInfo {
         self.ctor = ctor;
         self
     }
+
+    pub fn with_env(mut self, env: Option<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    pub fn with_action(mut self, action: Option<Action>) -> Self {
+        self.action = action;
+        self
+    }
 }
 
 impl Information for ConstrctInfo {
@@ -111,6 +150,14 @@ impl Information for ConstrctInfo {
         self.ctor.is_some()
     }
 
+    fn has_env(&self) -> bool {
+        self.env.is_some()
+    }
+
+    fn has_action(&self) -> bool {
+        self.action.is_some()
+    }
+
     fn name(&self) -> Option<&str> {
         self.name.as_deref()
     }
@@ -135,6 +182,14 @@ impl Information for ConstrctInfo {
         self.ctor.as_deref()
     }
 
+    fn env(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+
+    fn action(&self) -> Option<&Action> {
+        self.action.as_ref()
+    }
+
     fn take_name(&mut self) -> Option<String> {
         self.name.take()
     }
@@ -158,4 +213,12 @@ impl Information for ConstrctInfo {
     fn take_ctor(&mut self) -> Option<String> {
         self.ctor.take()
     }
+
+    fn take_env(&mut self) -> Option<String> {
+        self.env.take()
+    }
+
+    fn take_action(&mut self) -> Option<Action> {
+        self.action.take()
+    }
 }
@@ -32,8 +32,13 @@ pub use self::serialize::Serialize;
 pub use self::value::OptValueExt;
 
 pub use crate::acore::opt::Action;
+pub use crate::acore::opt::Constraint;
+pub use crate::acore::opt::ConstraintKind;
+pub use crate::acore::opt::Duplicate;
 pub use crate::acore::opt::Index;
+pub use crate::acore::opt::Occurrence;
 pub use crate::acore::opt::Opt;
+pub use crate::acore::opt::OptGroup;
 pub use crate::acore::opt::Style;
 pub use crate::acore::opt::BOOL_FALSE;
 pub use crate::acore::opt::BOOL_TRUE;
@@ -1,4 +1,5 @@
 use super::{ConstrctInfo, OptParser};
+use crate::opt::Action;
 use crate::opt::Index;
 use crate::Error;
 
@@ -7,18 +8,23 @@ use crate::Error;
 /// The struct of the option string are:
 ///
 /// ```plaintext
-/// [--option][=][type][!*][@index]
-///      |     |    |   |   |
-///      |     |    |   |   |
-///      |     |    |   |   |
-///      |     |    |   |   The index part of option. Here are all the possible string:
-///      |     |    |   |   @0 means first position
-///      |     |    |   |   @-0 means last position
-///      |     |    |   |   @[1, 2, 3] means the position 1, 2 and 3
-///      |     |    |   |   @-[1, 2] means except the position 1, 2
-///      |     |    |   |   @>2 means position that bigger than 2
-///      |     |    |   |   @<3 means position less than 3
-///      |     |    |   |   @* means all the position
+/// [--option][=][type][!*][%action][$ENV][@index]
+///      |     |    |   |      |      |     |
+///      |     |    |   |      |      |     |
+///      |     |    |   |      |      |     |
+///      |     |    |   |      |      |     The index part of option. Here are all the possible string:
+///      |     |    |   |      |      |     @0 means first position
+///      |     |    |   |      |      |     @-0 means last position
+///      |     |    |   |      |      |     @[1, 2, 3] means the position 1, 2 and 3
+///      |     |    |   |      |      |     @-[1, 2] means except the position 1, 2
+///      |     |    |   |      |      |     @>2 means position that bigger than 2
+///      |     |    |   |      |      |     @<3 means position less than 3
+///      |     |    |   |      |      |     @* means all the position
+///      |     |    |   |      |      |
+///      |     |    |   |      |      The environment variable used as fallback value of option.
+///      |     |    |   |      |
+///      |     |    |   |      The action of option, such as %uniq, %ext, %once or %toggle,
+///      |     |    |   |      see the [`FromStr`](std::str::FromStr) implementation of [`Action`](crate::opt::Action).
 ///      |     |    |   |
 ///      |     |    |   Indicate the option wether is force required(!) or not(*).
 ///      |     |    |
@@ -53,6 +59,17 @@ use crate::Error;
 ///     assert_eq!(ret.force(), None);
 ///     assert_eq!(ret.index(), Some(&Index::list(vec![1, 2, 3])));
 ///
+///     let ret = parser.parse_opt("--token=s!$MYTOOL_TOKEN".into())?;
+///
+///     assert_eq!(ret.name(), Some("--token"));
+///     assert_eq!(ret.force(), Some(true));
+///     assert_eq!(ret.env(), Some("MYTOOL_TOKEN"));
+///
+///     let ret = parser.parse_opt("--feature=s%uniq".into())?;
+///
+///     assert_eq!(ret.name(), Some("--feature"));
+///     assert_eq!(ret.action(), Some(&Action::AppUniq));
+///
 /// #   Ok(())
 /// # }
 /// ```
@@ -70,7 +87,7 @@ impl StrParser {
     pub fn parse_creator_string(&self, dat: &str) -> Result<ConstrctInfo, Error> {
         use neure::prelude::*;
 
-        let name = ['=', '!', '*', '@', ';', ':'].not().many1();
+        let name = ['=', '!', '*', '%', '$', '@', ';', ':'].not().many1();
         let aliases = name.sep(";");
         let parser = name.opt().if_then(";", aliases);
 
@@ -80,6 +97,12 @@ impl StrParser {
         let opt = "!".or("*").opt();
         let parser = parser.then(opt);
 
+        let action = neu::alphabetic().many1();
+        let parser = parser.if_then("%", action);
+
+        let env = '@'.or(':').not().many1();
+        let parser = parser.if_then("$", env);
+
         let index = '@'.or(':').not().many1();
         let parser = parser.if_then("@", index);
 
@@ -90,7 +113,7 @@ impl StrParser {
 
         let to_string = |v: &str| v.trim().to_string();
 
-        let (((((name, aliases), ctor), opt), index), help) = CharsCtx::new(dat)
+        let (((((((name, aliases), ctor), opt), action), env), index), help) = CharsCtx::new(dat)
             .ctor(&parser)
             .map_err(|_| Error::create_str(dat, "can not parsing string"))?;
 
@@ -100,6 +123,17 @@ impl StrParser {
         ci = ci.with_alias(aliases.map(|v| v.iter().copied().map(to_string).collect()));
         ci = ci.with_ctor(ctor.map(to_string));
         ci = ci.with_force(opt.map(|v| v == "!"));
+        ci = ci.with_action(if let Some(action) = action {
+            Some(
+                action
+                    .trim()
+                    .parse::<Action>()
+                    .map_err(|_| Error::create_str(dat, "unknown action name"))?,
+            )
+        } else {
+            None
+        });
+        ci = ci.with_env(env.map(to_string));
         ci = ci.with_index(if let Some(index) = index {
             Some(Index::parse(index)?)
         } else {
@@ -181,6 +215,10 @@ mod test {
         let helps_test = [Some("This is an option help message"), None];
         let forces = ["!", "*", ""];
         let forces_test = [Some(true), Some(false), None];
+        let actions = ["%uniq", "%toggle", ""];
+        let actions_test = [Some(Action::AppUniq), Some(Action::Toggle), None];
+        let envs = ["$MYTOOL_TOKEN", ""];
+        let envs_test = [Some("MYTOOL_TOKEN"), None];
         let positions = [
             "@1",
             "@68",
@@ -214,30 +252,43 @@ mod test {
         for (option, option_test) in options.iter().zip(options_test.iter()) {
             for (help, help_test) in helps.iter().zip(helps_test.iter()) {
                 for (force, force_test) in forces.iter().zip(forces_test.iter()) {
-                    for (position, position_test) in positions.iter().zip(positions_test.iter()) {
-                        let creator = format!("{}{}{}{}", option, force, position, help);
-
-                        println!("\"{}\",", creator);
-                        if let Ok(cap) = parser.parse_opt(&creator) {
-                            assert_eq!(option_test.0, cap.name());
-                            assert_eq!(
-                                option_test.1,
-                                cap.alias().map(|v| v.iter().map(|v| v.as_ref()).collect())
-                            );
-                            assert_eq!(help_test, &cap.help());
-                            assert_eq!(force_test, &cap.force());
-                            assert_eq!(position_test.as_ref(), cap.index());
-                            assert_eq!(option_test.2, cap.ctor());
-                        } else {
-                            assert!(
-                                option_test.0.is_none(),
-                                "{}{}{}{}",
-                                option,
-                                force,
-                                position,
-                                help
-                            );
-                            assert!(option_test.1.is_none());
+                    for (action, action_test) in actions.iter().zip(actions_test.iter()) {
+                        for (env, env_test) in envs.iter().zip(envs_test.iter()) {
+                            for (position, position_test) in
+                                positions.iter().zip(positions_test.iter())
+                            {
+                                let creator = format!(
+                                    "{}{}{}{}{}{}",
+                                    option, force, action, env, position, help
+                                );
+
+                                println!("\"{}\",", creator);
+                                if let Ok(cap) = parser.parse_opt(&creator) {
+                                    assert_eq!(option_test.0, cap.name());
+                                    assert_eq!(
+                                        option_test.1,
+                                        cap.alias().map(|v| v.iter().map(|v| v.as_ref()).collect())
+                                    );
+                                    assert_eq!(help_test, &cap.help());
+                                    assert_eq!(force_test, &cap.force());
+                                    assert_eq!(action_test.as_ref(), cap.action());
+                                    assert_eq!(env_test, &cap.env());
+                                    assert_eq!(position_test.as_ref(), cap.index());
+                                    assert_eq!(option_test.2, cap.ctor());
+                                } else {
+                                    assert!(
+                                        option_test.0.is_none(),
+                                        "{}{}{}{}{}{}",
+                                        option,
+                                        force,
+                                        action,
+                                        env,
+                                        position,
+                                        help
+                                    );
+                                    assert!(option_test.1.is_none());
+                                }
+                            }
                         }
                     }
                 }
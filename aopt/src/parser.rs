@@ -1,34 +1,56 @@
 pub(crate) mod checker;
 pub(crate) mod commit;
 pub(crate) mod failure;
+pub(crate) mod hook;
+pub(crate) mod lexer;
 pub(crate) mod optset;
 pub(crate) mod policy_delay;
 pub(crate) mod policy_fwd;
 pub(crate) mod policy_seq;
 pub(crate) mod returnval;
+pub(crate) mod session;
 pub(crate) mod storage;
 pub(crate) mod style;
+pub(crate) mod txn;
 
 pub use self::checker::DefaultSetChecker;
 pub use self::commit::ParserCommit;
 pub use self::commit::ParserCommitWithValue;
 pub use self::failure::FailManager;
+pub use self::hook::ParseHooks;
+pub use self::hook::PostParseHook;
+pub use self::hook::PreParseHook;
+pub use self::lexer::Lexer;
+pub use self::lexer::Token;
 pub use self::optset::HCOptSet;
 pub use self::policy_delay::DelayPolicy;
 pub use self::policy_fwd::FwdPolicy;
 pub use self::policy_seq::SeqPolicy;
 pub use self::returnval::Return;
+pub use self::returnval::Score;
+pub use self::returnval::Scoring;
+pub use self::session::LineReader;
+pub use self::session::Session;
+pub use self::storage::AppDataRestore;
+pub use self::storage::AppDataSnapshot;
 pub use self::storage::AppServices;
 pub use self::storage::AppStorage;
 pub use self::storage::UsrValService;
 pub use self::style::OptStyleManager;
 pub use self::style::UserStyle;
+pub use self::txn::Deferred;
+pub use self::txn::DeferredWork;
+pub use self::txn::Transaction;
 
 pub use crate::acore::parser::Action;
 
 use std::fmt::Debug;
+use std::future::Future;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
 
 use crate::args::Args;
 use crate::ctx::InnerCtx;
@@ -92,6 +114,35 @@ pub trait Policy {
         inv: &mut Self::Inv<'_>,
         args: Args,
     ) -> Result<Self::Ret, Self::Error>;
+
+    /// Parse the arguments like [`parse`](Policy::parse), the async handler is awaited when the option matched.
+    ///
+    /// The default implementation not support async handler, it call [`parse`](Policy::parse) directly.
+    fn parse_async(
+        &mut self,
+        set: &mut Self::Set,
+        inv: &mut Self::Inv<'_>,
+        args: Args,
+    ) -> impl Future<Output = Result<Self::Ret, Self::Error>> {
+        async move { self.parse(set, inv, args) }
+    }
+}
+
+/// Poll the future of parsing once, return an error if it is pending.
+///
+/// The policies implement [`parse`](Policy::parse) with [`parse_async`](Policy::parse_async),
+/// the future is ready immediately because async handler is not allowed in synchronous parsing.
+pub(crate) fn poll_ready<T, E: From<Error>>(
+    fut: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let mut fut = std::pin::pin!(fut);
+
+    match fut.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(ret) => ret,
+        Poll::Pending => {
+            Err(crate::error!("parsing is pending, parse it with `parse_async`").into())
+        }
+    }
 }
 
 pub trait PolicySettings {
@@ -147,6 +198,29 @@ where
     }
 
     fn parse_policy(&mut self, args: Args, policy: &mut P) -> Result<P::Ret, Self::Error>;
+
+    /// Parse the arguments, the async handlers are awaited when the options matched.
+    fn parse_async(&mut self, args: Args) -> impl Future<Output = Result<P::Ret, Self::Error>>
+    where
+        P: Default,
+    {
+        async move {
+            let mut policy = P::default();
+
+            self.parse_policy_async(args, &mut policy).await
+        }
+    }
+
+    /// Parse the arguments with `policy`, the async handlers are awaited when the options matched.
+    ///
+    /// The default implementation not support async handler, it call [`parse_policy`](PolicyParser::parse_policy) directly.
+    fn parse_policy_async(
+        &mut self,
+        args: Args,
+        policy: &mut P,
+    ) -> impl Future<Output = Result<P::Ret, Self::Error>> {
+        async move { self.parse_policy(args, policy) }
+    }
 }
 
 /// Parser manage the components are using in [`parse`](Policy::parse) of [`Policy`].
@@ -205,6 +279,9 @@ where
 pub struct Parser<S, P: Policy<Set = S>> {
     pub policy: P,
     pub optset: S,
+    /// The hooks called before and after parsing, using [`ParseHooks::default`]
+    /// when constructing the parser with struct literal.
+    pub hooks: ParseHooks<S, P::Ret>,
 }
 
 impl<S, P: Policy<Set = S>> Deref for Parser<S, P> {
@@ -223,7 +300,11 @@ impl<S, P: Policy<Set = S>> DerefMut for Parser<S, P> {
 
 impl<S, P: Policy<Set = S>> Parser<S, P> {
     pub fn new(policy: P, optset: S) -> Self {
-        Self { optset, policy }
+        Self {
+            optset,
+            policy,
+            hooks: ParseHooks::default(),
+        }
     }
 
     pub fn policy(&self) -> &P {
@@ -251,6 +332,14 @@ impl<S, P: Policy<Set = S>> Parser<S, P> {
         self.optset = optset;
         self
     }
+
+    pub fn hooks(&self) -> &ParseHooks<S, P::Ret> {
+        &self.hooks
+    }
+
+    pub fn hooks_mut(&mut self) -> &mut ParseHooks<S, P::Ret> {
+        &mut self.hooks
+    }
 }
 
 impl<'a, S, P> Parser<HCOptSet<'a, S>, P>
@@ -266,6 +355,7 @@ where
         Self {
             policy,
             optset: HCOptSet::new(optset, invoker),
+            hooks: ParseHooks::default(),
         }
     }
 }
@@ -287,7 +377,158 @@ where
     }
 
     pub fn parse(&mut self, args: Args) -> Result<<P as Policy>::Ret, Error> {
-        PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy)
+        let ret = self
+            .hooks
+            .pre_parse(&mut self.optset, args)
+            .and_then(|args| {
+                PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy)
+            });
+
+        self.hooks.post_parse(&mut self.optset, ret)
+    }
+
+    /// Parse the arguments, the async handlers registered by
+    /// [`on_async`](crate::parser::ParserCommit::on_async) are awaited when the options matched.
+    pub async fn parse_async(&mut self, args: Args) -> Result<<P as Policy>::Ret, Error> {
+        let ret = match self.hooks.pre_parse(&mut self.optset, args) {
+            Ok(args) => {
+                PolicyParser::<P>::parse_policy_async(&mut self.optset, args, &mut self.policy)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+
+        self.hooks.post_parse(&mut self.optset, ret)
+    }
+
+    /// Start an incremental parse [`Session`], the option set is initialized once.
+    pub fn session(&mut self) -> Result<Session<'_, 'a, S, P>, Error> {
+        Session::new(self)
+    }
+
+    /// Begin a [`Transaction`], the values of options and the app data set by
+    /// [`set_cloneable_app_data`](AppStorage::set_cloneable_app_data) are saved.
+    pub fn transaction(&mut self) -> Result<Transaction<'_, 'a, S, P>, Error> {
+        Transaction::new(self)
+    }
+
+    /// Parse the arguments in a [`Transaction`].
+    /// The transaction is committed if the parsing succeed, otherwise it is rolled back.
+    pub fn parse_tx(&mut self, args: Args) -> Result<<P as Policy>::Ret, Error>
+    where
+        for<'r> &'r P::Ret: Into<bool>,
+    {
+        let mut tx = self.transaction()?;
+
+        match tx.parse(args) {
+            Ok(ret) if (&ret).into() => {
+                tx.commit()?;
+                Ok(ret)
+            }
+            ret => {
+                tx.rollback();
+                ret
+            }
+        }
+    }
+
+    /// Parse the arguments in a [`Transaction`] and roll it back,
+    /// return the [`Score`] of parsing, or the reason if parsing failed.
+    ///
+    /// The handlers, middlewares and bound variables of [`Invoker`] are not invoked when scoring,
+    /// the values of options are saved by [`fallback`](Invoker::fallback).
+    pub fn score(&mut self, args: Args) -> Result<Score, Error>
+    where
+        P::Ret: Scoring,
+    {
+        let inv = std::mem::take(self.optset.invoker_mut()?);
+        let ret = self.transaction().and_then(|mut tx| {
+            let mut ret = tx.parse(args)?;
+
+            match ret.take_failure() {
+                Some(e) => Err(e),
+                None => Ok(ret.score(tx.optset())),
+            }
+        });
+
+        self.optset.set_invoker(inv);
+        ret
+    }
+
+    /// Parse the arguments and consume the parser.
+    ///
+    /// The variables bound by [`bind`](crate::parser::ParserCommit::bind) are borrowed by the parser,
+    /// they can be used after the parser dropped.
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut depth = 0i64;
+    /// let mut files = Vec::<String>::new();
+    /// let mut verbose = 0u64;
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--depth=i")?.bind(&mut depth)?;
+    /// parser.add_opt("--file=s")?.bind(&mut files)?;
+    /// parser.add_opt("-v=b")?.set_action(Action::Cnt).bind(&mut verbose)?;
+    ///
+    /// let args = ["app", "--depth=3", "-v", "--file=a", "-v", "--file=b"];
+    ///
+    /// assert!(parser.parse_once(Args::from(args))?.status());
+    /// assert_eq!(depth, 3);
+    /// assert_eq!(files, ["a", "b"]);
+    /// assert_eq!(verbose, 2);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn parse_once(mut self, args: Args) -> Result<<P as Policy>::Ret, Error> {
+        self.parse(args)
+    }
+
+    /// Add a hook called before parsing, it can modify the set or rewrite the arguments.
+    #[cfg(not(feature = "sync"))]
+    pub fn add_pre_parse<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnMut(&mut HCOptSet<'a, S>, Args) -> Result<Args, Error> + 'static,
+    {
+        self.hooks.add_pre(Box::new(hook));
+        self
+    }
+
+    /// Add a hook called after parsing with the result, the return value replace the result.
+    #[cfg(not(feature = "sync"))]
+    pub fn add_post_parse<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnMut(&mut HCOptSet<'a, S>, Result<P::Ret, Error>) -> Result<P::Ret, Error> + 'static,
+    {
+        self.hooks.add_post(Box::new(hook));
+        self
+    }
+
+    /// Add a hook called before parsing, it can modify the set or rewrite the arguments.
+    #[cfg(feature = "sync")]
+    pub fn add_pre_parse<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnMut(&mut HCOptSet<'a, S>, Args) -> Result<Args, Error> + Send + Sync + 'static,
+    {
+        self.hooks.add_pre(Box::new(hook));
+        self
+    }
+
+    /// Add a hook called after parsing with the result, the return value replace the result.
+    #[cfg(feature = "sync")]
+    pub fn add_post_parse<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnMut(&mut HCOptSet<'a, S>, Result<P::Ret, Error>) -> Result<P::Ret, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.hooks.add_post(Box::new(hook));
+        self
     }
 }
 
@@ -422,7 +663,14 @@ where
     where
         P: Default,
     {
-        PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy)
+        let ret = self
+            .hooks
+            .pre_parse(&mut self.optset, args)
+            .and_then(|args| {
+                PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy)
+            });
+
+        self.hooks.post_parse(&mut self.optset, ret)
     }
 
     fn parse_policy(
@@ -430,6 +678,24 @@ where
         args: Args,
         policy: &mut P,
     ) -> Result<<P as Policy>::Ret, Self::Error> {
-        PolicyParser::<P>::parse_policy(&mut self.optset, args, policy)
+        let ret = self
+            .hooks
+            .pre_parse(&mut self.optset, args)
+            .and_then(|args| PolicyParser::<P>::parse_policy(&mut self.optset, args, policy));
+
+        self.hooks.post_parse(&mut self.optset, ret)
+    }
+
+    async fn parse_policy_async(
+        &mut self,
+        args: Args,
+        policy: &mut P,
+    ) -> Result<<P as Policy>::Ret, Self::Error> {
+        let ret = match self.hooks.pre_parse(&mut self.optset, args) {
+            Ok(args) => PolicyParser::<P>::parse_policy_async(&mut self.optset, args, policy).await,
+            Err(e) => Err(e),
+        };
+
+        self.hooks.post_parse(&mut self.optset, ret)
     }
 }
@@ -1,8 +1,11 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::opt::Constraint;
+use crate::opt::ConstraintKind;
 use crate::opt::Index;
 use crate::opt::Opt;
+use crate::opt::OptGroup;
 use crate::opt::Style;
 use crate::set::SetChecker;
 use crate::set::SetOpt;
@@ -47,6 +50,117 @@ where
     pub fn opt<'a>(set: &'a S, id: &Uid) -> &'a SetOpt<S> {
         set.get(*id).unwrap()
     }
+
+    /// Get the [`Uid`] of [`Constraint`] targets resolved when inserting option,
+    /// return an error if any target is not found.
+    pub fn resolve(opt: &SetOpt<S>, constraint: &Constraint) -> Result<Vec<Uid>, Error> {
+        constraint
+            .targets()
+            .iter()
+            .enumerate()
+            .map(|(idx, target)| {
+                let uid = constraint.uids().get(idx).copied().flatten();
+
+                uid.ok_or_else(|| {
+                    crate::error!(
+                        "can not find option `{target}` in constraint of `{}`",
+                        opt.name()
+                    )
+                    .with_uid(opt.uid())
+                })
+            })
+            .collect()
+    }
+
+    /// Check the [`Constraint`]s of all options.
+    pub fn constraint_check(set: &S) -> Result<bool, Error> {
+        for opt in set.iter() {
+            for constraint in opt.constraints() {
+                let targets = Self::resolve(opt, constraint)?;
+                let mut targets = targets.iter().map(|uid| Self::opt(set, uid));
+                let (name, uid) = (opt.name(), opt.uid());
+
+                trace!("in constraint check, checking `{name}` {constraint}");
+                match constraint.kind() {
+                    ConstraintKind::Conflicts => {
+                        if opt.matched() {
+                            if let Some(other) = targets.find(|v| v.matched()) {
+                                return Err(
+                                    Error::sp_opt_conflict(name, other.name()).with_uid(uid)
+                                );
+                            }
+                        }
+                    }
+                    ConstraintKind::Requires => {
+                        if opt.matched() {
+                            if let Some(other) = targets.find(|v| !v.matched()) {
+                                return Err(Error::sp_opt_depend(name, other.name()).with_uid(uid));
+                            }
+                        }
+                    }
+                    ConstraintKind::RequiredUnless => {
+                        let targets: Vec<_> = targets.collect();
+
+                        if !opt.matched() && !targets.iter().any(|v| v.matched()) {
+                            let names = targets.iter().map(|v| v.name()).collect();
+
+                            return Err(Error::sp_opt_require_unless(name, names).with_uid(uid));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Resolve the members of [`OptGroup`] to uid, the member can be name or alias of option.
+    pub fn resolve_group(set: &S, group: &OptGroup) -> Result<Vec<Uid>, Error> {
+        group
+            .members()
+            .iter()
+            .map(|member| {
+                set.iter()
+                    .find(|v| v.mat_name(Some(member)) || v.mat_alias(member))
+                    .map(|v| v.uid())
+                    .ok_or_else(|| {
+                        crate::error!("can not find option `{member}` in group `{}`", group.name())
+                    })
+            })
+            .collect()
+    }
+
+    /// Check the cardinality of all [`OptGroup`]s registered in the set.
+    pub fn group_check(set: &S) -> Result<bool, Error> {
+        for group in set.groups() {
+            let members = Self::resolve_group(set, group)?;
+            let (matched, unmatched): (Vec<_>, Vec<_>) = members
+                .iter()
+                .map(|uid| Self::opt(set, uid))
+                .partition(|v| v.matched());
+            let name = group.name();
+
+            trace!(
+                "in group check, checking `{name}` {}: {group}",
+                group.rule()
+            );
+            if group.exclusive() && matched.len() > 1 {
+                let names = matched.iter().map(|v| v.name()).collect();
+
+                return Err(Error::sp_group_conflict(name, names).with_uid(matched[1].uid()));
+            }
+            if group.required() && matched.is_empty() {
+                let names = unmatched.iter().map(|v| v.name()).collect();
+
+                return Err(Error::sp_group_require(name, names));
+            }
+            if group.all_or_none() && !matched.is_empty() && !unmatched.is_empty() {
+                let names = unmatched.iter().map(|v| v.name()).collect();
+
+                return Err(Error::sp_group_partial(name, names).with_uid(matched[0].uid()));
+            }
+        }
+        Ok(true)
+    }
 }
 
 impl<S> SetChecker<S> for DefaultSetChecker<S>
@@ -84,7 +198,8 @@ where
     /// Call the [`valid`](crate::opt::Opt::valid) check the
     /// options([`Argument`](crate::opt::Style::Argument),
     /// [`Boolean`](crate::opt::Style::Boolean), [`Combined`](crate::opt::Style::Combined)),
-    /// [`Flag`](crate::opt::Style::Flag)
+    /// [`Flag`](crate::opt::Style::Flag).
+    /// Then check the minimum occurrence of options those are set or force required.
     fn opt_check(&self, set: &mut S) -> Result<bool, Error> {
         trace!("in opt check, call valid on all Opt ...");
         for opt in set.iter().filter(|opt| {
@@ -96,6 +211,13 @@ where
             if !opt.valid() {
                 return Err(Error::sp_opt_require(vec![opt.hint()]).with_uid(opt.uid()));
             }
+            let (rule, count) = (opt.occurrence(), opt.positions().len());
+
+            if (opt.matched() || opt.force()) && rule.insufficient(count) {
+                return Err(
+                    Error::sp_opt_too_few(opt.name(), rule.min(), count).with_uid(opt.uid())
+                );
+            }
         }
         Ok(true)
     }
@@ -201,8 +323,12 @@ where
         Ok(true)
     }
 
-    /// Call [`valid`](crate::opt::Opt::valid) on options those style are [`Main`](Style::Main).
+    /// Check the [`Constraint`]s of options, see [`constraint_check`](DefaultSetChecker::constraint_check),
+    /// and the [`OptGroup`]s of set, see [`group_check`](DefaultSetChecker::group_check).
+    /// Then call [`valid`](crate::opt::Opt::valid) on options those style are [`Main`](Style::Main).
     fn post_check(&self, set: &mut S) -> Result<bool, Error> {
+        Self::constraint_check(set)?;
+        Self::group_check(set)?;
         trace!("in post check, call valid on Main ...");
         Ok(set
             .iter()
@@ -1,6 +1,10 @@
 use std::any::TypeId;
 use std::fmt::Debug;
 
+use crate::ctx::AsyncHandlerEntry;
+use crate::ctx::BindTarget;
+#[cfg(feature = "sync")]
+use crate::ctx::BoxedFuture;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerEntry;
 use crate::ctx::HandlerEntryThen;
@@ -237,10 +241,92 @@ where
         Ok(HandlerEntry::new(invoker.unwrap(), uid).fallback(handler))
     }
 
+    #[cfg(not(feature = "sync"))]
+    /// Register the async handler which will be called when option is set.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then pass the unqiue id to [`AsyncHandlerEntry`].
+    /// The handler is awaited by [`parse_async`](crate::parser::PolicyParser::parse_async)
+    /// when the option matched.
+    pub fn on_async<H, O>(
+        mut self,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, 'b, Invoker<'a, C>, C, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: AsyncFnMut(&mut C, &mut Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+        let invoker = std::mem::take(&mut self.invoker);
+
+        Ok(AsyncHandlerEntry::new(invoker.unwrap(), uid, handler))
+    }
+
+    #[cfg(feature = "sync")]
+    /// Register the async handler which will be called when option is set.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then pass the unqiue id to [`AsyncHandlerEntry`].
+    /// The handler is awaited by [`parse_async`](crate::parser::PolicyParser::parse_async)
+    /// when the option matched.
+    /// The handler return a `Send` [`BoxedFuture`], such as `|set, ctx| Box::pin(async move { .. })`.
+    pub fn on_async<H, O>(
+        mut self,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, 'b, Invoker<'a, C>, C, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: for<'x> FnMut(&'x mut C, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+        let invoker = std::mem::take(&mut self.invoker);
+
+        Ok(AsyncHandlerEntry::new(invoker.unwrap(), uid, handler))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    /// Bind the option to the `target`, such as `&mut i64` or `&Cell<Vec<String>>`.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then register the binding handler by [`bind`](crate::ctx::Invoker::bind).
+    pub fn bind<B>(self, target: B) -> Result<Uid, Error>
+    where
+        B: BindTarget + 'a,
+        SetOpt<C>: Opt,
+    {
+        self.commit_with(|invoker, uid| {
+            invoker.bind(uid, target);
+        })
+    }
+
+    #[cfg(feature = "sync")]
+    /// Bind the option to the `target`, such as `&mut i64` or `&Cell<Vec<String>>`.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then register the binding handler by [`bind`](crate::ctx::Invoker::bind).
+    pub fn bind<B>(self, target: B) -> Result<Uid, Error>
+    where
+        B: BindTarget + Send + Sync + 'a,
+        SetOpt<C>: Opt,
+    {
+        self.commit_with(|invoker, uid| {
+            invoker.bind(uid, target);
+        })
+    }
+
     pub(crate) fn commit_inner_change(&mut self) -> Result<Uid, Error> {
         self.inner_mut()?.commit_change()
     }
 
+    /// Commit the option, then call `f` with the invoker and the [`Uid`] of option.
+    fn commit_with(mut self, f: impl FnOnce(&mut Invoker<'a, C>, Uid)) -> Result<Uid, Error> {
+        let uid = self.commit_inner_change()?;
+
+        if let Some(invoker) = self.invoker.take() {
+            f(invoker, uid);
+        }
+        Ok(uid)
+    }
+
     /// Run the commit.
     ///
     /// It create an option using given type [`Ctor`](crate::set::Ctor).
@@ -520,10 +606,92 @@ where
         Ok(HandlerEntry::new(invoker.unwrap(), uid).fallback(handler))
     }
 
+    #[cfg(not(feature = "sync"))]
+    /// Register the async handler which will be called when option is set.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then pass the unqiue id to [`AsyncHandlerEntry`].
+    /// The handler is awaited by [`parse_async`](crate::parser::PolicyParser::parse_async)
+    /// when the option matched.
+    pub fn on_async<H, O>(
+        mut self,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, 'b, Invoker<'a, C>, C, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: AsyncFnMut(&mut C, &mut Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+        let invoker = std::mem::take(&mut self.invoker);
+
+        Ok(AsyncHandlerEntry::new(invoker.unwrap(), uid, handler))
+    }
+
+    #[cfg(feature = "sync")]
+    /// Register the async handler which will be called when option is set.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then pass the unqiue id to [`AsyncHandlerEntry`].
+    /// The handler is awaited by [`parse_async`](crate::parser::PolicyParser::parse_async)
+    /// when the option matched.
+    /// The handler return a `Send` [`BoxedFuture`], such as `|set, ctx| Box::pin(async move { .. })`.
+    pub fn on_async<H, O>(
+        mut self,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, 'b, Invoker<'a, C>, C, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: for<'x> FnMut(&'x mut C, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+        let invoker = std::mem::take(&mut self.invoker);
+
+        Ok(AsyncHandlerEntry::new(invoker.unwrap(), uid, handler))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    /// Bind the option to the `target`, such as `&mut i64` or `&Cell<Vec<String>>`.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then register the binding handler by [`bind`](crate::ctx::Invoker::bind).
+    pub fn bind<B>(self, target: B) -> Result<Uid, Error>
+    where
+        B: BindTarget + 'a,
+        SetOpt<C>: Opt,
+    {
+        self.commit_with(|invoker, uid| {
+            invoker.bind(uid, target);
+        })
+    }
+
+    #[cfg(feature = "sync")]
+    /// Bind the option to the `target`, such as `&mut i64` or `&Cell<Vec<String>>`.
+    /// The function will register the option to [`Set`](Set) first,
+    /// then register the binding handler by [`bind`](crate::ctx::Invoker::bind).
+    pub fn bind<B>(self, target: B) -> Result<Uid, Error>
+    where
+        B: BindTarget + Send + Sync + 'a,
+        SetOpt<C>: Opt,
+    {
+        self.commit_with(|invoker, uid| {
+            invoker.bind(uid, target);
+        })
+    }
+
     pub(crate) fn commit_inner_change(&mut self) -> Result<Uid, Error> {
         self.inner_mut()?.commit_inner_change()
     }
 
+    /// Commit the option, then call `f` with the invoker and the [`Uid`] of option.
+    fn commit_with(mut self, f: impl FnOnce(&mut Invoker<'a, C>, Uid)) -> Result<Uid, Error> {
+        let uid = self.commit_inner_change()?;
+
+        if let Some(invoker) = self.invoker.take() {
+            f(invoker, uid);
+        }
+        Ok(uid)
+    }
+
     /// Run the commit.
     ///
     /// It create an option using given type [`Ctor`](crate::set::Ctor).
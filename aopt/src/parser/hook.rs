@@ -0,0 +1,153 @@
+use std::fmt::Debug;
+
+use crate::args::Args;
+use crate::Error;
+
+/// Hook called before parsing, it can modify the set or rewrite the arguments.
+#[cfg(not(feature = "sync"))]
+pub type PreParseHook<S> = Box<dyn FnMut(&mut S, Args) -> Result<Args, Error>>;
+
+/// Hook called before parsing, it can modify the set or rewrite the arguments.
+#[cfg(feature = "sync")]
+pub type PreParseHook<S> = Box<dyn FnMut(&mut S, Args) -> Result<Args, Error> + Send + Sync>;
+
+/// Hook called after parsing with the result, the return value replace the result.
+#[cfg(not(feature = "sync"))]
+pub type PostParseHook<S, R> = Box<dyn FnMut(&mut S, Result<R, Error>) -> Result<R, Error>>;
+
+/// Hook called after parsing with the result, the return value replace the result.
+#[cfg(feature = "sync")]
+pub type PostParseHook<S, R> =
+    Box<dyn FnMut(&mut S, Result<R, Error>) -> Result<R, Error> + Send + Sync>;
+
+/// Keep the hooks of [`Parser`](crate::parser::Parser), they are called in the order of registration.
+pub struct ParseHooks<S, R> {
+    pre: Vec<PreParseHook<S>>,
+
+    post: Vec<PostParseHook<S, R>>,
+}
+
+impl<S, R> Debug for ParseHooks<S, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParseHooks")
+            .field("pre", &self.pre.len())
+            .field("post", &self.post.len())
+            .finish()
+    }
+}
+
+impl<S, R> Default for ParseHooks<S, R> {
+    fn default() -> Self {
+        Self {
+            pre: vec![],
+            post: vec![],
+        }
+    }
+}
+
+impl<S, R> ParseHooks<S, R> {
+    pub fn add_pre(&mut self, hook: PreParseHook<S>) -> &mut Self {
+        self.pre.push(hook);
+        self
+    }
+
+    pub fn add_post(&mut self, hook: PostParseHook<S, R>) -> &mut Self {
+        self.post.push(hook);
+        self
+    }
+
+    pub fn clear(&mut self) -> &mut Self {
+        self.pre.clear();
+        self.post.clear();
+        self
+    }
+
+    /// Call the pre parse hooks, return the arguments rewrote by them.
+    pub fn pre_parse(&mut self, set: &mut S, args: Args) -> Result<Args, Error> {
+        self.pre
+            .iter_mut()
+            .try_fold(args, |args, hook| hook(set, args))
+    }
+
+    /// Call the post parse hooks, return the result rewrote by them.
+    pub fn post_parse(&mut self, set: &mut S, ret: Result<R, Error>) -> Result<R, Error> {
+        self.post.iter_mut().fold(ret, |ret, hook| hook(set, ret))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use crate::parser::ParseHooks;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn hooks() {
+        assert!(hooks_impl().is_ok());
+    }
+
+    fn hooks_impl() -> Result<(), Error> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--name=s!")?;
+        parser.add_opt("--debug=b")?;
+
+        let pre_count = count.clone();
+
+        parser
+            // enable debug mode if `DEBUG` in arguments
+            .add_pre_parse(move |_, args| {
+                pre_count.fetch_add(1, Ordering::SeqCst);
+                Ok(Args::from(args.iter().map(|v| match v.to_str() {
+                    Some("DEBUG") => "--debug".into(),
+                    _ => v.clone(),
+                })))
+            })
+            // convert the failure to an error
+            .add_post_parse(|_, ret| {
+                let mut ret = ret?;
+
+                match ret.take_failure() {
+                    Some(e) => Err(crate::error!("parse failed: {e}")),
+                    None => Ok(ret),
+                }
+            });
+
+        let ret = parser.parse(Args::from(["app", "--name=a", "DEBUG"]))?;
+
+        assert!(ret.status());
+        assert_eq!(parser.find_val::<bool>("--debug")?, &true);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        parser.reset()?;
+
+        let ret = parser.parse(Args::from(["app", "--debug"]));
+
+        assert!(ret.is_err_and(|e| e.to_string().starts_with("parse failed")));
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        assert!(format!("{:?}", parser.hooks()).contains("pre: 1"));
+
+        parser.hooks_mut().clear();
+        parser.parse(Args::from(["app", "--name=b", "DEBUG"]))?;
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        // the hooks is a public field of parser
+        let mut parser = Parser {
+            policy: parser.policy,
+            optset: parser.optset,
+            hooks: ParseHooks::default(),
+        };
+
+        parser.hooks.add_pre(Box::new(|_, args| {
+            Ok(Args::from(args.iter().chain([&"--name=c".into()]).cloned()))
+        }));
+        assert!(parser.parse(Args::from(["app"]))?.status());
+        assert_eq!(parser.find_val::<String>("--name")?, "c");
+        Ok(())
+    }
+}
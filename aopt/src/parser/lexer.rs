@@ -0,0 +1,437 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use crate::args::ArgInfo;
+use crate::args::Args;
+use crate::parser::UserStyle;
+use crate::set::OptValidator;
+use crate::set::PrefixOptValidator;
+use crate::trace;
+use crate::Error;
+
+/// The event returned by [`Lexer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// Long option such as `--depth`, the name not contain the prefix.
+    ///
+    /// The `-depth` is a long option only if neither [`CombinedOption`](UserStyle::CombinedOption)
+    /// nor [`EmbeddedValue`](UserStyle::EmbeddedValue) enabled,
+    /// otherwise it is the short option `d`.
+    Long(Cow<'a, str>),
+
+    /// Short option such as `-v`, or one of the combined options `-abc`.
+    Short(char),
+
+    /// The value attached to previous option, such as `42` of `--depth=42`,
+    /// it is returned if the value not taken by [`value`](Lexer::value).
+    Value(Cow<'a, OsStr>),
+
+    /// The argument not like an option, or any argument after the [`Separator`](Token::Separator).
+    Positional(Cow<'a, OsStr>),
+
+    /// The separator `--`.
+    Separator,
+}
+
+/// A pull based lexer iterate over the command line arguments without declaring options.
+///
+/// The argument start with the prefix of [`OptValidator`] is an option,
+/// the option has a prefix of one character, such as `-v` and `/v`, is a short option.
+/// The [`UserStyle`] decide how to split the option:
+///
+/// * [`EqualWithValue`](UserStyle::EqualWithValue): `--depth=42` is split into `depth` and value `42`.
+///
+/// * [`Argument`](UserStyle::Argument): [`value`](Lexer::value) can take the next argument as value.
+///
+/// * [`EmbeddedValue`](UserStyle::EmbeddedValue): `-i42` is short option `i` with value `42`,
+///   the value of `-i42=x` is `42=x` because the `=` is kept in the embedded value.
+///
+/// * [`CombinedOption`](UserStyle::CombinedOption): `-abc` is short options `a`, `b` and `c`,
+///   [`value`](Lexer::value) take the rest characters if [`EmbeddedValue`](UserStyle::EmbeddedValue) enabled.
+///
+/// The [`EmbeddedValuePlus`](UserStyle::EmbeddedValuePlus) need the option names, it is not supported.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::parser::Lexer;
+/// # use aopt::parser::Token;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let args = Args::from(["app", "-vv", "--depth", "3", "-o=a.txt", "src", "--", "-x"]);
+/// let mut lexer = Lexer::new(&args).with_styles(vec![
+///     UserStyle::EqualWithValue,
+///     UserStyle::Argument,
+///     UserStyle::EmbeddedValue,
+///     UserStyle::CombinedOption,
+/// ]);
+/// let (mut verbose, mut depth, mut output, mut files) = (0, 0, None, vec![]);
+///
+/// // skip the program name
+/// lexer.next();
+/// while let Some(token) = lexer.next() {
+///     match token? {
+///         Token::Short('v') => verbose += 1,
+///         Token::Long(name) if name == "depth" => {
+///             depth = lexer.value()?.to_str().unwrap().parse::<i64>().unwrap();
+///         }
+///         Token::Short('o') => output = Some(lexer.value()?.into_owned()),
+///         Token::Positional(val) => files.push(val.into_owned()),
+///         Token::Separator => {}
+///         token => panic!("unexpected {token:?}"),
+///     }
+/// }
+///
+/// assert_eq!(verbose, 2);
+/// assert_eq!(depth, 3);
+/// assert_eq!(output.as_deref(), Some(OsStr::new("a.txt")));
+/// assert_eq!(files, ["src", "-x"]);
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lexer<'a, V = PrefixOptValidator> {
+    args: &'a [OsString],
+
+    idx: usize,
+
+    validator: V,
+
+    styles: Vec<UserStyle>,
+
+    /// Name of last option, using in error message.
+    name: Option<String>,
+
+    /// The prefix of last option.
+    prefix: Option<Cow<'a, str>>,
+
+    /// The rest characters of combined options.
+    shorts: String,
+
+    value: Option<Cow<'a, OsStr>>,
+
+    separated: bool,
+}
+
+impl<'a> Lexer<'a> {
+    /// Create a lexer with default [`PrefixOptValidator`] and the default styles of
+    /// [`OptStyleManager`](crate::parser::OptStyleManager).
+    pub fn new(args: &'a Args) -> Self {
+        Self::new_with(args, PrefixOptValidator::default())
+    }
+}
+
+impl<'a, V: OptValidator> Lexer<'a, V> {
+    /// Create a lexer with given validator and the default styles.
+    pub fn new_with(args: &'a [OsString], validator: V) -> Self {
+        Self {
+            args,
+            idx: 0,
+            validator,
+            styles: crate::parser::OptStyleManager::default().to_vec(),
+            name: None,
+            prefix: None,
+            shorts: String::default(),
+            value: None,
+            separated: false,
+        }
+    }
+
+    /// Set the validator, such as the [`validator`](crate::set::OptSet::validator) of parser.
+    pub fn with_validator<T: OptValidator>(self, validator: T) -> Lexer<'a, T> {
+        Lexer {
+            args: self.args,
+            idx: self.idx,
+            validator,
+            styles: self.styles,
+            name: self.name,
+            prefix: self.prefix,
+            shorts: self.shorts,
+            value: self.value,
+            separated: self.separated,
+        }
+    }
+
+    /// Set the styles, such as the [`style_manager`](crate::parser::PolicySettings::style_manager) of parser.
+    pub fn with_styles(mut self, styles: Vec<UserStyle>) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    pub fn validator(&self) -> &V {
+        &self.validator
+    }
+
+    pub fn styles(&self) -> &[UserStyle] {
+        &self.styles
+    }
+
+    /// Return the index of next argument.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+
+    /// Return the prefix of last option, such as `--` of `--depth`.
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    fn has_style(&self, style: UserStyle) -> bool {
+        self.styles.contains(&style)
+    }
+
+    fn next_arg(&mut self) -> Option<&'a OsStr> {
+        let arg = self.args.get(self.idx)?;
+
+        self.idx += 1;
+        Some(arg.as_os_str())
+    }
+
+    fn take_short(&mut self) -> Option<char> {
+        let ch = self.shorts.chars().next()?;
+
+        self.shorts.drain(..ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Take the value of last option.
+    ///
+    /// The value attached to the option is returned first,
+    /// such as `42` of `--depth=42`, or the rest characters of `-i42`.
+    /// Otherwise take the next argument if [`Argument`](UserStyle::Argument) enabled.
+    pub fn value(&mut self) -> Result<Cow<'a, OsStr>, Error> {
+        if !self.shorts.is_empty() {
+            if self.has_style(UserStyle::EmbeddedValue) {
+                let mut embedded = OsString::from(std::mem::take(&mut self.shorts));
+
+                if let Some(value) = self.value.take() {
+                    embedded.push("=");
+                    embedded.push(value);
+                }
+                return Ok(Cow::Owned(embedded));
+            }
+        } else if let Some(value) = self.value.take() {
+            return Ok(value);
+        } else if self.has_style(UserStyle::Argument) {
+            if let Some(arg) = self.next_arg() {
+                return Ok(Cow::Borrowed(arg));
+            }
+        }
+        Err(Error::sp_missing_value(
+            self.name.clone().unwrap_or_default(),
+        ))
+    }
+
+    fn lex(&mut self, arg: &'a OsStr) -> Result<Token<'a>, Error> {
+        let ArgInfo { name, value } = if self.has_style(UserStyle::EqualWithValue) {
+            match ArgInfo::parse(arg) {
+                Ok(info) => info,
+                Err(_) => return Ok(Token::Positional(Cow::Borrowed(arg))),
+            }
+        } else {
+            match arg.to_str() {
+                Some(name) => ArgInfo {
+                    name: Cow::Borrowed(name),
+                    value: None,
+                },
+                None => return Ok(Token::Positional(Cow::Borrowed(arg))),
+            }
+        };
+
+        if !self.validator.check(&name).map_err(Into::into)? {
+            return Ok(Token::Positional(Cow::Borrowed(arg)));
+        }
+        let (prefix, opt) = self.validator.split(&name).map_err(Into::into)?;
+        let mut chars = opt.chars();
+
+        let Some(ch) = chars.next() else {
+            return Ok(Token::Positional(Cow::Borrowed(arg)));
+        };
+        let short = prefix.chars().count() == 1;
+        let rest = chars.as_str();
+
+        trace!("lexing `{prefix}` `{opt}` with value {value:?}");
+        self.name = Some(name.to_string());
+        self.prefix = Some(prefix);
+        if short && rest.is_empty() {
+            self.value = value;
+            Ok(Token::Short(ch))
+        } else if short && self.has_style(UserStyle::CombinedOption) {
+            self.shorts = rest.to_owned();
+            self.value = value;
+            Ok(Token::Short(ch))
+        } else if short && self.has_style(UserStyle::EmbeddedValue) {
+            let mut embedded = OsString::from(rest);
+
+            if let Some(value) = value {
+                embedded.push("=");
+                embedded.push(value);
+            }
+            self.value = Some(Cow::Owned(embedded));
+            Ok(Token::Short(ch))
+        } else {
+            self.value = value;
+            Ok(Token::Long(opt))
+        }
+    }
+}
+
+impl<'a, V: OptValidator> Iterator for Lexer<'a, V> {
+    type Item = Result<Token<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ch) = self.take_short() {
+            return Some(Ok(Token::Short(ch)));
+        }
+        if let Some(value) = self.value.take() {
+            return Some(Ok(Token::Value(value)));
+        }
+        let arg = self.next_arg()?;
+
+        if self.separated {
+            Some(Ok(Token::Positional(Cow::Borrowed(arg))))
+        } else if arg == "--" {
+            self.separated = true;
+            Some(Ok(Token::Separator))
+        } else {
+            Some(self.lex(arg))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+
+    use crate::args::Args;
+    use crate::parser::Lexer;
+    use crate::parser::Token;
+    use crate::parser::UserStyle;
+    use crate::set::PrefixOptValidator;
+    use crate::Error;
+
+    fn pos(val: &str) -> Token<'_> {
+        Token::Positional(Cow::Borrowed(OsStr::new(val)))
+    }
+
+    #[test]
+    fn lexer() {
+        assert!(lexer_impl().is_ok());
+    }
+
+    fn lexer_impl() -> Result<(), Error> {
+        let args = Args::from(["-abc", "--name=foo", "+x", "-", "-i42", "--", "-v"]);
+        let tokens = Lexer::new(&args).collect::<Result<Vec<_>, Error>>()?;
+
+        // `-abc` is `a` with embedded value `bc` in default styles
+        assert_eq!(
+            tokens,
+            [
+                Token::Short('a'),
+                Token::Value(Cow::Borrowed(OsStr::new("bc"))),
+                Token::Long(Cow::Borrowed("name")),
+                Token::Value(Cow::Borrowed(OsStr::new("foo"))),
+                pos("+x"),
+                pos("-"),
+                Token::Short('i'),
+                Token::Value(Cow::Borrowed(OsStr::new("42"))),
+                Token::Separator,
+                pos("-v"),
+            ]
+        );
+
+        let validator = PrefixOptValidator::new(["--", "-", "+"].map(String::from).to_vec());
+        let mut lexer = Lexer::new(&args)
+            .with_validator(validator)
+            .with_styles(vec![UserStyle::CombinedOption, UserStyle::Argument]);
+
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('a')));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('b')));
+        // can not take the value in combined options without `EmbeddedValue`
+        assert!(lexer.value().is_err());
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('c')));
+        // `=` is part of name without `EqualWithValue`
+        assert_eq!(
+            lexer.next().transpose()?,
+            Some(Token::Long(Cow::Borrowed("name=foo")))
+        );
+        assert_eq!(lexer.prefix(), Some("--"));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('x')));
+        assert_eq!(lexer.prefix(), Some("+"));
+        assert_eq!(lexer.value()?, OsStr::new("-"));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('i')));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('4')));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Short('2')));
+        assert_eq!(lexer.next().transpose()?, Some(Token::Separator));
+        assert_eq!(lexer.idx(), 6);
+        assert_eq!(lexer.next().transpose()?, Some(pos("-v")));
+        assert_eq!(lexer.next().transpose()?, None);
+        assert!(lexer.value().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn lexer_single_prefix_long() {
+        assert!(lexer_single_prefix_long_impl().is_ok());
+    }
+
+    fn lexer_single_prefix_long_impl() -> Result<(), Error> {
+        let args = Args::from(["-depth", "-i42=x"]);
+        let equal = vec![UserStyle::EqualWithValue];
+        let tokens = Lexer::new(&args)
+            .with_styles(equal)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // `-depth` is a long option without `CombinedOption` and `EmbeddedValue`
+        assert_eq!(
+            tokens,
+            [
+                Token::Long(Cow::Borrowed("depth")),
+                Token::Long(Cow::Borrowed("i42")),
+                Token::Value(Cow::Borrowed(OsStr::new("x"))),
+            ]
+        );
+
+        let embedded = vec![UserStyle::EqualWithValue, UserStyle::EmbeddedValue];
+        let tokens = Lexer::new(&args)
+            .with_styles(embedded)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // the `=` is kept in the embedded value
+        assert_eq!(
+            tokens,
+            [
+                Token::Short('d'),
+                Token::Value(Cow::Borrowed(OsStr::new("epth"))),
+                Token::Short('i'),
+                Token::Value(Cow::Owned("42=x".into())),
+            ]
+        );
+
+        let combined = vec![UserStyle::EqualWithValue, UserStyle::CombinedOption];
+        let tokens = Lexer::new(&args)
+            .with_styles(combined)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        assert_eq!(
+            tokens,
+            [
+                Token::Short('d'),
+                Token::Short('e'),
+                Token::Short('p'),
+                Token::Short('t'),
+                Token::Short('h'),
+                Token::Short('i'),
+                Token::Short('4'),
+                Token::Short('2'),
+                Token::Value(Cow::Borrowed(OsStr::new("x"))),
+            ]
+        );
+        Ok(())
+    }
+}
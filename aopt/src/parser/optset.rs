@@ -12,9 +12,11 @@ use crate::opt::ConfigBuild;
 use crate::opt::ConfigValue;
 use crate::opt::Information;
 use crate::opt::Opt;
+use crate::opt::OptGroup;
 use crate::opt::OptParser;
 use crate::parser::ParserCommit;
 use crate::parser::Policy;
+use crate::set::ConfigLayer;
 use crate::set::OptValidator;
 use crate::set::PrefixedValidator;
 use crate::set::Set;
@@ -22,6 +24,7 @@ use crate::set::SetCfg;
 use crate::set::SetCommit;
 use crate::set::SetOpt;
 use crate::set::SetValueFindExt;
+use crate::value::BoolLiteral;
 use crate::value::Infer;
 use crate::value::Placeholder;
 use crate::value::RawValParser;
@@ -38,6 +41,9 @@ pub struct HCOptSet<'a, S> {
     set: S,
     inv: Option<Invoker<'a, Self>>,
     ser: AppServices,
+    groups: Vec<OptGroup>,
+    layers: Vec<ConfigLayer>,
+    literal: Option<BoolLiteral>,
 }
 
 impl<S: Default> Default for HCOptSet<'_, S> {
@@ -46,6 +52,9 @@ impl<S: Default> Default for HCOptSet<'_, S> {
             set: Default::default(),
             inv: Some(Invoker::default()),
             ser: Default::default(),
+            groups: vec![],
+            layers: vec![],
+            literal: None,
         }
     }
 }
@@ -56,6 +65,9 @@ impl<'a, S> HCOptSet<'a, S> {
             set,
             inv: Some(inv),
             ser: AppServices::default(),
+            groups: vec![],
+            layers: vec![],
+            literal: None,
         }
     }
 
@@ -97,11 +109,35 @@ impl<'a, S> HCOptSet<'a, S> {
         &mut self.ser
     }
 
+    pub(crate) fn set_and_service_mut(&mut self) -> (&mut S, &mut AppServices) {
+        (&mut self.set, &mut self.ser)
+    }
+
     pub fn set_service(&mut self, ser: AppServices) -> &mut Self {
         self.ser = ser;
         self
     }
 
+    /// Register an [`OptGroup`], it will be checked in
+    /// [`post_check`](crate::set::SetChecker::post_check).
+    pub fn add_group(&mut self, group: OptGroup) -> &mut Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Register a [`ConfigLayer`], the values of it are used if option not set
+    /// in command line or environment variable.
+    pub fn add_layer(&mut self, layer: ConfigLayer) -> &mut Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Set the [`BoolLiteral`] used for parsing [`bool`] values of the set.
+    pub fn set_bool_literal(&mut self, literal: BoolLiteral) -> &mut Self {
+        self.literal = Some(literal);
+        self
+    }
+
     pub fn set_policy<P>(self, policy: P) -> Parser<Self, P>
     where
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self>>,
@@ -146,6 +182,41 @@ where
     }
 }
 
+impl<'a, S> HCOptSet<'a, S>
+where
+    S: Set,
+{
+    /// Parse the arguments with `policy`, the option values are not initialized.
+    pub(crate) fn parse_policy_uninit<P>(
+        &mut self,
+        args: Args,
+        policy: &mut P,
+    ) -> Result<P::Ret, Error>
+    where
+        P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self>>,
+    {
+        let mut inv = self.inv.take().unwrap();
+
+        let ret = policy.parse(self, &mut inv, args).map_err(Into::into);
+
+        self.inv = Some(inv);
+        ret
+    }
+
+    /// Update the bound targets deferred by a [`Transaction`](crate::parser::Transaction).
+    pub(crate) fn flush_binds(&mut self) -> Result<(), Error> {
+        match self.inv.take() {
+            Some(mut inv) => {
+                let ret = inv.flush_binds(self);
+
+                self.inv = Some(inv);
+                ret
+            }
+            None => Ok(()),
+        }
+    }
+}
+
 impl<S> AppStorage for HCOptSet<'_, S> {
     /// Set the value that can access in option handler.
     ///
@@ -232,6 +303,9 @@ impl<S> AppStorage for HCOptSet<'_, S> {
     fn take_app_data<T: ErasedTy>(&mut self) -> Result<T, Error> {
         AppStorage::take_app_data(&mut self.ser)
     }
+    fn set_cloneable_app_data<T: ErasedTy + Clone>(&mut self, val: T) -> Option<T> {
+        AppStorage::set_cloneable_app_data(&mut self.ser, val)
+    }
 }
 
 impl<'a, S> HCOptSet<'a, S>
@@ -467,6 +541,18 @@ impl<S: Set> crate::set::Set for HCOptSet<'_, S> {
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         S::insert(&mut self.set, opt)
     }
+
+    fn groups(&self) -> &[OptGroup] {
+        &self.groups
+    }
+
+    fn layers(&self) -> &[ConfigLayer] {
+        &self.layers
+    }
+
+    fn bool_literal(&self) -> Option<&BoolLiteral> {
+        self.literal.as_ref()
+    }
 }
 
 impl<S> OptValidator for HCOptSet<'_, S>
@@ -512,11 +598,26 @@ where
         policy: &mut P,
     ) -> Result<<P as Policy>::Ret, Self::Error> {
         self.init()?;
+        self.parse_policy_uninit(args, policy)
+    }
+
+    async fn parse_policy_async(
+        &mut self,
+        args: Args,
+        policy: &mut P,
+    ) -> Result<<P as Policy>::Ret, Self::Error> {
+        self.init()?;
 
         let mut inv = self.inv.take().unwrap();
 
-        let ret = policy.parse(self, &mut inv, args).map_err(Into::into);
+        inv.set_allow_async(true);
+
+        let ret = policy
+            .parse_async(self, &mut inv, args)
+            .await
+            .map_err(Into::into);
 
+        inv.set_allow_async(false);
         self.inv = Some(inv);
         ret
     }
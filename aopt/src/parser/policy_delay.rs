@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use super::poll_ready;
 use super::OptStyleManager;
 use super::Policy;
 use super::PolicySettings;
@@ -14,7 +15,11 @@ use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
 use crate::ctx::Invoker;
+use crate::guess::invoke_handler;
+use crate::guess::process_env;
 use crate::guess::process_handler_ret;
+use crate::guess::process_layers;
+use crate::guess::process_occurrence;
 use crate::guess::InnerCtxSaver;
 use crate::guess::InvokeGuess;
 use crate::guess::SimpleMatRet;
@@ -22,6 +27,7 @@ use crate::opt::Opt;
 use crate::opt::OptParser;
 use crate::parser::Action;
 use crate::parser::FailManager;
+use crate::set::similar_names;
 use crate::set::OptValidator;
 use crate::set::SetChecker;
 use crate::set::SetExt;
@@ -346,7 +352,7 @@ where
     // ignore failure
     #[allow(clippy::too_many_arguments)]
     #[inline(always)]
-    pub fn invoke_opt_callback<'a, 'b, Inv>(
+    pub async fn invoke_opt_callback<'a, 'b, Inv>(
         &mut self,
         uid: Uid,
         ctx: &mut Ctx<'a>,
@@ -358,19 +364,41 @@ where
     where
         Inv: HandlerCollection<'b, S>,
     {
-        let fail = |e: Error| {
+        let idx = inner_ctx.idx();
+        let name = set.opt(uid)?.name().to_owned();
+        let fail = |mut e: Error| {
+            // keep the argument and option caused the failure
+            if e.index().is_none() {
+                e = e.with_index(idx);
+            }
+            if e.name().is_none() {
+                e = e.with_name(name.as_str());
+            }
             fail.push(e);
             Ok(())
         };
 
+        let source = inner_ctx.source();
+
         ctx.set_inner_ctx(Some(inner_ctx.with_uid(uid)));
-        let ret = process_handler_ret(inv.invoke_fb(&uid, set, ctx), |_| Ok(()), fail)?;
+        if !process_occurrence(set, uid, idx)? {
+            set.opt_mut(uid)?.set_matched(true);
+            return Ok(true);
+        }
+        let ret = process_handler_ret(invoke_handler(set, inv, ctx, uid).await, |_| Ok(()), fail)?;
 
+        if ret {
+            let opt = set.opt_mut(uid)?;
+            let act = *opt.action();
+
+            opt.add_position(idx);
+            opt.accessor_mut().add_source(source, &act);
+        }
         set.opt_mut(uid)?.set_matched(ret);
         Ok(ret)
     }
 
-    pub fn process_delay_ctx<'a, 'b, Inv>(
+    pub async fn process_delay_ctx<'a, 'b, Inv>(
         &mut self,
         ctx: &mut Ctx<'a>,
         set: &mut S,
@@ -400,7 +428,8 @@ where
                         inv,
                         fail,
                         inner_ctx.clone().with_uid(*uid),
-                    )?
+                    )
+                    .await?
                 };
 
                 // if it matched,
@@ -419,7 +448,7 @@ where
         Ok(SimpleMatRet::new(true, consume))
     }
 
-    pub fn save_or_call<'a, 'b, 'c, Inv>(
+    pub async fn save_or_call<'a, 'b, 'c, Inv>(
         &mut self,
         guess: &mut InvokeGuess<'a, 'b, S, Inv>,
         saver: InnerCtxSaver<'b>,
@@ -441,14 +470,16 @@ where
                 let name = guess.set.opt(*uid)?.name();
 
                 if self.no_delay_opt.iter().any(|v| v == name) {
-                    let ret = self.invoke_opt_callback(
-                        *uid,
-                        guess.ctx,
-                        guess.set,
-                        guess.inv,
-                        guess.fail,
-                        inner_ctx.clone().with_uid(*uid),
-                    )?;
+                    let ret = self
+                        .invoke_opt_callback(
+                            *uid,
+                            guess.ctx,
+                            guess.set,
+                            guess.inv,
+                            guess.fail,
+                            inner_ctx.clone().with_uid(*uid),
+                        )
+                        .await?;
 
                     // if it matched,
                     // so the policy_inner_ctx matched
@@ -490,7 +521,7 @@ where
     Chk: SetChecker<S>,
     S: crate::set::Set + OptParser + OptValidator,
 {
-    pub(crate) fn parse_impl<'a>(
+    pub(crate) async fn parse_impl<'a>(
         &mut self,
         set: &mut <Self as Policy>::Set,
         inv: &mut <Self as Policy>::Inv<'_>,
@@ -549,7 +580,9 @@ where
                             // pretend we are matched, cause it is delay
                             matched = true;
                             consume = ret.consume;
-                            if let Some(ret) = self.save_or_call(&mut guess, ret, &mut contexts)? {
+                            if let Some(ret) =
+                                self.save_or_call(&mut guess, ret, &mut contexts).await?
+                            {
                                 // if the call returned, set the real return value
                                 (matched, consume) = (ret.matched, ret.consume);
                             }
@@ -568,7 +601,11 @@ where
                         }
                     }
                     if !pre && !stopped && !matched && self.strict() {
-                        return Err(opt_fail.cause(Error::sp_not_found(name)));
+                        let suggestions = similar_names(set, &name);
+
+                        return Err(
+                            opt_fail.cause(Error::sp_not_found(name).with_suggestions(suggestions))
+                        );
                     }
                 }
                 if !like_opt {
@@ -612,7 +649,7 @@ where
             };
 
             trace!("guess Cmd = {:?}", guess.name);
-            Self::filter(pre, guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
+            Self::filter(pre, guess.guess_and_invoke(&UserStyle::Cmd, overload).await)?;
             if let Action::Quit = ctx.policy_act() {
                 return Ok(());
             }
@@ -634,7 +671,7 @@ where
                 guess.idx = Self::noa_pos(idx);
                 guess.name = crate::str::osstr_to_str_i(&args, Self::noa_pos(idx));
                 trace!("guess Pos argument = {:?} @ {}", guess.name, guess.idx);
-                Self::filter(pre, guess.guess_and_invoke(&UserStyle::Pos, overload))?;
+                Self::filter(pre, guess.guess_and_invoke(&UserStyle::Pos, overload).await)?;
                 match guess.ctx.policy_act() {
                     Action::Stop => {
                         guess.ctx.reset_policy_act();
@@ -653,7 +690,8 @@ where
         for saver in contexts {
             if let Some(ret) = Self::filter(
                 pre,
-                self.process_delay_ctx(&mut prev_ctx, set, inv, &mut opt_fail, saver),
+                self.process_delay_ctx(&mut prev_ctx, set, inv, &mut opt_fail, saver)
+                    .await,
             )? {
                 if ret.matched {
                     match prev_ctx.policy_act() {
@@ -673,6 +711,9 @@ where
             }
         }
 
+        // fallback to the environment variable and configuration if option not set
+        process_env(set, inv, ctx).await?;
+        process_layers(set, inv, ctx).await?;
         opt_fail.process_check(self.checker().opt_check(set))?;
         pos_fail.process_check(self.checker().pos_check(set))?;
 
@@ -692,7 +733,10 @@ where
         };
 
         trace!("guess Main {:?}", guess.name);
-        Self::filter(pre, guess.guess_and_invoke(&UserStyle::Main, overload))?;
+        Self::filter(
+            pre,
+            guess.guess_and_invoke(&UserStyle::Main, overload).await,
+        )?;
         main_fail.process_check(self.checker().post_check(set))?;
         Ok(())
     }
@@ -718,9 +762,20 @@ where
         inv: &mut Self::Inv<'_>,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
+        poll_ready(self.parse_async(set, inv, orig))
+    }
+
+    async fn parse_async(
+        &mut self,
+        set: &mut Self::Set,
+        inv: &mut Self::Inv<'_>,
+        orig: Args,
+    ) -> Result<Self::Ret, Self::Error> {
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_bool_literal(set.bool_literal().cloned());
 
-        match self.parse_impl(set, inv, &orig, &mut ctx) {
+        match self.parse_impl(set, inv, &orig, &mut ctx).await {
             Ok(_) => Ok(Return::new(ctx)),
             Err(e) => {
                 if e.is_failure() {
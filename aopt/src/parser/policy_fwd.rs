@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use super::poll_ready;
 use super::FailManager;
 use super::OptStyleManager;
 use super::Policy;
@@ -13,10 +14,13 @@ use crate::args::ArgInfo;
 use crate::args::Args;
 use crate::ctx::Ctx;
 use crate::ctx::Invoker;
+use crate::guess::process_env;
+use crate::guess::process_layers;
 use crate::guess::InvokeGuess;
 use crate::opt::Opt;
 use crate::opt::OptParser;
 use crate::parser::Action;
+use crate::set::similar_names;
 use crate::set::OptValidator;
 use crate::set::SetChecker;
 use crate::set::SetOpt;
@@ -388,7 +392,7 @@ where
     Chk: SetChecker<S>,
     S: crate::set::Set + OptParser + OptValidator,
 {
-    pub(crate) fn parse_impl<'a>(
+    pub(crate) async fn parse_impl<'a>(
         &mut self,
         set: &mut <Self as Policy>::Set,
         inv: &mut <Self as Policy>::Inv<'_>,
@@ -439,7 +443,7 @@ where
                     like_opt = true;
                     for style in opt_styles.iter() {
                         if let Some(Some(ret)) =
-                            Self::filter(pre, guess.guess_and_invoke(style, overload))?
+                            Self::filter(pre, guess.guess_and_invoke(style, overload).await)?
                         {
                             (matched, consume) = (ret.matched, ret.consume);
                         }
@@ -462,7 +466,11 @@ where
                     // and in strict mode
                     // raise an Error::sp_not_found
                     if !pre && !stopped && !matched && self.strict() {
-                        return Err(opt_fail.cause(Error::sp_not_found(name)));
+                        let suggestions = similar_names(set, &name);
+
+                        return Err(
+                            opt_fail.cause(Error::sp_not_found(name).with_suggestions(suggestions))
+                        );
                     }
                 }
                 if !like_opt {
@@ -483,6 +491,9 @@ where
             }
         }
 
+        // fallback to the environment variable and configuration if option not set
+        process_env(set, inv, ctx).await?;
+        process_layers(set, inv, ctx).await?;
         opt_fail.process_check(self.checker().opt_check(set))?;
 
         let args = lefts;
@@ -507,7 +518,7 @@ where
             };
 
             trace!("guess Cmd = {:?}", guess.name);
-            Self::filter(pre, guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
+            Self::filter(pre, guess.guess_and_invoke(&UserStyle::Cmd, overload).await)?;
             if let Action::Quit = ctx.policy_act() {
                 return Ok(());
             }
@@ -529,7 +540,7 @@ where
                 guess.idx = Self::noa_pos(idx);
                 guess.name = crate::str::osstr_to_str_i(&args, Self::noa_pos(idx));
                 trace!("guess Pos argument = {:?} @ {}", guess.name, guess.idx);
-                Self::filter(pre, guess.guess_and_invoke(&UserStyle::Pos, overload))?;
+                Self::filter(pre, guess.guess_and_invoke(&UserStyle::Pos, overload).await)?;
                 match guess.ctx.policy_act() {
                     Action::Stop => {
                         guess.ctx.reset_policy_act();
@@ -560,7 +571,10 @@ where
         };
 
         trace!("guess Main {:?}", guess.name);
-        Self::filter(pre, guess.guess_and_invoke(&UserStyle::Main, overload))?;
+        Self::filter(
+            pre,
+            guess.guess_and_invoke(&UserStyle::Main, overload).await,
+        )?;
         main_fail.process_check(self.checker().post_check(set))?;
         Ok(())
     }
@@ -584,12 +598,22 @@ where
         &mut self,
         set: &mut Self::Set,
         inv: &mut Self::Inv<'_>,
+        orig: Args,
+    ) -> Result<Self::Ret, Self::Error> {
+        poll_ready(self.parse_async(set, inv, orig))
+    }
 
+    async fn parse_async(
+        &mut self,
+        set: &mut Self::Set,
+        inv: &mut Self::Inv<'_>,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_bool_literal(set.bool_literal().cloned());
 
-        match self.parse_impl(set, inv, &orig, &mut ctx) {
+        match self.parse_impl(set, inv, &orig, &mut ctx).await {
             Ok(_) => Ok(Return::new(ctx)),
             Err(e) => {
                 if e.is_failure() {
@@ -1,8 +1,12 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::ffi::OsString;
 
 use crate::args::Args;
 use crate::ctx::Ctx;
+use crate::opt::Opt;
 use crate::opt::Style;
+use crate::set::Set;
 use crate::{Error, Uid};
 
 #[derive(Debug, Clone, Default)]
@@ -160,3 +164,261 @@ impl From<&mut Return> for Args {
         Self::new(value.take_args().into_iter())
     }
 }
+
+/// The return value of [`Policy`](crate::parser::Policy) can be scored by
+/// [`score`](crate::parser::Parser::score), it is required by [`getopt!`](crate::getopt).
+pub trait Scoring {
+    /// Take the failure of parsing, return `None` if the parsing successes.
+    fn take_failure(&mut self) -> Option<Error>;
+
+    /// Calculate the score from options of `set` and the return value.
+    ///
+    /// The default score is same for all the return values,
+    /// so the parsers are tried in order of declaration.
+    fn score<S: Set>(&self, _set: &S) -> Score {
+        Score::default()
+    }
+}
+
+impl Scoring for Return {
+    fn take_failure(&mut self) -> Option<Error> {
+        Return::take_failure(self)
+    }
+
+    fn score<S: Set>(&self, set: &S) -> Score {
+        Score::from_ret(set, self)
+    }
+}
+
+/// How well a [`Parser`](crate::parser::Parser) matched the arguments,
+/// used by [`getopt!`](crate::getopt) select the best parser.
+///
+/// The score with more matched commands is better,
+/// then the one with less unmatched arguments,
+/// then the one consumed more arguments.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Score {
+    cmds: usize,
+
+    consumed: usize,
+
+    unmatched: usize,
+}
+
+impl Score {
+    pub fn new(cmds: usize, consumed: usize, unmatched: usize) -> Self {
+        Self {
+            cmds,
+            consumed,
+            unmatched,
+        }
+    }
+
+    /// Calculate the score from options of `set` and the return value of parsing.
+    pub fn from_ret<S: Set>(set: &S, ret: &Return) -> Self {
+        let mut cmds = 0;
+        let mut consumed = 0;
+        let mut noa = HashSet::new();
+        let total = ret.ctx.orig.len();
+
+        for opt in set.iter().filter(|v| v.matched()) {
+            if opt.mat_style(Style::Cmd) {
+                cmds += 1;
+                noa.extend(opt.positions().iter().copied());
+            } else if opt.mat_style(Style::Pos) {
+                noa.extend(opt.positions().iter().copied());
+            } else if !opt.mat_style(Style::Main) {
+                // skip the value not from command line, such as environment variable
+                consumed += opt.positions().iter().filter(|&&v| v < total).count();
+            }
+        }
+        consumed += noa.len();
+        Self::new(cmds, consumed, ret.args().len().saturating_sub(noa.len()))
+    }
+
+    /// The number of matched commands.
+    pub fn cmds(&self) -> usize {
+        self.cmds
+    }
+
+    /// The number of arguments consumed by options, positionals and commands.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// The number of arguments not matched by any option.
+    pub fn unmatched(&self) -> usize {
+        self.unmatched
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmds
+            .cmp(&other.cmds)
+            .then_with(|| other.unmatched.cmp(&self.unmatched))
+            .then_with(|| self.consumed.cmp(&other.consumed))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use crate::err::Kind;
+    use crate::getopt;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn best_match() {
+        assert!(best_match_impl().is_ok());
+    }
+
+    fn best_match_impl() -> Result<(), Error> {
+        let mut loose = AFwdParser::default();
+        let mut build = AFwdParser::default();
+        let mut run = AFwdParser::default();
+        let build_jobs = Arc::new(AtomicUsize::new(0));
+        let run_debug = Arc::new(AtomicUsize::new(0));
+
+        loose.set_prepolicy(true);
+        loose.add_opt("--debug=b")?;
+        build.add_opt("build=c")?;
+        build.add_opt("--debug=b")?;
+
+        let count = build_jobs.clone();
+
+        build
+            .add_opt("--jobs=i")?
+            .on(move |_: &mut AHCSet, ctx: &mut Ctx| {
+                count.fetch_add(1, Ordering::SeqCst);
+                Ok(Some(ctx.value::<i64>()?))
+            })?;
+
+        let count = run_debug.clone();
+
+        run.add_opt("--debug=b")?
+            .on(move |_: &mut AHCSet, ctx: &mut Ctx| {
+                count.fetch_add(1, Ordering::SeqCst);
+                Ok(Some(ctx.value::<bool>()?))
+            })?;
+        run.add_opt("--jobs=i!")?;
+        run.add_opt("file=p@1..")?.set_pos_type_only::<String>();
+
+        // the parser matched command is better
+        let ret = getopt!(
+            Args::from(["app", "build", "--debug", "--jobs=4"]),
+            "loose" => &mut loose,
+            "build" => &mut build,
+            "run" => &mut run
+        )?;
+
+        assert_eq!(ret.parser, "build");
+        assert_eq!(build.find_val::<i64>("--jobs")?, &4);
+        // only the handler of selected parser is invoked, and only once
+        assert_eq!(build_jobs.load(Ordering::SeqCst), 1);
+        assert_eq!(run_debug.load(Ordering::SeqCst), 0);
+        // the values of other parsers are rolled back
+        assert!(!loose.find_opt("--debug")?.matched());
+        assert!(!run.find_opt("--debug")?.matched());
+
+        // the parser consumed more arguments is better
+        let ret = getopt!(
+            Args::from(["app", "--debug", "--jobs=2", "a.txt"]),
+            "loose" => &mut loose,
+            "run" => &mut run
+        )?;
+
+        assert_eq!(ret.parser, "run");
+        assert_eq!(run.find_vals::<String>("file")?, &["a.txt"]);
+        assert_eq!(build_jobs.load(Ordering::SeqCst), 1);
+        assert_eq!(run_debug.load(Ordering::SeqCst), 1);
+
+        // all the parsers failed
+        let ret = getopt!(
+            Args::from(["app", "--jobs=x"]),
+            "build" => &mut build,
+            "run" => &mut run
+        );
+        let err = ret.err().unwrap();
+        let desp = err.to_string();
+
+        assert_eq!(err.kind(), &Kind::NoParserMatched);
+        assert_eq!(err.fails().len(), 2);
+        assert!(desp.contains("parser `build`"));
+        assert!(desp.contains("parser `run`"));
+        Ok(())
+    }
+
+    #[test]
+    fn best_match_fallback() {
+        assert!(best_match_fallback_impl().is_ok());
+    }
+
+    fn best_match_fallback_impl() -> Result<(), Error> {
+        let mut strict = AFwdParser::default();
+        let mut loose = AFwdParser::default();
+
+        strict.add_opt("build=c")?;
+        strict
+            .add_opt("--jobs=i")?
+            .on(|_: &mut AHCSet, ctx: &mut Ctx| {
+                let jobs = ctx.value::<i64>()?;
+
+                if jobs > 0 {
+                    Ok(Some(jobs))
+                } else {
+                    Err(crate::failure!("jobs must be positive"))
+                }
+            })?;
+        loose.set_prepolicy(true);
+        loose.add_opt("--jobs=i")?;
+
+        // the handlers not invoked when scoring, try the next parser if the best one failed
+        let ret = getopt!(
+            Args::from(["app", "build", "--jobs=0"]),
+            "strict" => &mut strict,
+            "loose" => &mut loose
+        )?;
+
+        assert_eq!(ret.parser, "loose");
+        assert_eq!(loose.find_val::<i64>("--jobs")?, &0);
+        assert!(!strict.find_opt("build")?.matched());
+
+        let ret = getopt!(
+            Args::from(["app", "build", "--jobs=2"]),
+            "strict" => &mut strict,
+            "loose" => &mut loose
+        )?;
+
+        assert_eq!(ret.parser, "strict");
+        assert_eq!(strict.find_val::<i64>("--jobs")?, &2);
+
+        // the failures of real parsing are collected
+        loose.add_opt("--name=s!")?;
+
+        let err = getopt!(
+            Args::from(["app", "build", "--jobs=0"]),
+            "strict" => &mut strict,
+            "loose" => &mut loose
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(err.kind(), &Kind::NoParserMatched);
+        assert_eq!(err.fails().len(), 2);
+        assert!(err.to_string().contains("parser `strict`"));
+        assert!(err.to_string().contains("parser `loose`"));
+        assert!(format!("{err:?}").contains("jobs must be positive"));
+        Ok(())
+    }
+}
@@ -0,0 +1,411 @@
+use std::ffi::OsString;
+use std::io::BufRead;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::args::Args;
+use crate::ctx::Invoker;
+use crate::opt::Opt;
+use crate::parser::HCOptSet;
+use crate::parser::Parser;
+use crate::parser::Policy;
+use crate::set::Set;
+use crate::Error;
+use crate::Uid;
+
+/// An incremental parse session of [`Parser`], it parse one command at a time against the same option set.
+///
+/// The option set is initialized once when the session created.
+/// Before each command, the options are [`reset`](crate::opt::Opt::reset),
+/// the values of option are cleared and initialized again unless the option is persisted.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--verbose=b")?.set_action(Action::Cnt);
+/// parser.add_opt("--name=s")?.set_value_t(String::from("world"));
+///
+/// let verbose = parser.find_uid("--verbose")?;
+/// let mut session = parser.session()?;
+///
+/// session.persist(verbose);
+///
+/// session.feed(Args::from(["repl", "--verbose", "--name=foo"]))?;
+/// assert_eq!(session.find_val::<u64>("--verbose")?, &1);
+/// assert_eq!(session.find_val::<String>("--name")?, "foo");
+///
+/// // the value of `--name` reset to default value
+/// session.feed(Args::from(["repl", "--verbose"]))?;
+/// assert_eq!(session.find_val::<u64>("--verbose")?, &2);
+/// assert_eq!(session.find_val::<String>("--name")?, "world");
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+pub struct Session<'p, 'a, S, P>
+where
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    parser: &'p mut Parser<HCOptSet<'a, S>, P>,
+
+    persist: Vec<Uid>,
+}
+
+impl<'a, S, P> std::fmt::Debug for Session<'_, 'a, S, P>
+where
+    S: std::fmt::Debug,
+    P: Policy<Set = HCOptSet<'a, S>> + std::fmt::Debug,
+    P::Ret: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Session")
+            .field("parser", &self.parser)
+            .field("persist", &self.persist)
+            .finish()
+    }
+}
+
+impl<'p, 'a, S, P> Session<'p, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>, Inv<'a> = Invoker<'a, HCOptSet<'a, S>>>,
+{
+    pub fn new(parser: &'p mut Parser<HCOptSet<'a, S>, P>) -> Result<Self, Error> {
+        parser.init()?;
+        Ok(Self {
+            parser,
+            persist: vec![],
+        })
+    }
+
+    pub fn parser(&self) -> &Parser<HCOptSet<'a, S>, P> {
+        self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<HCOptSet<'a, S>, P> {
+        self.parser
+    }
+
+    /// Keep the values of option `uid` across the commands.
+    pub fn persist(&mut self, uid: Uid) -> &mut Self {
+        if !self.persist.contains(&uid) {
+            self.persist.push(uid);
+        }
+        self
+    }
+
+    /// Keep the values of all options across the commands.
+    pub fn persist_all(&mut self) -> &mut Self {
+        self.persist = self.parser.optset().iter().map(|v| v.uid()).collect();
+        self
+    }
+
+    /// Reset the values of option `uid` before next command.
+    pub fn unpersist(&mut self, uid: Uid) -> &mut Self {
+        self.persist.retain(|v| v != &uid);
+        self
+    }
+
+    /// Reset the values of all options before next command.
+    pub fn unpersist_all(&mut self) -> &mut Self {
+        self.persist.clear();
+        self
+    }
+
+    pub fn is_persist(&self, uid: Uid) -> bool {
+        self.persist.contains(&uid)
+    }
+
+    /// Parse the arguments of one command, the hooks of parser are called.
+    pub fn feed(&mut self, args: Args) -> Result<P::Ret, Error> {
+        for opt in self.parser.optset.iter_mut() {
+            opt.reset();
+            if !self.persist.contains(&opt.uid()) {
+                let (rawvals, values) = opt.accessor_mut().handlers();
+
+                rawvals.clear();
+                values.clear();
+                opt.init()?;
+            }
+        }
+
+        let parser = &mut *self.parser;
+        let ret = parser
+            .hooks
+            .pre_parse(&mut parser.optset, args)
+            .and_then(|args| parser.optset.parse_policy_uninit(args, &mut parser.policy));
+
+        parser.hooks.post_parse(&mut parser.optset, ret)
+    }
+}
+
+impl<'a, S, P> Deref for Session<'_, 'a, S, P>
+where
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    type Target = Parser<HCOptSet<'a, S>, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.parser
+    }
+}
+
+impl<'a, S, P> DerefMut for Session<'_, 'a, S, P>
+where
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.parser
+    }
+}
+
+/// Read the commands line by line, the words of line are split like shell.
+///
+/// The words can be quoted by `'` or `"`, and the character can be escaped by `\`.
+/// The `name` is prepended to the arguments as program name.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::parser::LineReader;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let input = "add --name 'hello world'\n\nquit\n";
+/// let mut reader = LineReader::new("repl", input.as_bytes());
+///
+/// assert_eq!(
+///     reader.read_args()?.unwrap().as_slice(),
+///     ["repl", "add", "--name", "hello world"]
+/// );
+/// assert_eq!(reader.read_args()?.unwrap().as_slice(), ["repl"]);
+/// assert_eq!(reader.read_args()?.unwrap().as_slice(), ["repl", "quit"]);
+/// assert!(reader.read_args()?.is_none());
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineReader<R> {
+    name: String,
+
+    reader: R,
+}
+
+impl<R> LineReader<R> {
+    pub fn new(name: impl Into<String>, reader: R) -> Self {
+        Self {
+            name: name.into(),
+            reader,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Split the `line` into arguments with program name.
+    ///
+    /// Return an error if the `line` has an unterminated quote.
+    pub fn split(&self, line: &str) -> Result<Args, Error> {
+        let (words, _, quote) = split_line(line);
+
+        if let Some(quote) = quote {
+            return Err(crate::error!(
+                "unterminated quote `{quote}` in line: {:?}",
+                line.trim_end()
+            ));
+        }
+        Ok(Args::from(
+            std::iter::once(OsString::from(&self.name)).chain(words),
+        ))
+    }
+}
+
+impl<R: BufRead> LineReader<R> {
+    /// Read next line, return `None` if reach the end of input.
+    ///
+    /// Return an error if the line has an unterminated quote.
+    pub fn read_args(&mut self) -> Result<Option<Args>, Error> {
+        let mut line = String::default();
+        let count = self
+            .reader
+            .read_line(&mut line)
+            .map_err(|e| crate::error!("can not read line: {e:?}"))?;
+
+        (count > 0).then(|| self.split(&line)).transpose()
+    }
+}
+
+impl LineReader<std::io::StdinLock<'static>> {
+    /// Create a reader read from standard input.
+    pub fn stdin(name: impl Into<String>) -> Self {
+        Self::new(name, std::io::stdin().lock())
+    }
+}
+
+/// Split the line into words, return the words and a flag indicate if the last word is finished.
+///
+/// The last word is not finished if the quote of it is unterminated.
+#[cfg(feature = "shell")]
+pub(crate) fn split_words(line: &str) -> (Vec<OsString>, bool) {
+    let (words, finished, _) = split_line(line);
+
+    (words, finished)
+}
+
+/// Split the line into words, also return the unterminated quote.
+fn split_line(line: &str) -> (Vec<OsString>, bool, Option<char>) {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (_, '\\') if quote != Some('\'') => {
+                if let Some(ch) = chars.next() {
+                    word.get_or_insert_with(String::default).push(ch);
+                }
+            }
+            (Some(q), ch) if q == ch => {
+                quote = None;
+            }
+            (Some(_), ch) => {
+                word.get_or_insert_with(String::default).push(ch);
+            }
+            (None, '\'' | '"') => {
+                quote = Some(ch);
+                word.get_or_insert_with(String::default);
+            }
+            (None, ch) if ch.is_whitespace() => {
+                words.extend(word.take().map(OsString::from));
+            }
+            (None, ch) => {
+                word.get_or_insert_with(String::default).push(ch);
+            }
+        }
+    }
+    let finished = word.is_none() && quote.is_none();
+
+    words.extend(word.map(OsString::from));
+    (words, finished, quote)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parser::session::split_line;
+    use crate::parser::LineReader;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn session() {
+        assert!(session_impl().is_ok());
+    }
+
+    fn session_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+        let input = "--file a --count=1 --debug\n--file 'b c'\n--count=x\n--file d --count=3";
+
+        parser.add_opt("--file=s")?;
+        parser.add_opt("--count=i")?.set_value_t(0i64);
+        let debug = parser.add_opt("--debug=b")?.run()?;
+
+        let mut reader = LineReader::new("repl", input.as_bytes());
+        let mut session = parser.session()?;
+
+        session.persist(debug);
+
+        let ret = session.feed(reader.read_args()?.unwrap())?;
+
+        assert!(ret.status());
+        assert_eq!(session.find_vals::<String>("--file")?, &["a"]);
+        assert_eq!(session.find_val::<i64>("--count")?, &1);
+        assert_eq!(session.find_val::<bool>("--debug")?, &true);
+
+        let ret = session.feed(reader.read_args()?.unwrap())?;
+
+        assert!(ret.status());
+        assert_eq!(session.find_vals::<String>("--file")?, &["b c"]);
+        assert_eq!(session.find_val::<i64>("--count")?, &0);
+        assert_eq!(session.find_val::<bool>("--debug")?, &true);
+
+        // the failure of command not affect the session
+        let ret = session.feed(reader.read_args()?.unwrap())?;
+
+        assert!(!ret.status());
+
+        session.unpersist(debug);
+        session.persist_all();
+        session.unpersist(session.find_uid("--file")?);
+
+        let ret = session.feed(reader.read_args()?.unwrap())?;
+
+        assert!(ret.status());
+        assert_eq!(session.find_vals::<String>("--file")?, &["d"]);
+        assert_eq!(session.find_val::<i64>("--count")?, &3);
+        assert_eq!(session.find_val::<bool>("--debug")?, &true);
+        assert!(reader.read_args()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn split() {
+        let words = |line: &str| {
+            let (words, finished, _) = split_line(line);
+
+            (
+                words
+                    .into_iter()
+                    .map(|v| v.into_string().unwrap())
+                    .collect::<Vec<_>>(),
+                finished,
+            )
+        };
+
+        assert_eq!(words("  a  b "), (vec!["a".into(), "b".into()], true));
+        assert_eq!(
+            words(r#"--x="a b" 'c\d' e\ f"#),
+            (vec!["--x=a b".into(), r"c\d".into(), "e f".into()], false)
+        );
+        assert_eq!(words("a ''"), (vec!["a".into(), "".into()], false));
+        assert_eq!(words("a 'b "), (vec!["a".into(), "b ".into()], false));
+        assert_eq!(words("a \"b\n"), (vec!["a".into(), "b\n".into()], false));
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert!(unterminated_quote_impl().is_ok());
+    }
+
+    fn unterminated_quote_impl() -> Result<(), Error> {
+        let input = "--file 'a b\n--file \"c\n--file 'd'\n";
+        let mut reader = LineReader::new("app", input.as_bytes());
+
+        assert!(reader.read_args().is_err());
+        assert!(reader.read_args().is_err());
+        assert_eq!(
+            reader.read_args()?.unwrap().as_slice(),
+            ["app", "--file", "d"]
+        );
+        assert!(reader.read_args()?.is_none());
+        assert!(reader.split("--file 'e").is_err());
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use std::any::type_name;
+use std::any::TypeId;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::ops::DerefMut;
@@ -21,6 +23,30 @@ pub trait AppStorage {
 
     /// Take the user value of option `uid` from [`AppServices`].
     fn take_app_data<T: ErasedTy>(&mut self) -> Result<T, Error>;
+
+    /// Set the user value which is cloned before a [`Transaction`](crate::parser::Transaction),
+    /// and restored when the transaction rolled back.
+    fn set_cloneable_app_data<T: ErasedTy + Clone>(&mut self, val: T) -> Option<T>;
+}
+
+/// Restore the app data saved by [`AppDataSnapshot`].
+pub type AppDataRestore = Box<dyn FnOnce(&mut AppServices)>;
+
+/// Save the app data of [`AppServices`] before a [`Transaction`](crate::parser::Transaction),
+/// see [`set_cloneable_app_data`](AppStorage::set_cloneable_app_data).
+pub type AppDataSnapshot = fn(&AppServices) -> AppDataRestore;
+
+fn app_data_snapshot<T: ErasedTy + Clone>(ser: &AppServices) -> AppDataRestore {
+    let val = ser.get::<T>().cloned();
+
+    Box::new(move |ser: &mut AppServices| match val {
+        Some(val) => {
+            ser.insert(val);
+        }
+        None => {
+            ser.remove::<T>();
+        }
+    })
 }
 
 /// A service can keep any type data, user can get the data inside [`hanlder`](crate::ctx::InvokeHandler) of option.
@@ -51,7 +77,11 @@ pub trait AppStorage {
 /// # }
 /// ```
 #[derive(Debug, Default)]
-pub struct AppServices(UsrValService);
+pub struct AppServices {
+    data: UsrValService,
+
+    snapshots: HashMap<TypeId, AppDataSnapshot>,
+}
 
 impl AppServices {
     pub fn new() -> Self {
@@ -59,23 +89,37 @@ impl AppServices {
             ..Default::default()
         }
     }
+
+    /// Save the app data set by [`set_cloneable_app_data`](AppStorage::set_cloneable_app_data).
+    pub fn snapshot(&self) -> Vec<AppDataRestore> {
+        self.snapshots
+            .values()
+            .map(|snapshot| snapshot(self))
+            .collect()
+    }
 }
 
 impl AppStorage for AppServices {
     fn set_app_data<T: ErasedTy>(&mut self, val: T) -> Option<T> {
-        self.0.insert(val)
+        self.data.insert(val)
     }
 
     fn app_data<T: ErasedTy>(&self) -> Result<&T, Error> {
-        self.0.val::<T>()
+        self.data.val::<T>()
     }
 
     fn app_data_mut<T: ErasedTy>(&mut self) -> Result<&mut T, Error> {
-        self.0.val_mut::<T>()
+        self.data.val_mut::<T>()
+    }
+
+    fn set_cloneable_app_data<T: ErasedTy + Clone>(&mut self, val: T) -> Option<T> {
+        self.snapshots
+            .insert(TypeId::of::<T>(), app_data_snapshot::<T>);
+        self.data.insert(val)
     }
 
     fn take_app_data<T: ErasedTy>(&mut self) -> Result<T, Error> {
-        self.0.remove::<T>().ok_or_else(|| {
+        self.data.remove::<T>().ok_or_else(|| {
             error!(
                 "can not take value type `{}` from AppServices",
                 type_name::<T>()
@@ -88,13 +132,13 @@ impl Deref for AppServices {
     type Target = UsrValService;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.data
     }
 }
 
 impl DerefMut for AppServices {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.data
     }
 }
 
@@ -28,6 +28,9 @@ pub enum UserStyle {
     CombinedOption,
 
     /// Option set style like `--bool`, only support boolean option.
+    ///
+    /// The value set explicitly such as `--bool=no` is passed to the option instead of `true`,
+    /// it is parsed with the [`BoolLiteral`](crate::value::BoolLiteral) of the set.
     Boolean,
 
     /// Option set style like `--flag`, but the value will be set to None.
@@ -0,0 +1,547 @@
+use std::ffi::OsString;
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::args::Args;
+use crate::ctx::Invoker;
+use crate::opt::Opt;
+use crate::parser::AppDataRestore;
+use crate::parser::AppServices;
+use crate::parser::AppStorage;
+use crate::parser::HCOptSet;
+use crate::parser::Parser;
+use crate::parser::Policy;
+use crate::set::Set;
+use crate::value::AnyValue;
+use crate::value::ValueSource;
+use crate::Error;
+use crate::Uid;
+
+/// The side effect work deferred until the [`Transaction`] committed.
+#[cfg(not(feature = "sync"))]
+pub type DeferredWork = Box<dyn FnOnce() -> Result<(), Error>>;
+
+/// The side effect work deferred until the [`Transaction`] committed.
+#[cfg(feature = "sync")]
+pub type DeferredWork = Box<dyn FnOnce() -> Result<(), Error> + Send + Sync>;
+
+/// The works deferred by handlers during a [`Transaction`], it is kept in [`AppServices`].
+///
+/// The works are called in order when the transaction committed, and dropped when rolled back.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::parser::Deferred;
+/// # use aopt::Error;
+/// # use std::sync::atomic::{AtomicBool, Ordering};
+/// # use std::sync::Arc;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let created = Arc::new(AtomicBool::new(false));
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--name=s!")?;
+///
+/// let flag = created.clone();
+///
+/// parser.add_opt("--create=b")?.on(move |set, ctx| {
+///     let flag = flag.clone();
+///
+///     // create the file only if the parsing succeed
+///     Deferred::defer(set, move || {
+///         flag.store(true, Ordering::SeqCst);
+///         Ok(())
+///     })?;
+///     Ok(Some(ctx.value::<bool>()?))
+/// })?;
+///
+/// assert!(!parser.parse_tx(Args::from(["app", "--create"]))?.status());
+/// assert!(!created.load(Ordering::SeqCst));
+///
+/// assert!(parser.parse_tx(Args::from(["app", "--create", "--name=a"]))?.status());
+/// assert!(created.load(Ordering::SeqCst));
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Deferred(Vec<DeferredWork>);
+
+impl Debug for Deferred {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Deferred").field(&self.0.len()).finish()
+    }
+}
+
+impl Deferred {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[cfg(not(feature = "sync"))]
+    pub fn push(&mut self, work: impl FnOnce() -> Result<(), Error> + 'static) -> &mut Self {
+        self.0.push(Box::new(work));
+        self
+    }
+
+    #[cfg(feature = "sync")]
+    pub fn push(
+        &mut self,
+        work: impl FnOnce() -> Result<(), Error> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.0.push(Box::new(work));
+        self
+    }
+
+    /// Call the works in order, stop at the first error.
+    pub fn run(self) -> Result<(), Error> {
+        self.0.into_iter().try_for_each(|work| work())
+    }
+
+    /// Defer the `work` if there is a [`Transaction`] in progress, otherwise call it immediately.
+    #[cfg(not(feature = "sync"))]
+    pub fn defer<T: AppStorage>(
+        set: &mut T,
+        work: impl FnOnce() -> Result<(), Error> + 'static,
+    ) -> Result<(), Error> {
+        match set.app_data_mut::<Deferred>() {
+            Ok(deferred) => {
+                deferred.push(work);
+                Ok(())
+            }
+            Err(_) => work(),
+        }
+    }
+
+    /// Defer the `work` if there is a [`Transaction`] in progress, otherwise call it immediately.
+    #[cfg(feature = "sync")]
+    pub fn defer<T: AppStorage>(
+        set: &mut T,
+        work: impl FnOnce() -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Result<(), Error> {
+        match set.app_data_mut::<Deferred>() {
+            Ok(deferred) => {
+                deferred.push(work);
+                Ok(())
+            }
+            Err(_) => work(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct OptSnapshot {
+    uid: Uid,
+
+    matched: bool,
+
+    positions: Vec<usize>,
+
+    rawvals: Vec<OsString>,
+
+    values: AnyValue,
+
+    sources: Vec<ValueSource>,
+}
+
+/// The values of options and the cloneable app data saved before a [`Transaction`].
+struct Snapshot {
+    opts: Vec<OptSnapshot>,
+
+    app_data: Vec<AppDataRestore>,
+
+    deferred: Option<Deferred>,
+}
+
+impl Snapshot {
+    /// Move the values out of options, and initialize the options.
+    fn take<S: Set>(set: &mut S, ser: &mut AppServices) -> Result<Self, Error> {
+        let app_data = ser.snapshot();
+        let deferred = ser.set_app_data(Deferred::default());
+        let opts = set
+            .iter_mut()
+            .map(|opt| {
+                let uid = opt.uid();
+                let matched = opt.matched();
+                let positions = opt.positions().to_vec();
+                let sources = std::mem::take(opt.accessor_mut().sources_mut());
+                let (rawvals, values) = opt.accessor_mut().handlers();
+
+                OptSnapshot {
+                    uid,
+                    matched,
+                    positions,
+                    rawvals: std::mem::take(rawvals),
+                    values: std::mem::take(values),
+                    sources,
+                }
+            })
+            .collect();
+        let snapshot = Self {
+            opts,
+            app_data,
+            deferred,
+        };
+
+        for opt in set.iter_mut() {
+            opt.reset();
+            if let Err(e) = opt.init() {
+                snapshot.restore(set, ser);
+                return Err(e);
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Put back the values of options and app data.
+    fn restore<S: Set>(self, set: &mut S, ser: &mut AppServices) {
+        for snapshot in self.opts {
+            if let Some(opt) = set.iter_mut().find(|v| v.uid() == snapshot.uid) {
+                let (rawvals, values) = opt.accessor_mut().handlers();
+
+                *rawvals = snapshot.rawvals;
+                *values = snapshot.values;
+                *opt.accessor_mut().sources_mut() = snapshot.sources;
+                opt.reset();
+                opt.set_matched(snapshot.matched);
+                for position in snapshot.positions {
+                    opt.add_position(position);
+                }
+            }
+        }
+        for restore in self.app_data {
+            restore(ser);
+        }
+        Self::restore_deferred(ser, self.deferred);
+    }
+
+    fn restore_deferred(ser: &mut AppServices, deferred: Option<Deferred>) -> Option<Deferred> {
+        let current = ser.remove::<Deferred>();
+
+        if let Some(deferred) = deferred {
+            ser.set_app_data(deferred);
+        }
+        current
+    }
+}
+
+/// A transactional parse of [`Parser`], the parse can be committed or rolled back.
+///
+/// When the transaction begin, the values of options are saved and the options are initialized,
+/// so the parsing starts from the initialized values.
+/// The app data set by [`set_cloneable_app_data`](AppStorage::set_cloneable_app_data) are cloned also,
+/// other app data are not saved.
+///
+/// * [`commit`](Transaction::commit) keeps the result of parsing, updates the bound variables
+///   and calls the [`Deferred`] works.
+///
+/// * [`rollback`](Transaction::rollback) restores the values of options and the cloneable app data,
+///   the [`Deferred`] works and the updates of bound variables are dropped.
+///
+/// The transaction is rolled back if it dropped without committing.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--name=s")?;
+/// parser.add_opt("--depth=i")?;
+/// parser.set_cloneable_app_data(0i64);
+///
+/// assert!(parser.parse(Args::from(["app", "--name=a", "--depth=1"]))?.status());
+///
+/// let mut tx = parser.transaction()?;
+///
+/// *tx.app_data_mut::<i64>()? = 42;
+/// assert!(!tx.parse(Args::from(["app", "--name=b", "--depth=x"]))?.status());
+/// tx.rollback();
+///
+/// assert_eq!(parser.find_vals::<String>("--name")?, &["a"]);
+/// assert_eq!(parser.find_val::<i64>("--depth")?, &1);
+/// assert_eq!(parser.app_data::<i64>()?, &0);
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+pub struct Transaction<'p, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    parser: &'p mut Parser<HCOptSet<'a, S>, P>,
+
+    snapshot: Option<Snapshot>,
+}
+
+impl<'a, S, P> Debug for Transaction<'_, 'a, S, P>
+where
+    S: Set + Debug,
+    P: Policy<Set = HCOptSet<'a, S>> + Debug,
+    P::Ret: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("parser", &self.parser)
+            .field("snapshot", &self.snapshot.as_ref().map(|v| &v.opts))
+            .finish()
+    }
+}
+
+impl<'p, 'a, S, P> Transaction<'p, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>, Inv<'a> = Invoker<'a, HCOptSet<'a, S>>>,
+{
+    pub fn new(parser: &'p mut Parser<HCOptSet<'a, S>, P>) -> Result<Self, Error> {
+        let optset = &mut parser.optset;
+        let (set, ser) = optset.set_and_service_mut();
+        let snapshot = Snapshot::take(set, ser)?;
+
+        if let Ok(inv) = optset.invoker_mut() {
+            inv.defer_binds();
+        }
+        Ok(Self {
+            parser,
+            snapshot: Some(snapshot),
+        })
+    }
+
+    pub fn parser(&self) -> &Parser<HCOptSet<'a, S>, P> {
+        self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<HCOptSet<'a, S>, P> {
+        self.parser
+    }
+
+    /// Parse the arguments inside the transaction, the option values are not initialized again.
+    pub fn parse(&mut self, args: Args) -> Result<P::Ret, Error> {
+        let parser = &mut *self.parser;
+        let ret = parser
+            .hooks
+            .pre_parse(&mut parser.optset, args)
+            .and_then(|args| parser.optset.parse_policy_uninit(args, &mut parser.policy));
+
+        parser.hooks.post_parse(&mut parser.optset, ret)
+    }
+
+    /// Keep the result of parsing, update the variables bound by [`bind`](Invoker::bind),
+    /// then call the [`Deferred`] works.
+    pub fn commit(mut self) -> Result<(), Error> {
+        let snapshot = self.snapshot.take().unwrap();
+        let deferred =
+            Snapshot::restore_deferred(self.parser.optset.service_mut(), snapshot.deferred);
+
+        self.parser.optset.flush_binds()?;
+        deferred.map(Deferred::run).unwrap_or(Ok(()))
+    }
+
+    /// Restore the values of options and the cloneable app data saved before the transaction.
+    pub fn rollback(mut self) {
+        self.rollback_impl();
+    }
+}
+
+impl<'a, S, P> Transaction<'_, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    fn rollback_impl(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            let (set, ser) = self.parser.optset.set_and_service_mut();
+
+            snapshot.restore(set, ser);
+            if let Ok(inv) = self.parser.optset.invoker_mut() {
+                inv.discard_binds();
+            }
+        }
+    }
+}
+
+impl<'a, S, P> Drop for Transaction<'_, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    fn drop(&mut self) {
+        self.rollback_impl();
+    }
+}
+
+impl<'a, S, P> Deref for Transaction<'_, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    type Target = Parser<HCOptSet<'a, S>, P>;
+
+    fn deref(&self) -> &Self::Target {
+        self.parser
+    }
+}
+
+impl<'a, S, P> DerefMut for Transaction<'_, 'a, S, P>
+where
+    S: Set,
+    P: Policy<Set = HCOptSet<'a, S>>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.parser
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use crate::parser::Deferred;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn transaction() {
+        assert!(transaction_impl().is_ok());
+    }
+
+    fn transaction_impl() -> Result<(), Error> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--file=s")?;
+        parser.add_opt("--depth=i")?.set_value_t(0i64);
+
+        let counter = count.clone();
+
+        parser
+            .add_opt("-v=b")?
+            .set_action(Action::Cnt)
+            .on(move |set, ctx| {
+                let counter = counter.clone();
+
+                Deferred::defer(set, move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                })?;
+                Ok(Some(ctx.value::<bool>()?))
+            })?;
+        parser.set_cloneable_app_data(vec![String::from("init")]);
+        parser.set_cloneable_app_data(0i64);
+        parser.set_app_data(0u64);
+
+        // the works called immediately without transaction
+        let ret = parser.parse(Args::from(["app", "--file=a", "-v", "--depth=1"]))?;
+
+        assert!(ret.status());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        // failed parse rolled back
+        let ret = parser.parse_tx(Args::from(["app", "--file=b", "-v", "--depth=x"]))?;
+
+        assert!(!ret.status());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(parser.find_vals::<String>("--file")?, &["a"]);
+        assert_eq!(parser.find_val::<i64>("--depth")?, &1);
+        assert_eq!(parser.find_val::<u64>("-v")?, &1);
+        assert!(parser.find_opt("--file")?.matched());
+        assert!(!parser.service().contain_type::<Deferred>());
+
+        // dropped transaction rolled back
+        {
+            let mut tx = parser.transaction()?;
+
+            tx.app_data_mut::<Vec<String>>()?.push("changed".into());
+            *tx.app_data_mut::<i64>()? = 42;
+            *tx.app_data_mut::<u64>()? = 42;
+            assert!(tx.parse(Args::from(["app", "--file=c", "-v"]))?.status());
+            assert_eq!(tx.find_vals::<String>("--file")?, &["c"]);
+            assert_eq!(tx.find_val::<i64>("--depth")?, &0);
+        }
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(parser.find_vals::<String>("--file")?, &["a"]);
+        assert_eq!(parser.app_data::<Vec<String>>()?, &["init"]);
+        assert_eq!(parser.app_data::<i64>()?, &0);
+        // the app data can not be cloned is not restored
+        assert_eq!(parser.app_data::<u64>()?, &42);
+
+        // committed transaction keep the values and call the works
+        let mut tx = parser.transaction()?;
+
+        tx.app_data_mut::<Vec<String>>()?.push("changed".into());
+        assert!(tx
+            .parse(Args::from(["app", "--file=d", "-v", "-v"]))?
+            .status());
+        tx.commit()?;
+
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+        assert_eq!(parser.find_vals::<String>("--file")?, &["d"]);
+        assert_eq!(parser.find_val::<u64>("-v")?, &2);
+        assert_eq!(parser.app_data::<Vec<String>>()?, &["init", "changed"]);
+        assert!(!parser.service().contain_type::<Deferred>());
+        Ok(())
+    }
+
+    #[test]
+    fn transaction_bind() -> Result<(), Error> {
+        let depth = Mutex::new(0i64);
+        let files = Mutex::new(Vec::<String>::new());
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--name=s")?.set_force(true);
+        parser.add_opt("--depth=i")?.bind(&depth)?;
+        parser.add_opt("--file=s")?.bind(&files)?;
+
+        assert!(parser
+            .parse(Args::from(["app", "--name=a", "--depth=1", "--file=a"]))?
+            .status());
+        assert_eq!(*depth.lock().unwrap(), 1);
+
+        // the bound variables not updated if rolled back
+        let ret = parser.parse_tx(Args::from(["app", "--depth=2", "--file=b"]))?;
+
+        assert!(!ret.status());
+        assert_eq!(*depth.lock().unwrap(), 1);
+        assert_eq!(*files.lock().unwrap(), ["a"]);
+        {
+            let mut tx = parser.transaction()?;
+
+            assert!(tx
+                .parse(Args::from(["app", "--name=b", "--depth=3"]))?
+                .status());
+        }
+        assert_eq!(*depth.lock().unwrap(), 1);
+
+        // the bound variables updated when committed
+        let ret = parser.parse_tx(Args::from(["app", "--name=c", "--depth=4", "--file=c"]))?;
+
+        assert!(ret.status());
+        assert_eq!(*depth.lock().unwrap(), 4);
+        assert_eq!(*files.lock().unwrap(), ["c"]);
+
+        // no transaction in progress, update immediately
+        assert!(parser
+            .parse(Args::from(["app", "--name=d", "--depth=5"]))?
+            .status());
+        assert_eq!(*depth.lock().unwrap(), 5);
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) mod commit;
 pub(crate) mod filter;
 pub(crate) mod index;
+pub(crate) mod layer;
 pub(crate) mod optset;
 pub(crate) mod optvalid;
 
@@ -10,6 +11,7 @@ pub use self::filter::Filter;
 pub use self::filter::FilterMatcher;
 pub use self::filter::FilterMut;
 pub use self::index::SetIndex;
+pub use self::layer::ConfigLayer;
 pub use self::optset::OptSet;
 pub use self::optvalid::OptValidator;
 pub use self::optvalid::PrefixOptValidator;
@@ -26,11 +28,17 @@ use crate::opt::Action;
 use crate::opt::Cid;
 use crate::opt::ConfigBuild;
 use crate::opt::ConfigValue;
+use crate::opt::Constraint;
 use crate::opt::Index;
+use crate::opt::Occurrence;
 use crate::opt::Opt;
+use crate::opt::OptGroup;
 use crate::opt::OptValueExt;
+use crate::value::BoolLiteral;
+use crate::value::ElemSeparator;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
+use crate::value::ValueSource;
 use crate::Error;
 use crate::Uid;
 
@@ -103,6 +111,15 @@ pub fn ctor_default_name() -> String {
     String::from(CTOR_DEFAULT)
 }
 
+/// Return the names and aliases of options in `set` which similar to `name`.
+pub fn similar_names<S: Set>(set: &S, name: &str) -> Vec<String> {
+    let names = set.iter().flat_map(|opt| {
+        std::iter::once(opt.name()).chain(opt.alias().into_iter().flatten().map(String::as_str))
+    });
+
+    crate::str::similar_words(name, names)
+}
+
 /// Create [`Opt`](crate::set::Ctor::Opt) with given [`Config`](crate::set::Ctor::Config).
 pub trait Ctor {
     type Opt: Opt;
@@ -160,6 +177,21 @@ pub trait Set {
     fn get_mut(&mut self, uid: Uid) -> Option<&mut SetOpt<Self>> {
         self.iter_mut().find(|v| v.uid() == uid)
     }
+
+    /// Return the [`OptGroup`]s registered in the set.
+    fn groups(&self) -> &[OptGroup] {
+        &[]
+    }
+
+    /// Return the [`ConfigLayer`]s registered in the set, the later one has higher priority.
+    fn layers(&self) -> &[ConfigLayer] {
+        &[]
+    }
+
+    /// Return the [`BoolLiteral`] used for parsing [`bool`], None means the default one.
+    fn bool_literal(&self) -> Option<&BoolLiteral> {
+        None
+    }
 }
 
 pub trait SetExt<C: Ctor> {
@@ -234,6 +266,19 @@ where
         self.opt_mut(self.find_uid(cb)?)?.vals_mut()
     }
 
+    /// Return the source of last value of option, return `None` if the option has no value.
+    fn find_source(
+        &self,
+        cb: impl ConfigBuild<SetCfg<Self>>,
+    ) -> Result<Option<&ValueSource>, Error> {
+        Ok(self.opt(self.find_uid(cb)?)?.accessor().source())
+    }
+
+    /// Return the sources of all values of option.
+    fn find_sources(&self, cb: impl ConfigBuild<SetCfg<Self>>) -> Result<&[ValueSource], Error> {
+        Ok(self.opt(self.find_uid(cb)?)?.accessor().sources())
+    }
+
     fn take_val<T: ErasedTy>(&mut self, cb: impl ConfigBuild<SetCfg<Self>>) -> Result<T, Error> {
         let opt = self.opt_mut(self.find_uid(cb)?)?;
         let (name, uid) = (opt.name(), opt.uid());
@@ -331,6 +376,26 @@ where
         self.cfg_mut().set_initializer(initializer.into());
         self
     }
+
+    fn add_constraint(mut self, constraint: Constraint) -> Self {
+        self.cfg_mut().add_constraint(constraint);
+        self
+    }
+
+    fn set_occurrence(mut self, occurrence: Occurrence) -> Self {
+        self.cfg_mut().set_occurrence(occurrence);
+        self
+    }
+
+    fn set_env(mut self, env: impl Into<String>) -> Self {
+        self.cfg_mut().set_env(env);
+        self
+    }
+
+    fn set_separator(mut self, separator: impl Into<ElemSeparator>) -> Self {
+        self.cfg_mut().set_separator(separator.into());
+        self
+    }
 }
 
 pub trait SetChecker<S> {
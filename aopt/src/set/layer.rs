@@ -0,0 +1,293 @@
+use std::path::Path;
+
+use crate::Error;
+
+/// A layer of option values loaded from a configuration source, such as a TOML or JSON file.
+///
+/// The keys are matched against the name or alias of options without the prefix,
+/// `_` and `-` are treated as same, such as `dry_run` matches `--dry-run`.
+/// The table value is used as layer of sub command which has same name.
+/// Each value of array is passed to the option like it appears multiple times.
+///
+/// The layers registered in the set are used by policies after command line and
+/// environment variable processed, the layer added later has higher priority.
+/// So the precedence is `command line > env > project file > user file > defaults`
+/// if you add user file first.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// let mut parser = AFwdParser::default();
+///
+/// parser.add_opt("--jobs=i")?;
+/// parser.add_opt("--dry-run=b")?;
+/// parser.add_opt("--name=s")?;
+/// parser.add_layer(
+///     ConfigLayer::new("user.toml")
+///         .with_value("jobs", ["4"])
+///         .with_value("name", ["user"]),
+/// );
+/// parser.add_layer(
+///     ConfigLayer::new("project.toml")
+///         .with_value("jobs", ["8"])
+///         .with_value("dry_run", ["true"]),
+/// );
+/// parser.parse(Args::from(["app", "--name", "cli"]))?.ok()?;
+///
+/// assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+/// assert_eq!(parser.find_val::<bool>("--dry-run")?, &true);
+/// assert_eq!(parser.find_val::<String>("--name")?, "cli");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigLayer {
+    origin: String,
+
+    prefix: String,
+
+    values: Vec<(String, Vec<String>)>,
+
+    subs: Vec<(String, ConfigLayer)>,
+}
+
+impl ConfigLayer {
+    pub fn new(origin: impl Into<String>) -> Self {
+        Self {
+            origin: origin.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_value<S: Into<String>>(
+        mut self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.add_value(key, values);
+        self
+    }
+
+    pub fn with_sub(mut self, name: impl Into<String>, layer: ConfigLayer) -> Self {
+        self.add_sub(name, layer);
+        self
+    }
+
+    /// The origin of layer, such as the path of configuration file.
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    pub fn values(&self) -> &[(String, Vec<String>)] {
+        &self.values
+    }
+
+    pub fn subs(&self) -> &[(String, ConfigLayer)] {
+        &self.subs
+    }
+
+    pub fn add_value<S: Into<String>>(
+        &mut self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = S>,
+    ) -> &mut Self {
+        self.values
+            .push((key.into(), values.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    pub fn add_sub(&mut self, name: impl Into<String>, mut layer: ConfigLayer) -> &mut Self {
+        let name = name.into();
+
+        layer.set_prefix(format!("{}{}.", self.prefix, name));
+        self.subs.push((name, layer));
+        self
+    }
+
+    fn set_prefix(&mut self, prefix: String) {
+        for (name, sub) in self.subs.iter_mut() {
+            sub.set_prefix(format!("{}{}.", prefix, name));
+        }
+        self.prefix = prefix;
+    }
+
+    /// Return the full path of key in the configuration, such as `list.all`.
+    pub fn key_path(&self, key: &str) -> String {
+        format!("{}{}", self.prefix, key)
+    }
+
+    /// Find the values of option, the `names` are name and aliases of option.
+    pub fn find(&self, names: &[&str]) -> Option<(&str, &[String])> {
+        let normalize = |name: &str| name.trim_start_matches('-').replace('_', "-");
+
+        self.values.iter().find_map(|(key, values)| {
+            let key_n = normalize(key);
+
+            names
+                .iter()
+                .any(|name| *name == key || normalize(name) == key_n)
+                .then_some((key.as_str(), values.as_slice()))
+        })
+    }
+
+    /// Find the layer of sub command `name`.
+    pub fn sub(&self, name: &str) -> Option<&ConfigLayer> {
+        self.subs
+            .iter()
+            .find_map(|(sub, layer)| (sub == name).then_some(layer))
+    }
+
+    /// Load the layer from file, the format is decided by extension of file:
+    /// `toml` need feature `toml`, `json` need feature `json`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+        #[cfg_attr(not(any(feature = "toml", feature = "json")), allow(unused_variables))]
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::sp_config_load(&origin, e.to_string()))?;
+
+        match path.extension().and_then(|v| v.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(origin, &content),
+            #[cfg(feature = "json")]
+            Some("json") => Self::from_json(origin, &content),
+            ext => Err(Error::sp_config_load(
+                origin,
+                format!("unsupported format `{}`", ext.unwrap_or_default()),
+            )),
+        }
+    }
+
+    /// Same as [`load`](ConfigLayer::load), but return `None` if the file not exists.
+    pub fn load_if_exists(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
+        let path = path.as_ref();
+
+        if path.is_file() {
+            Self::load(path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(origin: impl Into<String>, content: &str) -> Result<Self, Error> {
+        let origin = origin.into();
+        let table: toml::Table =
+            toml::from_str(content).map_err(|e| Error::sp_config_load(&origin, e.to_string()))?;
+
+        Self::from_toml_table(Self::new(origin), table)
+    }
+
+    #[cfg(feature = "toml")]
+    fn from_toml_table(mut layer: Self, table: toml::Table) -> Result<Self, Error> {
+        use toml::Value;
+
+        let scalar = |layer: &Self, key: &str, value: Value| match value {
+            Value::String(v) => Ok(v),
+            Value::Integer(v) => Ok(v.to_string()),
+            Value::Float(v) => Ok(v.to_string()),
+            Value::Boolean(v) => Ok(v.to_string()),
+            Value::Datetime(v) => Ok(v.to_string()),
+            _ => Err(layer.unsupported(key)),
+        };
+
+        for (key, value) in table {
+            match value {
+                Value::Table(table) => {
+                    let sub = Self::from_toml_table(layer.sub_layer(&key), table)?;
+
+                    layer.add_sub(key, sub);
+                }
+                Value::Array(values) => {
+                    let values = values
+                        .into_iter()
+                        .map(|value| scalar(&layer, &key, value))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    layer.add_value(key, values);
+                }
+                value => {
+                    let value = scalar(&layer, &key, value)?;
+
+                    layer.add_value(key, [value]);
+                }
+            }
+        }
+        Ok(layer)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(origin: impl Into<String>, content: &str) -> Result<Self, Error> {
+        use serde_json::Value;
+
+        let origin = origin.into();
+        let value: Value = serde_json::from_str(content)
+            .map_err(|e| Error::sp_config_load(&origin, e.to_string()))?;
+
+        match value {
+            Value::Object(map) => Self::from_json_map(Self::new(origin), map),
+            _ => Err(Error::sp_config_load(origin, "excepted an object")),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    fn from_json_map(
+        mut layer: Self,
+        map: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<Self, Error> {
+        use serde_json::Value;
+
+        let scalar = |layer: &Self, key: &str, value: Value| match value {
+            Value::String(v) => Ok(v),
+            Value::Number(v) => Ok(v.to_string()),
+            Value::Bool(v) => Ok(v.to_string()),
+            _ => Err(layer.unsupported(key)),
+        };
+
+        for (key, value) in map {
+            match value {
+                Value::Object(map) => {
+                    let sub = Self::from_json_map(layer.sub_layer(&key), map)?;
+
+                    layer.add_sub(key, sub);
+                }
+                Value::Array(values) => {
+                    let values = values
+                        .into_iter()
+                        .map(|value| scalar(&layer, &key, value))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    layer.add_value(key, values);
+                }
+                Value::Null => {}
+                value => {
+                    let value = scalar(&layer, &key, value)?;
+
+                    layer.add_value(key, [value]);
+                }
+            }
+        }
+        Ok(layer)
+    }
+
+    #[allow(unused)]
+    fn sub_layer(&self, name: &str) -> Self {
+        Self {
+            origin: self.origin.clone(),
+            prefix: format!("{}{}.", self.prefix, name),
+            ..Default::default()
+        }
+    }
+
+    #[allow(unused)]
+    fn unsupported(&self, key: &str) -> Error {
+        Error::sp_config_load(
+            &self.origin,
+            format!("unsupported value of key `{}`", self.key_path(key)),
+        )
+    }
+}
@@ -400,6 +400,22 @@ where
         let uid = self.len() as Uid;
 
         opt.set_uid(uid);
+        // resolve the constraint targets between new option and the options inserted
+        for constraint in opt.constraints_mut() {
+            constraint.resolve(|target| {
+                self.opts
+                    .iter()
+                    .find(|v| v.mat_name(Some(target)) || v.mat_alias(target))
+                    .map(|v| v.uid())
+            });
+        }
+        for other in self.opts.iter_mut() {
+            for constraint in other.constraints_mut() {
+                constraint.resolve(|target| {
+                    (opt.mat_name(Some(target)) || opt.mat_alias(target)).then_some(uid)
+                });
+            }
+        }
         self.opts.push(opt);
         uid
     }
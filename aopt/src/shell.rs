@@ -1,21 +1,28 @@
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::marker::PhantomData;
 
 use crate::acore::args::Args;
 use crate::acore::opt::Opt;
 use crate::acore::Error;
 use crate::acore::HashMap;
 use crate::acore::Uid;
-use crate::ashell::shell::complete_eq;
+use crate::ashell::shell::complete_eq_with;
 use crate::ashell::shell::complete_opt;
 use crate::ashell::shell::complete_val;
+use crate::ashell::shell::default_values;
+use crate::ashell::shell::is_conflict;
+use crate::ashell::shell::used_opts;
 use crate::ashell::shell::Complete;
 use crate::ashell::shell::Shell;
 use crate::ashell::value::Values;
 use crate::opt::ConfigBuildInfer;
 use crate::opt::ConfigValue;
 use crate::opt::Style;
+use crate::parser::session::split_words;
 use crate::parser::HCOptSet;
+use crate::parser::LineReader;
 use crate::parser::PolicySettings;
 use crate::prelude::AFwdParser;
 use crate::set::OptValidator;
@@ -24,6 +31,7 @@ use crate::set::SetCfg;
 use crate::set::SetOpt;
 use crate::set::SetValueFindExt;
 use crate::trace;
+use crate::value::BoolLiteral;
 
 pub use crate::ashell::script;
 pub use crate::ashell::shell;
@@ -335,9 +343,16 @@ where
                 let optset = manager.optset();
                 let values = manager.values();
 
-                complete_eq(arg, bytes, optset.iter(), values, |name, val, opt| {
-                    s.write_eq(name, val, opt)
-                })?;
+                let literal = optset.bool_literal().unwrap_or(BoolLiteral::default_ref());
+
+                complete_eq_with(
+                    arg,
+                    bytes,
+                    optset.iter(),
+                    values,
+                    literal,
+                    |name, val, opt| s.write_eq(name, val, opt),
+                )?;
             }
         }
 
@@ -380,8 +395,14 @@ where
                 .map(|v| v.optset())
                 .filter(|v| v.split(&Cow::Borrowed(arg)).is_ok())
             {
+                // skip the options conflict with the options already set
+                let used = used_opts(&args[..args.len().min(*cword)], p.iter());
+                let opts = p
+                    .iter()
+                    .filter(|opt| !used.iter().any(|used| is_conflict(*opt, *used)));
+
                 found_opt =
-                    found_opt || complete_opt(arg, p.iter(), |name, opt| s.write_opt(name, opt))?;
+                    found_opt || complete_opt(arg, opts, |name, opt| s.write_opt(name, opt))?;
             }
         }
 
@@ -452,8 +473,14 @@ where
                     trace!("noa index = {noa_index}");
                     for pos in optset.iter().filter(|v| v.mat_style(Style::Pos)) {
                         if pos.mat_index(Some((noa_index, noa_index + 1))) {
-                            if let Some(getter) = values.get(&pos.uid()) {
-                                for val in getter.get_values(pos)? {
+                            let vals = if let Some(getter) = values.get(&pos.uid()) {
+                                Some(getter.get_values(pos)?)
+                            } else {
+                                default_values(pos, bytes, None)?
+                            };
+
+                            if let Some(vals) = vals {
+                                for val in vals {
                                     if !val.is_empty() && bytes.is_empty()
                                         || bytes
                                             .iter()
@@ -479,6 +506,136 @@ where
     }
 }
 
+/// A [`Shell`] collect the completion candidates in memory, using for completing in process.
+///
+/// The candidate of `--opt=val` is the whole word.
+pub struct Candidates<O> {
+    buff: Option<Vec<String>>,
+
+    __marker: PhantomData<O>,
+}
+
+impl<O> Default for Candidates<O> {
+    fn default() -> Self {
+        Self {
+            buff: Some(vec![]),
+            __marker: PhantomData,
+        }
+    }
+}
+
+impl<O> Candidates<O> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, word: String) -> Result<(), Error> {
+        self.buff
+            .as_mut()
+            .ok_or_else(|| crate::error!("must set buffer before write to"))?
+            .push(word);
+        Ok(())
+    }
+}
+
+impl<O> Shell<O, Vec<String>> for Candidates<O> {
+    type Err = Error;
+
+    fn is_avail(&self, _: &str) -> bool {
+        false
+    }
+
+    fn set_buff(&mut self, w: Vec<String>) {
+        self.buff = Some(w);
+    }
+
+    fn write_cmd(&mut self, name: &str, _: &O) -> Result<(), Self::Err> {
+        self.push(name.to_string())
+    }
+
+    fn write_opt(&mut self, name: &str, _: &O) -> Result<(), Self::Err> {
+        self.push(name.to_string())
+    }
+
+    fn write_pos(&mut self, name: &str, _: &O) -> Result<(), Self::Err> {
+        self.push(name.to_string())
+    }
+
+    fn write_val(&mut self, val: &OsStr, _: &O) -> Result<(), Self::Err> {
+        self.push(val.to_string_lossy().to_string())
+    }
+
+    fn write_eq(&mut self, name: &str, val: &OsStr, _: &O) -> Result<(), Self::Err> {
+        self.push(format!("{name}={}", val.to_string_lossy()))
+    }
+
+    fn finish(&mut self) -> Result<(), Self::Err> {
+        Ok(())
+    }
+
+    fn take_buff(&mut self) -> Option<Vec<String>> {
+        self.buff.take()
+    }
+}
+
+impl<R> LineReader<R> {
+    /// Return the completion candidates of the last word in `line`,
+    /// it can be used in the completer of line editor.
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::parser::LineReader;
+    /// # use aopt::shell::CompletionManager;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt("--count=i")?;
+    /// parser.add_opt("--color=s")?;
+    /// parser.add_opt("--debug=b")?;
+    ///
+    /// let manager = CompletionManager::new(parser.optset);
+    /// let reader = LineReader::new("repl", std::io::empty());
+    /// let mut words = reader.complete(&manager, "--debug --co")?;
+    ///
+    /// words.sort();
+    /// assert_eq!(words, ["--color", "--count"]);
+    /// assert_eq!(reader.complete(&manager, "--debug=t")?, ["--debug=true"]);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn complete<S>(
+        &self,
+        manager: &CompletionManager<'_, S>,
+        line: &str,
+    ) -> Result<Vec<String>, Error>
+    where
+        SetOpt<S>: Opt,
+        SetCfg<S>: ConfigValue + Default,
+        S: Set + OptValidator + SetValueFindExt,
+    {
+        let (words, finished) = split_words(line);
+        let mut args: Vec<_> = std::iter::once(OsString::from(self.name()))
+            .chain(words)
+            .collect();
+
+        if finished {
+            args.push(OsString::default());
+        }
+
+        let cword = args.len() - 1;
+        let curr = args[cword].clone();
+        let prev = args[cword - 1].clone();
+        let mut ctx = Context::new(&args, &curr, &prev, cword);
+        let mut candidates = Candidates::new();
+
+        manager.complete(&mut candidates, &mut ctx)?;
+        Ok(candidates.take_buff().unwrap_or_default())
+    }
+}
+
 /// Return [`CompleteCli`] if command line arguments has `--_shell` option.
 pub fn get_complete_cli() -> Result<CompleteCli, Error> {
     CompleteCli::parse_env()
@@ -436,19 +436,21 @@ where
     type Error = Error;
 
     fn guess_policy(&mut self) -> Result<Option<T>, Self::Error> {
-        let arg = Some(Cow::Borrowed(OsStr::new(BOOL_TRUE)));
-
-        if self.arg.is_none() {
-            if let Some(name) = &self.name {
-                return Ok(Some(
-                    T::default()
-                        .with_idx(self.idx)
-                        .with_tot(self.total)
-                        .with_name(Some(name.clone()))
-                        .with_arg(arg)
-                        .with_style(Style::Boolean),
-                ));
-            }
+        // using the value if user set it explicitly, such as `--bool=no`
+        let arg = self
+            .arg
+            .clone()
+            .or_else(|| Some(Cow::Borrowed(OsStr::new(BOOL_TRUE))));
+
+        if let Some(name) = &self.name {
+            return Ok(Some(
+                T::default()
+                    .with_idx(self.idx)
+                    .with_tot(self.total)
+                    .with_name(Some(name.clone()))
+                    .with_arg(arg)
+                    .with_style(Style::Boolean),
+            ));
         }
         Ok(None)
     }
@@ -299,7 +299,9 @@ where
         inv: &mut Self::Inv<'_>,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_bool_literal(set.bool_literal().cloned());
 
         match self.parse_impl(set, inv, &orig, &mut ctx) {
             Ok(_) => Ok(Return::new(ctx)),
@@ -1,6 +1,18 @@
 use std::fmt::Debug;
+use std::future::Future;
 use std::marker::PhantomData;
 
+use crate::ctx::After;
+use crate::ctx::AsyncHandler;
+use crate::ctx::AsyncHandlerCollection;
+use crate::ctx::AsyncHandlerEntry;
+use crate::ctx::AsyncInvokeHandler;
+use crate::ctx::Before;
+use crate::ctx::BindTarget;
+use crate::ctx::BoxedFuture;
+use crate::ctx::InvokeMiddleware;
+use crate::ctx::Middleware;
+
 use crate::ctx::wrap_handler;
 use crate::ctx::wrap_handler_action;
 use crate::ctx::wrap_handler_fallback;
@@ -9,6 +21,7 @@ use crate::ctx::Ctx;
 use crate::ctx::Store;
 use crate::map::ErasedTy;
 use crate::opt::Opt;
+use crate::opt::OptValueExt;
 use crate::set::Set;
 use crate::set::SetExt;
 use crate::set::SetOpt;
@@ -76,12 +89,27 @@ use crate::Uid;
 /// ```
 pub struct Invoker<'a, S> {
     callbacks: HashMap<Uid, InvokeHandler<'a, S, Error>>,
+
+    async_callbacks: HashMap<Uid, AsyncInvokeHandler<'a, S>>,
+
+    middlewares: Vec<InvokeMiddleware<'a, S>>,
+
+    binds: HashMap<Uid, Vec<BindHandler<'a, S>>>,
+
+    deferred_binds: Option<Vec<Uid>>,
+
+    allow_async: bool,
 }
 
 impl<S> Debug for Invoker<'_, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Invoker")
             .field("callbacks", &"{ ... }")
+            .field("async_callbacks", &"{ ... }")
+            .field("middlewares", &self.middlewares.len())
+            .field("binds", &self.binds.len())
+            .field("deferred_binds", &self.deferred_binds)
+            .field("allow_async", &self.allow_async)
             .finish()
     }
 }
@@ -90,6 +118,11 @@ impl<S> Default for Invoker<'_, S> {
     fn default() -> Self {
         Self {
             callbacks: HashMap::default(),
+            async_callbacks: HashMap::default(),
+            middlewares: vec![],
+            binds: HashMap::default(),
+            deferred_binds: None,
+            allow_async: false,
         }
     }
 }
@@ -98,8 +131,18 @@ impl<S> Invoker<'_, S> {
     pub fn new() -> Self {
         Self {
             callbacks: HashMap::default(),
+            async_callbacks: HashMap::default(),
+            middlewares: vec![],
+            binds: HashMap::default(),
+            deferred_binds: None,
+            allow_async: false,
         }
     }
+
+    /// Drop the updates of bound targets queued since [`defer_binds`](Invoker::defer_binds).
+    pub(crate) fn discard_binds(&mut self) {
+        self.deferred_binds = None;
+    }
 }
 
 impl<'a, S: Set> Invoker<'a, S> {
@@ -146,6 +189,177 @@ impl<'a, S: Set> Invoker<'a, S> {
     pub fn has(&self, uid: Uid) -> bool {
         self.callbacks.contains_key(&uid)
     }
+
+    /// Add a [`Middleware`] wrap the invocation of every handler.
+    pub fn add_middleware<M: Middleware<S> + Send + Sync + 'a>(
+        &mut self,
+        middleware: M,
+    ) -> &mut Self {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Add a [`Middleware`] called before the handler, see [`before`](Middleware::before).
+    pub fn add_before<F>(&mut self, before: F) -> &mut Self
+    where
+        F: FnMut(Uid, &mut S, &mut Ctx) -> Result<Option<bool>, Error> + Send + Sync + 'a,
+    {
+        self.add_middleware(Before(before))
+    }
+
+    /// Add a [`Middleware`] called after the handler, see [`after`](Middleware::after).
+    pub fn add_after<F>(&mut self, after: F) -> &mut Self
+    where
+        F: FnMut(Uid, &mut S, &mut Ctx, Result<bool, Error>) -> Result<bool, Error>
+            + Send
+            + Sync
+            + 'a,
+    {
+        self.add_middleware(After(after))
+    }
+
+    pub fn clear_middleware(&mut self) -> &mut Self {
+        self.middlewares.clear();
+        self
+    }
+
+    pub fn set_async_raw<H: AsyncHandler<S> + Send + Sync + 'a>(
+        &mut self,
+        uid: Uid,
+        handler: H,
+    ) -> &mut Self {
+        self.async_callbacks.insert(uid, Box::new(handler));
+        self
+    }
+
+    /// Allow invoking the async handlers, it is enabled by
+    /// [`parse_policy_async`](crate::parser::PolicyParser::parse_policy_async) when parsing.
+    /// If not enabled, the [`Policy`](crate::parser::Policy) return an error when an option has async handler.
+    pub fn set_allow_async(&mut self, allow_async: bool) -> &mut Self {
+        self.allow_async = allow_async;
+        self
+    }
+
+    pub fn allow_async(&self) -> bool {
+        self.allow_async
+    }
+
+    fn invoke_wrapped(
+        &mut self,
+        uid: &Uid,
+        set: &mut S,
+        ctx: &mut Ctx,
+        fallback: bool,
+    ) -> Result<bool, Error> {
+        let (called, ret) = self.invoke_before(uid, set, ctx);
+        let ret = match ret {
+            Some(ret) => ret,
+            None => match self.callbacks.get_mut(uid) {
+                Some(callback) => {
+                    trace!("invoking callback of {} {:?}", uid, ctx);
+                    (callback)(set, ctx)
+                }
+                None if fallback => {
+                    trace!("invoking fallback callback of {} {:?}", uid, ctx);
+                    Invoker::fallback(set, ctx)
+                }
+                None => unreachable!(
+                    "no callback of {}, call `invoke_fb` or `fallback` instead",
+                    set.opt(*uid)?.name()
+                ),
+            },
+        };
+
+        self.invoke_after(called, uid, set, ctx, ret)
+    }
+
+    /// Call the [`before`](Middleware::before) of middlewares, return the number of middlewares called
+    /// and the result if a middleware skip the handler.
+    fn invoke_before(
+        &mut self,
+        uid: &Uid,
+        set: &mut S,
+        ctx: &mut Ctx,
+    ) -> (usize, Option<Result<bool, Error>>) {
+        for (called, middleware) in self.middlewares.iter_mut().enumerate() {
+            if let Some(short) = middleware.before(*uid, set, ctx).transpose() {
+                trace!("middleware skip the callback of {}", uid);
+                return (called, Some(short));
+            }
+        }
+        (self.middlewares.len(), None)
+    }
+
+    /// Call the [`after`](Middleware::after) of the middlewares called before the handler in reverse order,
+    /// then update the targets of [`bind`](Invoker::bind).
+    fn invoke_after(
+        &mut self,
+        called: usize,
+        uid: &Uid,
+        set: &mut S,
+        ctx: &mut Ctx,
+        ret: Result<bool, Error>,
+    ) -> Result<bool, Error> {
+        let ret = self.middlewares[..called]
+            .iter_mut()
+            .rev()
+            .fold(ret, |ret, middleware| middleware.after(*uid, set, ctx, ret));
+
+        self.update_binds(uid, set, ret)
+    }
+
+    /// Copy the values of option to the targets of [`bind`](Invoker::bind) if the option is set.
+    /// The update is queued if the binds are deferred by a [`Transaction`](crate::parser::Transaction).
+    fn update_binds(
+        &mut self,
+        uid: &Uid,
+        set: &S,
+        ret: Result<bool, Error>,
+    ) -> Result<bool, Error> {
+        if matches!(ret, Ok(true)) && self.binds.contains_key(uid) {
+            match self.deferred_binds.as_mut() {
+                Some(deferred) => {
+                    if !deferred.contains(uid) {
+                        deferred.push(*uid);
+                    }
+                }
+                None => self.write_binds(uid, set)?,
+            }
+        }
+        ret
+    }
+
+    fn write_binds(&mut self, uid: &Uid, set: &S) -> Result<(), Error> {
+        if let Some(binds) = self.binds.get_mut(uid) {
+            for bind in binds.iter_mut() {
+                (bind)(set)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Queue the updates of bound targets until [`flush_binds`](Invoker::flush_binds) called.
+    pub(crate) fn defer_binds(&mut self) {
+        self.deferred_binds = Some(vec![]);
+    }
+
+    /// Update the bound targets of options set since [`defer_binds`](Invoker::defer_binds).
+    pub(crate) fn flush_binds(&mut self, set: &S) -> Result<(), Error> {
+        for uid in self.deferred_binds.take().unwrap_or_default() {
+            self.write_binds(&uid, set)?;
+        }
+        Ok(())
+    }
+
+    fn async_error(uid: &Uid, set: &S) -> Error {
+        match set.opt(*uid) {
+            Ok(opt) => crate::error!(
+                "option `{}` has an async handler, parse it with `parse_async`",
+                opt.name()
+            ),
+            Err(e) => e,
+        }
+    }
 }
 
 impl<'a, S> Invoker<'a, S>
@@ -161,6 +375,41 @@ where
         HandlerEntry::new(self, uid)
     }
 
+    /// Register an async handler of option `uid`, the handler is awaited by
+    /// [`parse_async`](crate::parser::PolicyParser::parse_async) when the option matched.
+    /// The handler return a `Send` [`BoxedFuture`], such as `|set, ctx| Box::pin(async move { .. })`.
+    pub fn entry_async<O, H>(
+        &mut self,
+        uid: Uid,
+        handler: H,
+    ) -> AsyncHandlerEntry<'a, '_, Self, S, H, O>
+    where
+        O: ErasedTy,
+        H: for<'x> FnMut(&'x mut S, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        AsyncHandlerEntry::new(self, uid, handler)
+    }
+
+    /// Bind the option `uid` to the `target`, the values of option are copied to the `target`
+    /// every time the option is set by the handler or [`fallback`](Invoker::fallback).
+    /// The binding does not replace the handler of option, and an option can be bound to multiple targets.
+    /// See [`BindValue`](crate::ctx::BindValue) for the supported variable types.
+    pub fn bind<T>(&mut self, uid: Uid, mut target: T) -> &mut Self
+    where
+        T: BindTarget + Send + Sync + 'a,
+    {
+        self.binds
+            .entry(uid)
+            .or_default()
+            .push(Box::new(move |set: &S| {
+                target.update(set.opt(uid)?.vals::<T::Val>()?)
+            }));
+        self
+    }
+
     /// The default handler for all option.
     ///
     /// If there no handler for a option, then default handler will be called.
@@ -177,6 +426,9 @@ where
     }
 }
 
+/// Handler type using for update the target of [`bind`](Invoker::bind).
+type BindHandler<'a, S> = Box<dyn FnMut(&S) -> Result<(), Error> + Send + Sync + 'a>;
+
 /// Handler type using for callback.
 pub type InvokeHandler<'a, S, Error> =
     Box<dyn FnMut(&mut S, &mut Ctx) -> Result<bool, Error> + Send + Sync + 'a>;
@@ -212,6 +464,17 @@ pub trait HandlerCollection<'a, S: Set> {
             Invoker::fallback(set, ctx)
         }
     }
+
+    /// Invoke the handler of given `uid` like [`invoke_fb`](HandlerCollection::invoke_fb),
+    /// the async handler of option is awaited if it exist.
+    fn invoke_fb_async(
+        &mut self,
+        uid: &Uid,
+        set: &mut S,
+        ctx: &mut Ctx,
+    ) -> impl Future<Output = Result<bool, Error>> {
+        async move { self.invoke_fb(uid, set, ctx) }
+    }
 }
 
 impl<'a, S: Set> HandlerCollection<'a, S> for Invoker<'a, S> {
@@ -226,6 +489,58 @@ impl<'a, S: Set> HandlerCollection<'a, S> for Invoker<'a, S> {
     fn get_handler(&mut self, uid: &Uid) -> Option<&mut InvokeHandler<'a, S, Error>> {
         self.callbacks.get_mut(uid)
     }
+
+    fn invoke(&mut self, uid: &Uid, set: &mut S, ctx: &mut Ctx) -> Result<bool, Error> {
+        if self.async_callbacks.contains_key(uid) {
+            return Err(Self::async_error(uid, set));
+        }
+        self.invoke_wrapped(uid, set, ctx, false)
+    }
+
+    fn invoke_fb(&mut self, uid: &Uid, set: &mut S, ctx: &mut Ctx) -> Result<bool, Error> {
+        if self.async_callbacks.contains_key(uid) {
+            return Err(Self::async_error(uid, set));
+        }
+        self.invoke_wrapped(uid, set, ctx, true)
+    }
+
+    async fn invoke_fb_async(
+        &mut self,
+        uid: &Uid,
+        set: &mut S,
+        ctx: &mut Ctx<'_>,
+    ) -> Result<bool, Error> {
+        if !self.async_callbacks.contains_key(uid) {
+            return self.invoke_wrapped(uid, set, ctx, true);
+        }
+        if !self.allow_async {
+            return Err(Self::async_error(uid, set));
+        }
+        let (called, ret) = self.invoke_before(uid, set, ctx);
+        let ret = match ret {
+            Some(ret) => ret,
+            None => {
+                trace!("invoking async callback of {} {:?}", uid, ctx);
+                self.invoke_async(uid, set, ctx).await
+            }
+        };
+
+        self.invoke_after(called, uid, set, ctx, ret)
+    }
+}
+
+impl<'a, S: crate::set::Set> AsyncHandlerCollection<'a, S> for Invoker<'a, S> {
+    fn register_async<H: AsyncHandler<S> + Send + Sync + 'a>(&mut self, uid: Uid, handler: H) {
+        self.set_async_raw(uid, handler);
+    }
+
+    fn get_async_handler(&mut self, uid: &Uid) -> Option<&mut AsyncInvokeHandler<'a, S>> {
+        self.async_callbacks.get_mut(uid)
+    }
+
+    fn has_async(&self, uid: Uid) -> bool {
+        self.async_callbacks.contains_key(&uid)
+    }
 }
 
 pub struct HandlerEntry<'a, 'b, I, S, H, O>
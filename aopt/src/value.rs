@@ -7,17 +7,33 @@ pub use self::infer::Infer;
 pub use self::infer::Placeholder;
 
 pub use crate::acore::value::raw2str;
+pub use crate::acore::value::type_hint;
 pub use crate::acore::value::AnyValue;
+pub use crate::acore::value::BoolLiteral;
+pub use crate::acore::value::CheckHandler;
+pub use crate::acore::value::CheckResult;
+pub use crate::acore::value::ColorChoice;
+pub use crate::acore::value::ElemParser;
+pub use crate::acore::value::ElemSeparator;
 pub use crate::acore::value::ErasedValue;
+pub use crate::acore::value::ErrorFormat;
 pub use crate::acore::value::InitHandler;
 pub use crate::acore::value::InitializeValue;
+pub use crate::acore::value::InputFile;
+pub use crate::acore::value::OutputFile;
+pub use crate::acore::value::OutputMode;
+pub use crate::acore::value::OutputWriter;
 pub use crate::acore::value::RawValParser;
 pub use crate::acore::value::StoreHandler;
 pub use crate::acore::value::ValAccessor;
+pub use crate::acore::value::ValHint;
 pub use crate::acore::value::ValInitializer;
 pub use crate::acore::value::ValStorer;
 pub use crate::acore::value::ValValidator;
 pub use crate::acore::value::ValidatorHandler;
+pub use crate::acore::value::ValueSource;
+pub use crate::acore::value::Verbosity;
+pub use crate::acore::value::DEFAULT_CHECK_MESSAGE;
 
 use crate::ctx::Ctx;
 use crate::Error;
@@ -22,10 +22,17 @@ use crate::value::ValInitializer;
 use crate::value::ValValidator;
 use crate::Error;
 
+use super::type_hint;
 use super::AnyValue;
+use super::ColorChoice;
+use super::ElemParser;
+use super::ErrorFormat;
+use super::InputFile;
+use super::OutputFile;
 use super::RawValParser;
 use super::Stop;
 use super::ValStorer;
+use super::Verbosity;
 
 /// Implement this if you want the type can used for create option.
 pub trait Infer {
@@ -147,9 +154,9 @@ impl Infer for bool {
         vec![Style::Combined, Style::Boolean]
     }
 
-    /// bool has a default value [`false`]
+    /// bool has a default value [`false`], it is not displayed in help message
     fn infer_initializer() -> Option<ValInitializer> {
-        Some(ValInitializer::new_value(false))
+        Some(ValInitializer::new_value(false).with_display(None))
     }
 
     fn infer_map(val: Self::Val) -> Self {
@@ -181,7 +188,7 @@ impl Infer for Cmd {
     }
 
     fn infer_initializer() -> Option<ValInitializer> {
-        Some(ValInitializer::new_value(false))
+        Some(ValInitializer::new_value(false).with_display(None))
     }
 
     fn infer_type_id() -> TypeId {
@@ -231,7 +238,8 @@ where
         self.0.infer_mutable(val);
     }
 
-    /// Will add default type storer when value type is bool.
+    /// Will add default type storer when value type is bool,
+    /// then apply the tweak of `T`, such as the hint of tuple.
     ///
     /// # Storer
     /// ```ignore
@@ -273,7 +281,7 @@ where
                 )));
             }
         }
-        Ok(())
+        <T as Infer>::infer_tweak_info(cfg)
     }
 }
 
@@ -404,6 +412,107 @@ impl Infer for Stdin {
     }
 }
 
+impl Infer for Verbosity {
+    type Val = Verbosity;
+
+    fn infer_act() -> Action {
+        Action::Set
+    }
+
+    fn infer_style() -> Vec<Style> {
+        vec![Style::Combined, Style::Boolean]
+    }
+
+    /// The default level is displayed in help message, such as `[default: warn]`
+    fn infer_initializer() -> Option<ValInitializer> {
+        let value = Verbosity::default();
+
+        Some(ValInitializer::new_value(value).with_display(Some(value.to_string())))
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    /// For type Verbosity, each occurrence increase the level,
+    /// register a separate option for decreasing, see [`update`](Verbosity::update).
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        if !cfg.has_storer() {
+            cfg.set_storer(Verbosity::storer());
+        }
+        Ok(())
+    }
+}
+
+impl Infer for ColorChoice {
+    type Val = ColorChoice;
+
+    fn infer_act() -> Action {
+        Action::Set
+    }
+
+    /// The default choice is displayed in help message, such as `[default: auto]`
+    fn infer_initializer() -> Option<ValInitializer> {
+        let value = ColorChoice::default();
+
+        Some(ValInitializer::new_value(value).with_display(Some(value.to_string())))
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    /// For type ColorChoice, the choices are displayed as hint of value.
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        if !cfg.has_storer() {
+            cfg.set_storer(ColorChoice::storer());
+        }
+        Ok(())
+    }
+}
+
+impl Infer for ErrorFormat {
+    type Val = ErrorFormat;
+
+    fn infer_act() -> Action {
+        Action::Set
+    }
+
+    /// The default format is displayed in help message, such as `[default: text]`
+    fn infer_initializer() -> Option<ValInitializer> {
+        let value = ErrorFormat::default();
+
+        Some(ValInitializer::new_value(value).with_display(Some(value.to_string())))
+    }
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    /// For type ErrorFormat, the formats are displayed as hint of value.
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        if !cfg.has_storer() {
+            cfg.set_storer(ErrorFormat::storer());
+        }
+        Ok(())
+    }
+}
+
 impl Infer for Stop {
     type Val = Stop;
 
@@ -490,10 +599,79 @@ impl_infer_for!(usize);
 impl_infer_for!(String);
 impl_infer_for!(PathBuf);
 impl_infer_for!(OsString);
+impl_infer_for!(InputFile);
+impl_infer_for!(OutputFile);
 
 #[derive(Debug, Clone, Copy)]
 pub struct Placeholder;
 
+/// Generate hint from the types of element with the separator of option, such as `--size, -s <INT>x<INT>`.
+///
+/// The storer parsing elements with the separator is set if the option has a separator.
+fn infer_elem_info<T, C>(cfg: &mut C, hints: &[String])
+where
+    T: ErasedTy + ElemParser,
+    C: ConfigValue,
+{
+    let separator = cfg.separator().cloned().unwrap_or_default();
+
+    if !cfg.has_hint() {
+        if let Some(name) = cfg.name() {
+            let names = crate::opt::aopt::gen_hint(None::<String>, name, cfg.index(), cfg.alias());
+            let hint = format!("{names} {}", separator.join(hints));
+
+            cfg.set_hint(hint);
+        }
+    }
+    if cfg.has_separator() && !cfg.has_storer() {
+        cfg.set_storer(separator.storer::<T>());
+    }
+}
+
+macro_rules! impl_infer_for_tuple {
+    ($($ty:ident),+) => {
+        impl<$($ty: ErasedTy + RawValParser),+> Infer for ($($ty,)+) {
+            type Val = ($($ty,)+);
+
+            fn infer_map(val: Self::Val) -> Self {
+                val
+            }
+
+            fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+            where
+                Self: Sized + 'static,
+                Self::Val: RawValParser,
+                C: ConfigValue + Default,
+            {
+                infer_elem_info::<Self::Val, C>(cfg, &[$(type_hint::<$ty>()),+]);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_infer_for_tuple!(T1, T2);
+impl_infer_for_tuple!(T1, T2, T3);
+impl_infer_for_tuple!(T1, T2, T3, T4);
+
+impl<T: ErasedTy + RawValParser, const N: usize> Infer for [T; N] {
+    type Val = [T; N];
+
+    fn infer_map(val: Self::Val) -> Self {
+        val
+    }
+
+    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+    where
+        Self: Sized + 'static,
+        Self::Val: RawValParser,
+        C: ConfigValue + Default,
+    {
+        infer_elem_info::<Self::Val, C>(cfg, &vec![type_hint::<T>(); N]);
+        Ok(())
+    }
+}
+
 impl Infer for Placeholder {
     type Val = ();
 
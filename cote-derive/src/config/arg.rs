@@ -45,6 +45,32 @@ pub enum ArgKind {
 
     Count,
 
+    Conflicts,
+
+    Requires,
+
+    RequiredUnless,
+
+    Group,
+
+    Max,
+
+    Min,
+
+    Duplicate,
+
+    Env,
+
+    ShowDefault,
+
+    Verbosity,
+
+    Quiet,
+
+    Color,
+
+    Separator,
+
     MethodCall(String),
 }
 
@@ -75,6 +101,19 @@ impl Kind for ArgKind {
                 "fetch" => (Self::Fetch, Style::Value),
                 "append" => (Self::Append, Style::Flag),
                 "count" => (Self::Count, Style::Flag),
+                "conflicts" => (Self::Conflicts, Style::Value),
+                "requires" => (Self::Requires, Style::Value),
+                "required_unless" => (Self::RequiredUnless, Style::Value),
+                "group" => (Self::Group, Style::Value),
+                "max" => (Self::Max, Style::Value),
+                "min" => (Self::Min, Style::Value),
+                "duplicate" => (Self::Duplicate, Style::Value),
+                "env" => (Self::Env, Style::True),
+                "showdef" => (Self::ShowDefault, Style::True),
+                "verbosity" => (Self::Verbosity, Style::True),
+                "quiet" => (Self::Quiet, Style::Value),
+                "color" => (Self::Color, Style::True),
+                "sep" => (Self::Separator, Style::Value),
                 method => (Self::MethodCall(method.to_owned()), Style::Value),
             })
         } else {
@@ -86,6 +125,25 @@ impl Kind for ArgKind {
     }
 }
 
+/// Map the name of action to the variant of `Action`, same as the `FromStr` implementation of `Action`.
+fn action_variant(name: &syn::LitStr) -> syn::Result<Ident> {
+    let variant = match name.value().as_str() {
+        "set" => "Set",
+        "app" | "append" => "App",
+        "pop" => "Pop",
+        "cnt" | "count" => "Cnt",
+        "clr" | "clear" => "Clr",
+        "uniq" | "unique" => "AppUniq",
+        "ext" | "extend" => "Ext",
+        "once" => "SetOnce",
+        "toggle" => "Toggle",
+        "null" => "Null",
+        other => return Err(crate::error(name, format!("unknown action name `{other}`"))),
+    };
+
+    Ok(Ident::new(variant, name.span()))
+}
+
 impl ArgKind {
     pub fn simple(&self, ident: &Ident, val: TokenStream) -> syn::Result<TokenStream> {
         match self {
@@ -114,9 +172,21 @@ impl ArgKind {
             ArgKind::Force => Ok(quote! {
                 cote::prelude::ConfigValue::set_force(&mut #ident, #val);
             }),
-            ArgKind::Action => Ok(quote! {
-                cote::prelude::ConfigValue::set_action(&mut #ident, #val);
-            }),
+            ArgKind::Action => {
+                // support the name of action, such as `action = "uniq"`
+                let val = match syn::parse2::<syn::LitStr>(val.clone()) {
+                    Ok(name) => {
+                        let variant = action_variant(&name)?;
+
+                        quote! { cote::prelude::Action::#variant }
+                    }
+                    Err(_) => val,
+                };
+
+                Ok(quote! {
+                    cote::prelude::ConfigValue::set_action(&mut #ident, #val);
+                })
+            }
             ArgKind::Validator => Ok(quote! {
                 cote::prelude::ConfigValue::set_storer(&mut #ident, #val);
             }),
@@ -126,6 +196,79 @@ impl ArgKind {
             ArgKind::Count => Ok(quote! {
                 cote::prelude::ConfigValue::set_action(&mut #ident, cote::prelude::Action::Cnt);
             }),
+            ArgKind::Conflicts => Ok(quote! {
+                cote::prelude::ConfigValue::add_constraint(&mut #ident, cote::prelude::Constraint::conflicts(#val));
+            }),
+            ArgKind::Requires => Ok(quote! {
+                cote::prelude::ConfigValue::add_constraint(&mut #ident, cote::prelude::Constraint::requires(#val));
+            }),
+            ArgKind::RequiredUnless => Ok(quote! {
+                cote::prelude::ConfigValue::add_constraint(&mut #ident, cote::prelude::Constraint::required_unless(#val));
+            }),
+            ArgKind::Max => Ok(quote! {
+                let mut occurrence = cote::prelude::ConfigValue::occurrence(&#ident).clone();
+
+                occurrence.set_max(Some(#val));
+                cote::prelude::ConfigValue::set_occurrence(&mut #ident, occurrence);
+            }),
+            ArgKind::Min => Ok(quote! {
+                let mut occurrence = cote::prelude::ConfigValue::occurrence(&#ident).clone();
+
+                occurrence.set_min(#val);
+                cote::prelude::ConfigValue::set_occurrence(&mut #ident, occurrence);
+            }),
+            ArgKind::Duplicate => Ok(quote! {
+                let mut occurrence = cote::prelude::ConfigValue::occurrence(&#ident).clone();
+
+                occurrence.set_duplicate(#val);
+                cote::prelude::ConfigValue::set_occurrence(&mut #ident, occurrence);
+            }),
+            ArgKind::Env => Ok(quote! {
+                cote::prelude::ConfigValue::set_env(&mut #ident, #val);
+            }),
+            ArgKind::Separator => Ok(quote! {
+                cote::prelude::ConfigValue::set_separator(&mut #ident, cote::prelude::ElemSeparator::from(#val));
+            }),
+            ArgKind::ShowDefault => Ok(quote! {
+                if let Some(initializer) = cote::prelude::ConfigValue::initializer_mut(&mut #ident) {
+                    initializer.set_display(#val);
+                }
+            }),
+            ArgKind::Verbosity => {
+                // the `val` is the default level, empty if not set
+                let init = (!val.is_empty()).then(|| {
+                    quote! {
+                        let level = #val;
+
+                        cote::prelude::ConfigValue::set_initializer(
+                            &mut #ident,
+                            cote::prelude::ValInitializer::new_value(level).with_display(Some(level.to_string())),
+                        );
+                    }
+                });
+
+                Ok(quote! {
+                    #init
+                })
+            }
+            ArgKind::Color => {
+                // the `val` is the default choice, empty if not set
+                let init = (!val.is_empty()).then(|| {
+                    quote! {
+                        let choice = #val;
+
+                        cote::prelude::ConfigValue::set_initializer(
+                            &mut #ident,
+                            cote::prelude::ValInitializer::new_value(choice).with_display(Some(choice.to_string())),
+                        );
+                    }
+                });
+
+                Ok(quote! {
+                    cote::prelude::ConfigValue::set_name(&mut #ident, cote::prelude::ColorChoice::NAME);
+                    #init
+                })
+            }
             _ => Err(crate::error(ident.span(), "")),
         }
     }
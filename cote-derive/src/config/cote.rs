@@ -46,6 +46,12 @@ pub enum CoteKind {
 
     ShellCompletion,
 
+    Group,
+
+    EnvPrefix,
+
+    Layer,
+
     MethodCall(String),
 }
 
@@ -77,6 +83,9 @@ impl Kind for CoteKind {
                 "overload" => (Self::Overload, Style::True),
                 "prepolicy" => (Self::PrePolicy, Style::True),
                 "shellcomp" => (Self::ShellCompletion, Style::True),
+                "group" => (Self::Group, Style::Value),
+                "envprefix" => (Self::EnvPrefix, Style::Value),
+                "layer" => (Self::Layer, Style::Value),
                 method => (Self::MethodCall(method.to_owned()), Style::Value),
             })
         } else {
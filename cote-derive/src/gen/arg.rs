@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{spanned::Spanned, Field, Ident, Type};
 
-use crate::{config::ArgKind, error};
+use crate::{config::ArgKind, error, value::Value};
 
 use super::{AttrKind, Utils};
 use super::{FieldCfg, OptUpdate};
@@ -17,6 +17,8 @@ pub struct ArgGenerator<'a> {
 
     index: Option<usize>,
 
+    env_prefix: Option<TokenStream>,
+
     config: FieldCfg<'a, ArgKind>,
 }
 
@@ -58,6 +60,7 @@ impl<'a> ArgGenerator<'a> {
             Ok(Self {
                 name,
                 index: None,
+                env_prefix: None,
                 config,
                 ident,
                 uid_ident,
@@ -65,6 +68,26 @@ impl<'a> ArgGenerator<'a> {
         }
     }
 
+    pub fn name(&self) -> &TokenStream {
+        &self.name
+    }
+
+    /// Return the group names of `group = "name"` attributes.
+    pub fn groups(&self) -> syn::Result<Vec<String>> {
+        self.config
+            .configs()
+            .find_values(ArgKind::Group)
+            .into_iter()
+            .map(|value| match value {
+                Value::Literal(syn::Lit::Str(name)) => Ok(name.value()),
+                _ => Err(error(
+                    self.orig_ident().span(),
+                    "excepted string literal for `group` attribute",
+                )),
+            })
+            .collect()
+    }
+
     pub fn uid(&self) -> u64 {
         self.config.id()
     }
@@ -103,6 +126,184 @@ impl<'a> ArgGenerator<'a> {
         self.index
     }
 
+    // prefix using for generate the default environment variable name
+    pub fn set_env_prefix(&mut self, prefix: Option<TokenStream>) -> &mut Self {
+        self.env_prefix = prefix;
+        self
+    }
+
+    /// Return the environment variable name of `env` attribute:
+    /// the string literal is used as is, `env` or `env = true` generate
+    /// a name from the struct `envprefix` and the field name, such as `MYTOOL_TOKEN`.
+    pub fn gen_env_name(&self, value: &Value) -> syn::Result<Option<TokenStream>> {
+        match value {
+            Value::Literal(syn::Lit::Bool(enable)) => Ok(enable.value.then(|| {
+                let ident_str = self.orig_ident().to_string();
+                let env_name = ident_str.trim_start_matches("r#").to_uppercase();
+
+                match &self.env_prefix {
+                    Some(prefix) => quote! { format!("{}{}", #prefix, #env_name) },
+                    None => env_name.to_token_stream(),
+                }
+            })),
+            Value::Literal(syn::Lit::Str(name)) => Ok(Some(name.to_token_stream())),
+            _ => Err(error(
+                self.orig_ident().span(),
+                "excepted string literal or boolean for `env` attribute",
+            )),
+        }
+    }
+
+    /// Return the display string of default value of `showdef` attribute:
+    /// the string literal is a custom display string, `showdef = false` hide the default value,
+    /// `showdef` or `showdef = true` keep the display string generated by initializer.
+    pub fn gen_default_display(&self, value: &Value) -> syn::Result<Option<TokenStream>> {
+        match value {
+            Value::Literal(syn::Lit::Bool(enable)) => Ok((!enable.value).then(|| quote! { None })),
+            Value::Literal(syn::Lit::Str(display)) => Ok(Some(quote! {
+                Some(String::from(#display))
+            })),
+            _ => Err(error(
+                self.orig_ident().span(),
+                "excepted string literal or boolean for `showdef` attribute",
+            )),
+        }
+    }
+
+    /// Return the default level of `verbosity` attribute:
+    /// `verbosity` or `verbosity = true` use the default level of `Verbosity`,
+    /// a level name or integer set the default level, such as `verbosity = "info"`.
+    pub fn gen_verbosity_level(&self, value: &Value) -> syn::Result<TokenStream> {
+        const LEVEL_NAMES: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+        let level = match value {
+            Value::Literal(syn::Lit::Bool(enable)) if enable.value => return Ok(quote! {}),
+            Value::Literal(syn::Lit::Str(level)) => LEVEL_NAMES
+                .iter()
+                .position(|v| v.eq_ignore_ascii_case(level.value().trim()))
+                .map(|v| v as u8),
+            Value::Literal(syn::Lit::Int(level)) => level
+                .base10_parse::<u8>()
+                .ok()
+                .filter(|v| (*v as usize) < LEVEL_NAMES.len()),
+            _ => {
+                return Err(error(
+                    self.orig_ident().span(),
+                    "excepted string literal or integer for `verbosity` attribute",
+                ))
+            }
+        };
+
+        match level {
+            Some(level) => Ok(quote! { cote::prelude::Verbosity::new(#level) }),
+            None => Err(error(
+                value,
+                format!(
+                    "invalid verbosity level, excepted one of {} or 0 to 5",
+                    LEVEL_NAMES.join(", ")
+                ),
+            )),
+        }
+    }
+
+    /// Return the names of decrease option of `verbosity` attribute,
+    /// such as `quiet = "-q"` or `quiet = ["-q", "--quiet"]`, default is `-q` and `--quiet`.
+    pub fn gen_quiet_names(&self) -> syn::Result<Vec<syn::LitStr>> {
+        let span = self.orig_ident().span();
+
+        match self.config.find_value(ArgKind::Quiet) {
+            None => Ok(vec![
+                syn::LitStr::new("-q", span),
+                syn::LitStr::new("--quiet", span),
+            ]),
+            Some(Value::Literal(syn::Lit::Str(name))) => Ok(vec![name.clone()]),
+            Some(Value::Expr(syn::Expr::Array(names))) if !names.elems.is_empty() => names
+                .elems
+                .iter()
+                .map(|v| match v {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(name),
+                        ..
+                    }) => Ok(name.clone()),
+                    _ => Err(error(v, "excepted string literal for `quiet` attribute")),
+                })
+                .collect(),
+            Some(value) => Err(error(
+                value,
+                "excepted string literal or array of string literal for `quiet` attribute",
+            )),
+        }
+    }
+
+    /// Register the decrease option of `verbosity` attribute,
+    /// its handler decrease the level saved in the option of field.
+    pub fn gen_quiet_option(&self) -> syn::Result<Option<TokenStream>> {
+        if !self.config.has_cfg(ArgKind::Verbosity) {
+            return Ok(None);
+        }
+        let uid_ident = self.uid_ident();
+        let names = self.gen_quiet_names()?;
+        let (name, aliases) = names.split_first().unwrap();
+
+        Ok(Some(quote! {
+            {
+                let set = parser.optset_mut();
+                let quiet = {
+                    let cfg = {
+                        let mut cfg = cote::prelude::SetCfg::<Set>::default();
+
+                        cote::prelude::ConfigValue::set_name(&mut cfg, #name);
+                        #(
+                            cote::prelude::ConfigValue::add_alias(&mut cfg, #aliases);
+                        )*
+                        cote::prelude::ConfigValue::set_help(&mut cfg, String::from("Decrease the verbosity level"));
+                        <bool as cote::prelude::Infer>::infer_fill_info(&mut cfg)?;
+                        cfg
+                    };
+                    cote::prelude::Ctor::new_with(cote::prelude::SetExt::ctor_mut(set, &ctor_name)?, cfg).map_err(Into::into)?
+                };
+                let quiet_uid = set.insert(quiet);
+
+                parser.entry(quiet_uid)?.on(
+                    move |set: &mut cote::prelude::Parser<'inv, Set>, _: &mut cote::prelude::Ctx| {
+                        let opt = cote::prelude::SetExt::opt_mut(set, #uid_ident)?;
+
+                        cote::prelude::Verbosity::update(opt.accessor_mut(), true);
+                        Ok(Some(true))
+                    }
+                );
+            }
+        }))
+    }
+
+    /// Return the default choice of `color` attribute:
+    /// `color` or `color = true` use the default choice `auto`,
+    /// a choice name set the default choice, such as `color = "never"`.
+    pub fn gen_color_choice(&self, value: &Value) -> syn::Result<TokenStream> {
+        match value {
+            Value::Literal(syn::Lit::Bool(enable)) if enable.value => Ok(quote! {}),
+            Value::Literal(syn::Lit::Str(choice)) => {
+                let variant = match choice.value().trim().to_ascii_lowercase().as_str() {
+                    "auto" => quote! { Auto },
+                    "always" => quote! { Always },
+                    "never" => quote! { Never },
+                    _ => {
+                        return Err(error(
+                            choice,
+                            "invalid color choice, excepted one of auto, always, never",
+                        ))
+                    }
+                };
+
+                Ok(quote! { cote::prelude::ColorChoice::#variant })
+            }
+            _ => Err(error(
+                self.orig_ident().span(),
+                "excepted string literal for `color` attribute",
+            )),
+        }
+    }
+
     pub fn gen_opt_update(&self) -> syn::Result<OptUpdate> {
         let c = self.gen_opt_create()?;
         let i = self.gen_opt_insert()?;
@@ -129,8 +330,12 @@ impl<'a> ArgGenerator<'a> {
         let fallback = self.config.find_cfg(ArgKind::Fallback);
         let then = self.config.find_cfg(ArgKind::Then);
         let uid_ident = self.uid_ident();
+        let handler = Utils::gen_opt_handler(uid_ident, on, fallback, then)?;
 
-        Utils::gen_opt_handler(uid_ident, on, fallback, then)
+        Ok(match (handler, self.gen_quiet_option()?) {
+            (Some(handler), Some(quiet)) => Some(quote! { #handler #quiet }),
+            (handler, quiet) => handler.or(quiet),
+        })
     }
 
     pub fn gen_opt_create(&self) -> syn::Result<TokenStream> {
@@ -139,7 +344,7 @@ impl<'a> ArgGenerator<'a> {
         let field_cfg = &self.config;
         let cfg_ident = Ident::new("cfg", field_span);
         let mut codes = vec![];
-        let mut value = None;
+        let mut has_value = false;
 
         codes.push(ArgKind::Name.simple(&cfg_ident, self.name.clone())?);
         for cfg in field_cfg.configs().iter() {
@@ -153,11 +358,24 @@ impl<'a> ArgGenerator<'a> {
                 | ArgKind::Action
                 | ArgKind::Count
                 | ArgKind::Index
+                | ArgKind::Max
+                | ArgKind::Min
+                | ArgKind::Duplicate
+                | ArgKind::Separator
                 | ArgKind::Append => {
                     let value = cfg_value.to_token_stream();
 
                     codes.push(kind.simple(&cfg_ident, value)?);
                 }
+                ArgKind::Conflicts | ArgKind::Requires | ArgKind::RequiredUnless => {
+                    // accept a single option name or an array of option names
+                    let value = match cfg_value {
+                        Value::Literal(lit) => quote! { [#lit] },
+                        value => value.to_token_stream(),
+                    };
+
+                    codes.push(kind.simple(&cfg_ident, value)?);
+                }
                 ArgKind::Name => {
                     // already processed
                 }
@@ -169,9 +387,15 @@ impl<'a> ArgGenerator<'a> {
                 ArgKind::Validator => codes.push(kind.simple(
                     &cfg_ident,
                     quote! {{
-                        let validator = cote::prelude::ValValidator::from_fn( |value| {
-                            cote::valid::Validate::check(& #cfg_value, value)
+                        let valid = #cfg_value;
+                        let hint = cote::valid::Validate::<InferedOptVal<#field_ty>>::hint(&valid);
+                        let mut validator = cote::prelude::ValValidator::from_fn(move |value| {
+                            cote::valid::Validate::validate(&valid, value)
                         });
+
+                        if let Some(hint) = hint {
+                            validator = validator.with_hint(hint);
+                        }
                         cote::prelude::ValStorer::new_validator::<InferedOptVal<#field_ty>>(validator)
                     }},
                 )?),
@@ -189,18 +413,26 @@ impl<'a> ArgGenerator<'a> {
                     });
                 }
                 ArgKind::Value => {
-                    value = Some(cfg_value.clone());
+                    has_value = true;
+                    // evaluate the value once, keep the Debug string as fallback display
+                    codes.push(quote! {
+                        let value = #cfg_value;
+                        let value_display = format!("{:?}", value);
+                    });
                     codes.push(kind.simple(
                         &cfg_ident,
-                        quote!( <InferedOptVal<#field_ty>>::from(#cfg_value) ),
+                        quote!( <InferedOptVal<#field_ty>>::from(value) ),
                     )?);
                 }
                 ArgKind::Values => {
-                    value = Some(cfg_value.clone());
-
+                    has_value = true;
+                    codes.push(quote! {
+                        let values = #cfg_value;
+                        let value_display = format!("{:?}", values);
+                    });
                     codes.push(kind.simple(
                         &cfg_ident,
-                        quote!( #cfg_value.into_iter().map(<InferedOptVal<#field_ty>>::from).collect::<Vec<InferedOptVal<#field_ty>>>()
+                        quote!( values.into_iter().map(<InferedOptVal<#field_ty>>::from).collect::<Vec<InferedOptVal<#field_ty>>>()
                         ),
                     )?);
                 }
@@ -213,28 +445,68 @@ impl<'a> ArgGenerator<'a> {
                 ArgKind::SCValues => {
                     // will process it in cote shell completion generator
                 }
+                ArgKind::Group => {
+                    // will process it in cote group generator
+                }
+                ArgKind::Env => {
+                    if let Some(env) = self.gen_env_name(cfg_value)? {
+                        codes.push(kind.simple(&cfg_ident, env)?);
+                    }
+                }
+                ArgKind::ShowDefault => {
+                    // will process it after the value set
+                }
+                ArgKind::Verbosity => {
+                    let level = self.gen_verbosity_level(cfg_value)?;
+
+                    if !field_cfg.has_cfg(ArgKind::Alias) {
+                        codes.push(ArgKind::Alias.simple(&cfg_ident, quote! { "-v" })?);
+                    }
+                    codes.push(kind.simple(&cfg_ident, level)?);
+                }
+                ArgKind::Quiet => {
+                    // will process it in handler of decrease option
+                }
+                ArgKind::Color => {
+                    let choice = self.gen_color_choice(cfg_value)?;
+
+                    codes.push(kind.simple(&cfg_ident, choice)?);
+                }
             }
         }
         // if we have value, set the force to false
-        if value.is_some() {
+        if has_value {
             codes.push(ArgKind::Force.simple(&cfg_ident, false.to_token_stream())?);
+            // using Debug for default value if the initializer can not display it
+            codes.push(quote! {
+                if let Some(initializer) = cote::prelude::ConfigValue::initializer_mut(&mut #cfg_ident) {
+                    if initializer.display().is_none() {
+                        initializer.set_display(Some(value_display));
+                    }
+                }
+            });
+        }
+        if let Some(showdef) = field_cfg.find_value(ArgKind::ShowDefault) {
+            if let Some(display) = self.gen_default_display(showdef)? {
+                codes.push(ArgKind::ShowDefault.simple(&cfg_ident, display)?);
+            }
         }
         if let Some(help) = field_cfg
             .find_value(ArgKind::Help)
             .map(|v| quote! { String::from(#v.trim()) })
             .or_else(|| field_cfg.collect_help_msgs())
+            .or_else(|| {
+                field_cfg.has_cfg(ArgKind::Verbosity).then(|| {
+                    quote! { String::from("Increase the verbosity level") }
+                })
+            })
+            .or_else(|| {
+                field_cfg.has_cfg(ArgKind::Color).then(|| {
+                    quote! { String::from("When to use colors: auto, always or never") }
+                })
+            })
         {
-            codes.push(ArgKind::Help.simple(
-                &cfg_ident,
-                if let Some(value) = value.as_ref() {
-                    let value = value.to_token_stream();
-
-                    // using Debug for default value, better?
-                    quote! { format!("{} [{:?}]", #help, #value) }
-                } else {
-                    help
-                },
-            )?);
+            codes.push(ArgKind::Help.simple(&cfg_ident, help)?);
         }
         if let Some(index) = self.pos_index() {
             if !self.config.has_cfg(ArgKind::Index) {
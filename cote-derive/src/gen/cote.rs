@@ -7,9 +7,12 @@ use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::DataStruct;
 use syn::DeriveInput;
+use syn::Expr;
+use syn::ExprLit;
 use syn::Field;
 use syn::Fields;
 use syn::GenericParam;
+use syn::Lit;
 use syn::Type;
 use syn::{Data, Generics};
 
@@ -17,6 +20,7 @@ use crate::config::Configs;
 use crate::config::CoteKind;
 use crate::error;
 use crate::r#gen::GenericsModifier;
+use crate::value::Value;
 
 use super::arg::ArgGenerator;
 use super::sub::SubGenerator;
@@ -57,6 +61,10 @@ impl<'a> CoteGenerator<'a> {
             .map(|v| quote!(String::from(#v)))
             .unwrap_or_else(|| quote!(String::from(env!("CARGO_PKG_NAME"))));
 
+        let env_prefix = configs
+            .find_value(CoteKind::EnvPrefix)
+            .map(|v| v.to_token_stream());
+
         Self::check_parameters(&generics.params)?;
 
         let mut fgs = vec![];
@@ -87,6 +95,7 @@ impl<'a> CoteGenerator<'a> {
                                 ag.set_pos_index(pos_index);
                                 pos_index += 1;
                             }
+                            ag.set_env_prefix(env_prefix.clone());
                             ag
                         })
                     };
@@ -261,6 +270,8 @@ impl<'a> CoteGenerator<'a> {
         if let Some(up) = self.gen_help_option()? {
             append(up);
         }
+        let groups = self.gen_groups()?;
+
         Ok(quote! {
             // a convenient type for option value
             type InferedOptVal<T> = <T as cote::prelude::Infer>::Val;
@@ -271,10 +282,92 @@ impl<'a> CoteGenerator<'a> {
             #(#creates)*
             #(#inserts)*
             #(#handlers)*
+            #(#groups)*
             Ok(())
         })
     }
 
+    /// Generate the [`OptGroup`] for every `group(name = "src", exclusive, required)` attribute,
+    /// the members are the options with `group = "src"` attribute.
+    pub fn gen_groups(&self) -> syn::Result<Vec<TokenStream>> {
+        let span = self.orig_ident.span();
+        let mut ret = vec![];
+        let mut names = vec![];
+
+        for value in self.configs.find_values(CoteKind::Group) {
+            let Value::Call(args) = value else {
+                return Err(error(span, "excepted `group(name = \"..\", ..)` attribute"));
+            };
+            let mut name = None;
+            let mut calls = vec![];
+
+            for arg in args {
+                let (key, val) = match arg {
+                    Expr::Assign(assign) => (assign.left.as_ref(), Some(assign.right.as_ref())),
+                    expr => (expr, None),
+                };
+                let key = key.to_token_stream().to_string();
+
+                match (key.as_str(), val) {
+                    (
+                        "name",
+                        Some(Expr::Lit(ExprLit {
+                            lit: Lit::Str(lit), ..
+                        })),
+                    ) => {
+                        name = Some(lit.value());
+                    }
+                    ("members", Some(val)) => {
+                        calls.push(quote! { .with_members(#val) });
+                    }
+                    ("exclusive" | "required" | "all_or_none", val) => {
+                        let method = Ident::new(&format!("with_{key}"), span);
+                        let val = val.map(|v| v.to_token_stream()).unwrap_or(quote! { true });
+
+                        calls.push(quote! { .#method(#val) });
+                    }
+                    _ => {
+                        return Err(error(
+                            arg.span(),
+                            format!("unknown configuration `{key}` of `group`"),
+                        ))
+                    }
+                }
+            }
+            let name = name.ok_or_else(|| {
+                error(
+                    span,
+                    "`group` need a string literal name, such as `name = \"src\"`",
+                )
+            })?;
+
+            for fg in self.field_generators.iter().filter(|v| v.is_arg()) {
+                let ag = fg.as_arg();
+
+                if ag.groups()?.contains(&name) {
+                    let member = ag.name();
+
+                    calls.push(quote! { .with_member(#member) });
+                }
+            }
+            ret.push(quote! {
+                parser.add_group(cote::prelude::OptGroup::new(#name)#(#calls)*);
+            });
+            names.push(name);
+        }
+        for fg in self.field_generators.iter().filter(|v| v.is_arg()) {
+            let ag = fg.as_arg();
+
+            if let Some(group) = ag.groups()?.into_iter().find(|v| !names.contains(v)) {
+                return Err(error(
+                    ag.orig_ident().span(),
+                    format!("can not find group `{group}`, please define it with `#[cote(group(name = \"{group}\"))]`"),
+                ));
+            }
+        }
+        Ok(ret)
+    }
+
     pub fn gen_main_and_help_uid(&mut self) -> syn::Result<()> {
         // we need help uid in handler of sub
         if self.configs.has_cfg(CoteKind::Fallback) || self.configs.has_cfg(CoteKind::On) {
@@ -419,6 +512,8 @@ impl<'a> CoteGenerator<'a> {
         let policy_ret_ty = self.gen_policy_ty(false)?;
         let policy_setting_mod = self.gen_policy_setting_mod()?;
         let method_calls = self.gen_method_call()?;
+        let layers = self.gen_layers();
+        let source_of = self.gen_source_of();
         let shell_where_clause = quote! {
             cote::prelude::SetOpt<S>: cote::prelude::Opt + 'static,
             cote::prelude::SetCfg<S>: cote::prelude::ConfigValue + Default,
@@ -476,6 +571,8 @@ impl<'a> CoteGenerator<'a> {
                 Ok(rctx)
             }
 
+            #source_of
+
             pub fn into_parser<'inv>() -> cote::Result<cote::prelude::Parser<'inv, cote::prelude::CoteSet>>
             where #fetch_code {
                 Self::into_parser_with::<cote::prelude::CoteSet>()
@@ -517,6 +614,9 @@ impl<'a> CoteGenerator<'a> {
                 // call on parser or policy set by user
                 #(#method_calls)* // todo! do we need apply this in sub handler ?
 
+                // load the configuration files, the later one has higher priority
+                #(#layers)*
+
                 // setup a new running ctx, set name of parser
                 parser.running_ctx().set_name(#parser_name);
 
@@ -739,6 +839,58 @@ impl<'a> CoteGenerator<'a> {
         })
     }
 
+    /// Generate the code load [`ConfigLayer`] for every `layer = "path"` attribute,
+    /// the file is ignored if not exists.
+    pub fn gen_layers(&self) -> Vec<TokenStream> {
+        self.configs
+            .find_values(CoteKind::Layer)
+            .into_iter()
+            .map(|path| {
+                quote! {
+                    if let Some(layer) = cote::prelude::ConfigLayer::load_if_exists(#path)? {
+                        parser.add_layer(layer);
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Return the source of value of given field, the sub commands are not included.
+    pub fn gen_source_of(&self) -> TokenStream {
+        let orig_ident = self.orig_ident;
+        let mut fields = vec![];
+        let mut uids = vec![];
+
+        for fg in self.field_generators.iter() {
+            if let FieldGenerator::Arg(ag) = fg {
+                let ident = ag.orig_ident().to_string();
+
+                fields.push(ident.trim_start_matches("r#").to_owned());
+                uids.push(Utils::id2uid_literal(ag.uid()));
+            }
+        }
+        quote! {
+            /// Return the source of last value of the field in the parser, return `None` if it has no value.
+            #[allow(unreachable_code)]
+            pub fn source_of<'a, 'inv, S>(parser: &'a cote::prelude::Parser<'inv, S>, field: &str)
+                -> cote::Result<Option<&'a cote::prelude::ValueSource>>
+            where
+                S: cote::prelude::Set,
+                cote::prelude::SetOpt<S>: cote::prelude::Opt, {
+                let uid = match field {
+                    #(#fields => #uids,)*
+                    _ => {
+                        return Err(cote::prelude::error!(
+                            "`{}` is not an option field of `{}`", field, stringify!(#orig_ident)
+                        ))
+                    }
+                };
+
+                Ok(cote::prelude::SetExt::opt(parser.optset(), uid)?.accessor().source())
+            }
+        }
+    }
+
     pub fn gen_method_call(&self) -> syn::Result<Vec<TokenStream>> {
         let span = self.orig_ident.span();
         let mut ret = vec![];
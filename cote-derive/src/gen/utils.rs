@@ -318,8 +318,10 @@ impl Utils {
                 }
             }
             Ok(false)
-        } else {
+        } else if let Type::Reference(_) = ty {
             Err(error(ty, "Cote not support reference type"))
+        } else {
+            Ok(false)
         }
     }
 
@@ -34,7 +34,7 @@ fn main() -> color_eyre::Result<()> {
     assert_eq!(app["--baz"].hint(), "-b, --baz");
 
     assert_eq!(app["foo"].help(), "Switch the mode to foo command");
-    assert_eq!(app["bar"].help(), "Set the value of bar [42]");
+    assert_eq!(app["bar"].help(), "Set the value of bar");
     assert_eq!(app["--baz"].help(), "Set the string value of baz");
 
     assert_eq!(default_value::<String>(&mut app["--baz"])?, None);
@@ -44,7 +44,14 @@ fn main() -> color_eyre::Result<()> {
         Some(vec!["corge".to_owned(), "grault".to_owned()])
     );
 
-    // Currently only display default values are set in the attribute
+    // The display string of default value is displayed as `[default: ..]`
+    assert_eq!(app["bar"].accessor().initializer().display(), Some("42"));
+    assert_eq!(
+        app["quux"].accessor().initializer().display(),
+        Some("corge, grault")
+    );
+    assert_eq!(app["--baz"].accessor().initializer().display(), None);
+
     assert!(Cli::parse(Args::from(["app", "--help"])).is_err());
 
     Ok(())
@@ -296,7 +296,10 @@
 //! use `hint` custom the hint information of item.
 //! Help is displayed on the right side of item information.
 //! Use `help` configure the help information of item.
-//! The default values will be display in help message if it is set.
+//! The default values will be display in help message as `[default: ..]` if it is set.
+//! The display string is generated by [`ValInitializer`](crate::prelude::ValInitializer),
+//! or using [`Debug`](std::fmt::Debug) if the type is not a primitive type, [`String`] or [`PathBuf`](std::path::PathBuf).
+//! Use `showdef = "..."` custom the display string, or `showdef = false` hide it.
 //!
 //!
 //! ```rust
@@ -318,8 +321,8 @@
 //!   -b,--baz          Set the string value of baz
 //!
 //! Args:
-//!   [BAR]         Set the value of bar [42]
-//!   quux@3..
+//!   [BAR]         Set the value of bar [default: 42]
+//!   quux@3..      [default: corge, grault]
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! ```
@@ -439,11 +442,23 @@
 //! Got client: Cli { foo: 9, bar: None, qux: Some(Qux { corge: true, grault: Some(42) }) }
 //! ```
 //!
+//! #### Extractor handler
+//!
+//! Wrap the handler with [`extract_handler`](crate::prelude::extract_handler), the parameters of
+//! handler are resolved by [`Extract`](crate::prelude::Extract) from the parser and context, such as
+//! [`Value`](aopt::ctx::Value), [`OptVal`](aopt::ctx::OptVal) and [`Service`](aopt::ctx::Service).
+//!
+//! ```rust
+#![doc = include_str!("../tests/39_extract.rs")]
+//! ```
+//!
 //! ### Validate values
 //!
 //! You can using `valid` check the value inside attribute.
 //! Using [`valid!`](crate::valid!) generate struct implemented [`Validate`](crate::valid::Validate)
 //! for the valid attribute.
+//! The reason of rejected value, such as `must be between 5 and 30`, is carried in the failure.
+//! The function passed to [`valid!`](crate::valid!) can return a `Result<(), impl Display>` for a custom reason.
 //!
 //! ```rust
 #![doc = include_str!("../examples/17_arg_validator.rs")]
@@ -586,6 +601,43 @@
 #![doc = include_str!("../examples/31_modify_prefix.rs")]
 //! ```
 //!
+//! ### Find out where the value come from
+//!
+//! The parser records a [`ValueSource`](crate::prelude::ValueSource) for each value of option,
+//! it can be default value, environment variable, configuration or command line with argument index.
+//! Using the generated `source_of` with the field name, or [`find_source`](crate::prelude::SetValueFindExt::find_source) with the option name.
+//! In the handler the source of current argument is [`Ctx::source`](crate::prelude::Ctx::source).
+//!
+//! ```rust
+#![doc = include_str!("../tests/33_source.rs")]
+//! ```
+//!
+//! ### Invoke async handler
+//!
+//! Register an async handler with [`entry_async`](crate::prelude::Parser::entry_async),
+//! it is awaited when the option matched, before the checks of policy.
+//! The `run_async*` method such as [`run_async_mut_with`](crate::prelude::Parser::run_async_mut_with) parse the arguments asynchronously before running the function,
+//! the synchronous parsing will report an error if any async handler matched.
+//! With feature `sync` the handler need return a `Send` [`BoxedFuture`](aopt::ctx::BoxedFuture).
+//!
+//! ```rust
+#![doc = include_str!("../tests/38_async.rs")]
+//! ```
+//!
+//! ### Output errors in JSON
+//!
+//! Add a field of [`ErrorFormat`](crate::prelude::ErrorFormat), such as `--error-format=json`,
+//! the format is read from the parser by [`error_format`](crate::prelude::error_format),
+//! pass it to [`display_error`](crate::prelude::display_error) choose the format of error output.
+//! With feature `json`, the error and its causes are serialized by `error_json`,
+//! include the kind, uid, option name, argument index and value, hints and suggestions.
+//! [`exit_with_error`](crate::prelude::exit_with_error) print the error and exit with [`exit_code`](crate::prelude::exit_code),
+//! it is [`EXIT_FAILURE`](crate::prelude::EXIT_FAILURE) for failures and [`EXIT_ERROR`](crate::prelude::EXIT_ERROR) for others.
+//!
+//! ```rust
+#![doc = include_str!("../tests/40_error_format.rs")]
+//! ```
+//!
 //! ## How it works
 //!
 //! ### Traits
@@ -661,6 +713,9 @@
 //!| `overload`|  false     | boolean |
 //!|`prepolicy`|  false     | boolean |
 //!|`shellcomp`|  false     | |
+//!| `group`   |  true      | `name = "..."`, `exclusive`, `required`, `all_or_none`, `members = [..]` |
+//!|`envprefix`|  true      | string literal |
+//!| `layer`   |  true      | path expression |
 //! * `policy`
 //!
 //! Configure the policy of current struct, its value should be `fwd`, `seq` or `delay`.
@@ -747,6 +802,32 @@
 //!
 //! Enable some extra [`user style`](crate::UserStyle) of policy. See also [`Configurating User Style`](#configurating-user-style).
 //!
+//! * `group`
+//!
+//! Register an [`OptGroup`](crate::prelude::OptGroup) into the parser, the members are the options
+//! have `group = "name"` attribute or listed in `members`.
+//! The group is checked after parsing, and it is displayed as a help block and `(--a | --b)` in usage.
+//!
+//! ```rust
+#![doc = include_str!("../tests/29_group.rs")]
+//! ```
+//!
+//! * `envprefix`
+//!
+//! The prefix of environment variable name generated by `env` attribute of option, such as `MYTOOL_`.
+//!
+//! * `layer`
+//!
+//! Load a [`ConfigLayer`](crate::prelude::ConfigLayer) from the configuration file if it exists,
+//! the format is decided by extension: `toml` needs feature `toml`, `json` needs feature `json`.
+//! The keys are matched against the option names, and the tables are used for sub commands.
+//! The values are parsed and validated as same as the arguments.
+//! The layer listed later has higher priority, the precedence is `command line > env > layers > defaults`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/32_layer.rs")]
+//! ```
+//!
 //! #### `arg`, `pos`, `cmd`
 //!
 //!| name      | need value | available value |
@@ -760,7 +841,7 @@
 //!| `alias`   |  true      | string literal |
 //!| `index`   |  true      | range or integer |
 //!| `force`   |  true      | boolean |
-//!| `action`  |  true      | [`Action`](crate::prelude::Action) |
+//!| `action`  |  true      | [`Action`](crate::prelude::Action) or string literal |
 //!| `valid`   |  true      | [`valid!`](crate::valid!) |
 //!| `on`      |  true      | function or closure |
 //!| `fallback`|  true      | function or closure |
@@ -770,6 +851,19 @@
 //!| `append`  |  false     | |
 //!| `count`   |  false     | |
 //!| `scvalues`|  true      | [`Values`](crate::shell::value::Values) |
+//!| `conflicts`|  true     | string literal or array of string literal |
+//!| `requires`|  true      | string literal or array of string literal |
+//!| `required_unless`|  true | string literal or array of string literal |
+//!| `group`   |  true      | string literal |
+//!| `max`     |  true      | integer |
+//!| `min`     |  true      | integer |
+//!|`duplicate`|  true      | [`Duplicate`](crate::prelude::Duplicate) |
+//!| `env`     |  false     | string literal or boolean |
+//!| `showdef` |  false     | string literal or boolean |
+//!|`verbosity`|  false     | string literal or integer |
+//!| `quiet`   |  false     | string literal or array of string literal |
+//!| `color`   |  false     | string literal |
+//!| `sep`     |  true      | char or array of char |
 //!
 //! * `name`, `alias`
 //!
@@ -816,6 +910,19 @@
 //! `append` is an alias of "action = [`Action::App`](crate::prelude::Action::App)",
 //! `count` is an alias of "action = [`Action::Cnt`](crate::prelude::Action::Cnt)"
 //!
+//! The `action` also accept the name of action, such as `action = "uniq"`, the name is checked at compile time.
+//! [`AppUniq`](crate::prelude::Action::AppUniq) ignore the duplicate values,
+//! [`Ext`](crate::prelude::Action::Ext) split the value by the `sep` of option (`,` in default)
+//! and invoke the handler with each of them,
+//! [`SetOnce`](crate::prelude::Action::SetOnce) raise an error if the option appears twice,
+//! and [`Toggle`](crate::prelude::Action::Toggle) flip the boolean value on each occurrence,
+//! it is only available for `bool` option.
+//! The hint of `App`, `AppUniq` and `Ext` option has a `...` suffix in help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/35_action.rs")]
+//! ```
+//!
 //! * `fetch`
 //!
 //! Configure the handler which is used to extract value from [`set`](crate::prelude::Set).
@@ -861,6 +968,84 @@
 #![doc = include_str!("../tests/20_completion.rs")]
 //! ```
 //!
+//! * `conflicts`, `requires`, `required_unless`
+//!
+//! Add [`Constraint`](crate::prelude::Constraint) between options, the value is name or alias of other options.
+//! The constraints are checked after parsing, and they are displayed in the help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/28_constraint.rs")]
+//! ```
+//!
+//! * `group`
+//!
+//! Add current option to the group defined by `group` of `cote` attribute.
+//!
+//! * `max`, `min`, `duplicate`
+//!
+//! Configure the [`Occurrence`](crate::prelude::Occurrence) rule of option.
+//! The policy raise an error cites both argument positions if the option occurs more than `max` times,
+//! or process the repeated occurrence according to `duplicate`.
+//! The `min` is checked after parsing if the option is set or force required.
+//!
+//! ```rust
+#![doc = include_str!("../tests/30_occurrence.rs")]
+//! ```
+//!
+//! * `env`
+//!
+//! Fallback to the value of environment variable if the option is not set in command line.
+//! The value is parsed and validated as same as the argument, and it satisfies the force required check.
+//! Without a name, the environment variable name is `envprefix` + the upper case field name, such as `MYTOOL_TOKEN`.
+//! The name is displayed as `[env: MYTOOL_TOKEN]` in the help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/31_env.rs")]
+//! ```
+//!
+//! * `showdef`
+//!
+//! Configure the display string of default value set by `value` or `values`, it is displayed as `[default: 30]` in help message.
+//! Use a string literal custom the display string, or `showdef = false` hide the default value.
+//!
+//! ```rust
+#![doc = include_str!("../tests/34_default.rs")]
+//! ```
+//!
+//! * `verbosity`
+//!
+//! Register the option as a pair of increase and decrease options of [`Verbosity`](crate::prelude::Verbosity),
+//! the alias `-v` is added to the increase option if no `alias` set,
+//! the decrease option is named by `quiet`, such as `quiet = ["-s", "--silent"]`, default is `-q` and `--quiet`.
+//! Both options share the value of field, and a help message is set if the field has no document.
+//! Use a level name or integer set the default level, such as `verbosity = "info"`, the level is checked at compile time.
+//! Enable `combine` for `-vvv` style.
+//!
+//! ```rust
+#![doc = include_str!("../tests/36_verbosity.rs")]
+//! ```
+//!
+//! * `color`
+//!
+//! Register the option as `--color` of [`ColorChoice`](crate::prelude::ColorChoice), a help message is set if the field has no document.
+//! Use a string literal set the default choice, such as `color = "never"`.
+//! The choice is read from the parser by [`color_choice`](crate::prelude::color_choice), it controls the colors of help message,
+//! pass it to [`display_error`](crate::prelude::display_error) for the colors of error message.
+//! The `auto` choice honours `NO_COLOR` and `CLICOLOR_FORCE`, and checks if the output is a terminal.
+//!
+//! ```rust
+#![doc = include_str!("../tests/37_color.rs")]
+//! ```
+//!
+//! * `sep`
+//!
+//! Configure the separator of elements for tuple or array option, such as `sep = 'x'` for `800x600`.
+//! The default separator is `,`, the hint of option is joined with the first separator.
+//!
+//! ```rust
+#![doc = include_str!("../tests/25_tuple.rs")]
+//! ```
+//!
 //! #### `sub`
 //!
 //!| name      | need value | available value |
@@ -1,10 +1,14 @@
 use aopt::opt::Opt;
+use aopt::opt::OptGroup;
 use aopt::opt::Style;
 use aopt::set::Set;
+use aopt::value::ColorChoice;
+use aopt::value::ErrorFormat;
 use aopt::Error;
 use aopt_help::block::Block;
 use aopt_help::store::Store;
 use std::borrow::Cow;
+use std::io::Write;
 
 pub const DEFAULT_OPTION_WIDTH: usize = 40;
 pub const DEFAULT_USAGE_WIDTH: usize = 10;
@@ -94,6 +98,78 @@ impl HelpContext {
     }
 }
 
+/// Return the help message of option, the constraints are appended such as
+/// `Output as json [conflicts with --table]`.
+pub fn help_with_constraints<O: Opt>(opt: &O) -> Cow<'_, str> {
+    let constraints = opt.constraints();
+
+    if constraints.is_empty() {
+        Cow::from(opt.help())
+    } else {
+        let rules = constraints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if opt.help().is_empty() {
+            Cow::from(format!("[{rules}]"))
+        } else {
+            Cow::from(format!("{} [{rules}]", opt.help()))
+        }
+    }
+}
+
+/// Return the help message of option with the constraints and environment variable,
+/// such as `Access token [required unless --anonymous] [env: MYTOOL_TOKEN]`.
+pub fn help_with_details<O: Opt>(opt: &O) -> Cow<'_, str> {
+    match opt.env() {
+        Some(env) => {
+            let help = help_with_constraints(opt);
+
+            if help.is_empty() {
+                Cow::from(format!("[env: {env}]"))
+            } else {
+                Cow::from(format!("{help} [env: {env}]"))
+            }
+        }
+        None => help_with_constraints(opt),
+    }
+}
+
+/// Return the hint of option, `...` is appended if the option can be repeated to collect values,
+/// such as `--feature <str>...` for option with [`Action::App`](crate::prelude::Action::App).
+pub fn hint_with_action<O: Opt>(opt: &O) -> Cow<'_, str> {
+    let act = opt.action();
+
+    if act.is_app() || act.is_app_uniq() || act.is_ext() {
+        Cow::from(format!("{}...", opt.hint()))
+    } else {
+        Cow::from(opt.hint())
+    }
+}
+
+/// Create the help [`Store`] of option, the default value is displayed if the initializer has a display string.
+fn store_of<O: Opt>(opt: &O, position: bool) -> Store<'_> {
+    let mut store = Store::new(
+        Cow::from(opt.name()),
+        hint_with_action(opt),
+        help_with_details(opt),
+        Cow::default(),
+        !opt.force(),
+        position,
+    );
+
+    if let Some(display) = opt.accessor().initializer().display() {
+        store.set_default_value(display);
+    }
+    store
+}
+
+fn group_block_name(group: &OptGroup) -> String {
+    format!("group.{}", group.name())
+}
+
 pub fn display_set_help<'a, T: Set>(
     set: &T,
     name: impl Into<Cow<'a, str>>,
@@ -101,13 +177,56 @@ pub fn display_set_help<'a, T: Set>(
     foot: impl Into<Cow<'a, str>>,
     max_width: usize,
     usage_width: usize,
+) -> Result<(), aopt_help::Error> {
+    let color = color_choice(set).resolve();
+
+    write_help_with(
+        set,
+        std::io::stdout(),
+        color,
+        name,
+        head,
+        foot,
+        max_width,
+        usage_width,
+    )
+}
+
+/// Write the help message of `set` to `writer`, see also [`display_set_help`].
+///
+/// The `writer` is not treated as a terminal,
+/// so the [`Auto`](ColorChoice::Auto) choice enables colors only if they are forced by environment.
+pub fn write_set_help<'a, T: Set, W: Write>(
+    set: &T,
+    writer: W,
+    name: impl Into<Cow<'a, str>>,
+    head: impl Into<Cow<'a, str>>,
+    foot: impl Into<Cow<'a, str>>,
+    max_width: usize,
+    usage_width: usize,
+) -> Result<(), aopt_help::Error> {
+    let color = color_choice(set).resolve_with(false);
+
+    write_help_with(set, writer, color, name, head, foot, max_width, usage_width)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_help_with<'a, T: Set, W: Write>(
+    set: &T,
+    writer: W,
+    color: bool,
+    name: impl Into<Cow<'a, str>>,
+    head: impl Into<Cow<'a, str>>,
+    foot: impl Into<Cow<'a, str>>,
+    max_width: usize,
+    usage_width: usize,
 ) -> Result<(), aopt_help::Error> {
     let mut app_help = aopt_help::AppHelp::new(
         name.into(),
         head.into(),
         foot.into(),
-        aopt_help::prelude::Style::default(),
-        std::io::stdout(),
+        aopt_help::prelude::Style::default().with_color(color),
+        writer,
         max_width,
         usage_width,
     );
@@ -115,47 +234,42 @@ pub fn display_set_help<'a, T: Set>(
 
     global.add_block(Block::new("command", "<COMMAND>", "", "Commands:", ""))?;
     global.add_block(Block::new("option", "", "", "Options:", ""))?;
+    for group in set.groups() {
+        let head = format!("{} ({}):", group.name(), group.rule());
+        let mut block = Block::new(
+            group_block_name(group),
+            String::new(),
+            String::new(),
+            head,
+            String::new(),
+        );
+
+        block.set_usage(group.usage());
+        global.add_block(block)?;
+    }
     global.add_block(Block::new("args", "[ARGS]", "", "Args:", ""))?;
     for opt in set.iter() {
         if opt.mat_style(Style::Pos) {
-            global.add_store(
-                "args",
-                Store::new(
-                    Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
-                    Cow::default(),
-                    !opt.force(),
-                    true,
-                ),
-            )?;
+            global.add_store("args", store_of(opt, true))?;
         } else if opt.mat_style(Style::Cmd) {
-            global.add_store(
-                "command",
-                Store::new(
-                    Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
-                    Cow::default(),
-                    !opt.force(),
-                    true,
-                ),
-            )?;
+            global.add_store("command", store_of(opt, true))?;
         } else if opt.mat_style(Style::Argument)
             || opt.mat_style(Style::Boolean)
             || opt.mat_style(Style::Combined)
             || opt.mat_style(Style::Flag)
         {
+            let group = set.groups().iter().find(|group| {
+                group
+                    .members()
+                    .iter()
+                    .any(|v| opt.mat_name(Some(v)) || opt.mat_alias(v))
+            });
+
             global.add_store(
-                "option",
-                Store::new(
-                    Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
-                    Cow::default(),
-                    !opt.force(),
-                    false,
-                ),
+                group
+                    .map(group_block_name)
+                    .unwrap_or_else(|| "option".to_owned()),
+                store_of(opt, false),
             )?;
         }
     }
@@ -165,6 +279,91 @@ pub fn display_set_help<'a, T: Set>(
     Ok(())
 }
 
+/// Return the value of first [`ColorChoice`] option in `set`, or [`Auto`](ColorChoice::Auto) if no such option.
+pub fn color_choice<T: Set>(set: &T) -> ColorChoice {
+    set.iter()
+        .find(|opt| opt.r#type() == &std::any::TypeId::of::<ColorChoice>())
+        .and_then(|opt| opt.accessor().val::<ColorChoice>().ok())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Return the value of first [`ErrorFormat`] option in `set`, or [`Text`](ErrorFormat::Text) if no such option.
+pub fn error_format<T: Set>(set: &T) -> ErrorFormat {
+    set.iter()
+        .find(|opt| opt.r#type() == &std::any::TypeId::of::<ErrorFormat>())
+        .and_then(|opt| opt.accessor().val::<ErrorFormat>().ok())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Return the message of error and its causes, such as `error: ...` following by lines of `  caused by: ...`.
+/// The `error:` and `caused by:` are decorated with ANSI escape sequences if `color` is true.
+pub fn error_message(err: &Error, color: bool) -> String {
+    let paint = |code: &str, text: &str| {
+        if color {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_owned()
+        }
+    };
+    let mut ret = format!("{} {err}", paint("1;31", "error:"));
+    let mut cause = err.caused_by();
+
+    while let Some(err) = cause {
+        ret += &format!("\n  {} {err}", paint("1;33", "caused by:"));
+        cause = err.caused_by();
+    }
+    ret
+}
+
+/// Return the error serialized in JSON, such as `{"kind":"RawValParse","failure":true,"message":...}`.
+/// The option name, argument index and value, hints and suggestions are included,
+/// and the `cause` is serialized recursively.
+#[cfg(feature = "json")]
+pub fn error_json(err: &Error) -> Result<String, Error> {
+    serde_json::to_string(err).map_err(|e| aopt::error!("can not serialize error: {e}"))
+}
+
+/// The exit code when a failure raised, such as invalid value or missing option.
+pub const EXIT_FAILURE: i32 = 2;
+
+/// The exit code when other error raised.
+pub const EXIT_ERROR: i32 = 1;
+
+/// Return [`EXIT_FAILURE`] if the error is a [`failure`](Error::is_failure), otherwise [`EXIT_ERROR`].
+pub fn exit_code(err: &Error) -> i32 {
+    if err.is_failure() {
+        EXIT_FAILURE
+    } else {
+        EXIT_ERROR
+    }
+}
+
+/// Print the error to stderr in `format`, such as the value returned from [`error_format`],
+/// the colors are controlled by `color`, such as the value returned from [`color_choice`].
+/// The `json` format is available with feature `json`, otherwise the text is printed.
+pub fn display_error(err: &Error, format: ErrorFormat, color: ColorChoice) {
+    #[cfg(feature = "json")]
+    if format.is_json() {
+        if let Ok(json) = error_json(err) {
+            eprintln!("{json}");
+            return;
+        }
+    }
+    #[cfg(not(feature = "json"))]
+    let _ = format;
+    let color = color.resolve_for(&std::io::stderr());
+
+    eprintln!("{}", error_message(err, color));
+}
+
+/// Print the error by [`display_error`] and exit the process with [`exit_code`].
+pub fn exit_with_error(err: &Error, format: ErrorFormat, color: ColorChoice) -> ! {
+    display_error(err, format, color);
+    std::process::exit(exit_code(err))
+}
+
 pub trait HelpDisplay<S: Set> {
     type Error: Into<Error>;
 
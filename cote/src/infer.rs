@@ -32,6 +32,24 @@ impl InferOverride for bool {
     }
 }
 
+impl InferOverride for crate::aopt::value::Verbosity {
+    fn infer_force() -> bool {
+        false
+    }
+}
+
+impl InferOverride for crate::aopt::value::ColorChoice {
+    fn infer_force() -> bool {
+        false
+    }
+}
+
+impl InferOverride for crate::aopt::value::ErrorFormat {
+    fn infer_force() -> bool {
+        false
+    }
+}
+
 infer_override!(crate::prelude::Cmd);
 infer_override!(Stdin);
 infer_override!(crate::aopt::value::Stop);
@@ -96,9 +114,16 @@ infer_override!(usize);
 infer_override!(String);
 infer_override!(PathBuf);
 infer_override!(OsString);
+infer_override!(crate::aopt::value::InputFile);
+infer_override!(crate::aopt::value::OutputFile);
 
 infer_override!(());
 
+impl<T1, T2> InferOverride for (T1, T2) {}
+impl<T1, T2, T3> InferOverride for (T1, T2, T3) {}
+impl<T1, T2, T3, T4> InferOverride for (T1, T2, T3, T4) {}
+impl<T, const N: usize> InferOverride for [T; N] {}
+
 impl<T> InferOverride for Option<T> {
     fn infer_force() -> bool {
         false
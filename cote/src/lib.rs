@@ -32,32 +32,50 @@ pub mod prelude {
     pub use aopt::opt::Pos;
     pub use aopt::parser::UserStyle;
     pub use aopt::prelude::ctor_default_name;
+    pub use aopt::prelude::extract_handler;
     pub use aopt::prelude::AOpt;
     pub use aopt::prelude::ARef;
     pub use aopt::prelude::Action;
     pub use aopt::prelude::AppServices;
     pub use aopt::prelude::AppStorage;
     pub use aopt::prelude::Args;
+    pub use aopt::prelude::AsyncHandlerCollection;
+    pub use aopt::prelude::BoolLiteral;
+    pub use aopt::prelude::ChannelStore;
+    pub use aopt::prelude::ColorChoice;
     pub use aopt::prelude::Commit;
     pub use aopt::prelude::ConfigBuild;
     pub use aopt::prelude::ConfigBuildInfer;
     pub use aopt::prelude::ConfigBuildWith;
     pub use aopt::prelude::ConfigBuilder;
     pub use aopt::prelude::ConfigBuilderWith;
+    pub use aopt::prelude::ConfigLayer;
     pub use aopt::prelude::ConfigValue;
+    pub use aopt::prelude::Constraint;
     pub use aopt::prelude::Ctor;
     pub use aopt::prelude::Ctx;
     pub use aopt::prelude::DefaultSetChecker;
+    pub use aopt::prelude::Duplicate;
     pub use aopt::prelude::ErasedTy;
     pub use aopt::prelude::ErasedValue;
+    pub use aopt::prelude::ErrorFormat;
+    pub use aopt::prelude::Extract;
     pub use aopt::prelude::FilterMatcher;
     pub use aopt::prelude::HandlerCollection;
     pub use aopt::prelude::Index;
     pub use aopt::prelude::Infer;
     pub use aopt::prelude::Information;
     pub use aopt::prelude::InitializeValue;
+    pub use aopt::prelude::InsertStore;
     pub use aopt::prelude::Invoker;
+    pub use aopt::prelude::KeyedStore;
+    pub use aopt::prelude::MapStore;
+    pub use aopt::prelude::Middleware;
+    pub use aopt::prelude::MutexStore;
+    pub use aopt::prelude::Occurrence;
     pub use aopt::prelude::Opt;
+    pub use aopt::prelude::OptGroup;
+    pub use aopt::prelude::OptName;
     pub use aopt::prelude::OptParser;
     pub use aopt::prelude::OptValidator;
     pub use aopt::prelude::OptValueExt;
@@ -79,8 +97,15 @@ pub mod prelude {
     pub use aopt::prelude::ValInitializer;
     pub use aopt::prelude::ValStorer;
     pub use aopt::prelude::ValValidator;
+    pub use aopt::prelude::ValueSource;
     pub use aopt::prelude::VecStore;
+    pub use aopt::prelude::Verbosity;
     pub use aopt::value::raw2str;
+    pub use aopt::value::ElemParser;
+    pub use aopt::value::ElemSeparator;
+    pub use aopt::value::InputFile;
+    pub use aopt::value::OutputFile;
+    pub use aopt::value::OutputMode;
     pub use aopt::value::Placeholder;
     pub use aopt::value::Stop;
     pub use aopt::GetoptRes;
@@ -89,11 +114,25 @@ pub mod prelude {
     pub use cote_derive::CoteOpt;
     pub use cote_derive::CoteVal;
 
+    pub use crate::help::color_choice;
+    pub use crate::help::display_error;
     pub use crate::help::display_set_help;
+    pub use crate::help::error_format;
+    #[cfg(feature = "json")]
+    pub use crate::help::error_json;
+    pub use crate::help::error_message;
+    pub use crate::help::exit_code;
+    pub use crate::help::exit_with_error;
+    pub use crate::help::help_with_constraints;
+    pub use crate::help::help_with_details;
+    pub use crate::help::hint_with_action;
+    pub use crate::help::write_set_help;
     pub use crate::help::HelpContext;
     pub use crate::help::HelpDisplay;
     pub use crate::help::DEFAULT_OPTION_WIDTH;
     pub use crate::help::DEFAULT_USAGE_WIDTH;
+    pub use crate::help::EXIT_ERROR;
+    pub use crate::help::EXIT_FAILURE;
     pub use crate::infer::InferOverride;
     pub use crate::meta::OptionMeta;
     pub use crate::parser::Parser;
@@ -2,18 +2,24 @@ use std::borrow::Cow;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use aopt::ctx::AsyncHandlerEntry;
+#[cfg(feature = "sync")]
+use aopt::ctx::BoxedFuture;
 use aopt::ctx::Ctx;
 use aopt::ctx::HandlerEntry;
 use aopt::error;
 use aopt::parser::AppServices;
 use aopt::parser::AppStorage;
 use aopt::prelude::Args;
+use aopt::prelude::BoolLiteral;
 use aopt::prelude::ConfigBuild;
+use aopt::prelude::ConfigLayer;
 use aopt::prelude::ConfigValue;
 use aopt::prelude::ErasedTy;
 use aopt::prelude::Information;
 use aopt::prelude::Invoker;
 use aopt::prelude::Opt;
+use aopt::prelude::OptGroup;
 use aopt::prelude::OptParser;
 use aopt::prelude::OptValidator;
 use aopt::prelude::Policy;
@@ -66,6 +72,9 @@ pub struct Parser<'a, S> {
     app_ser: AppServices,
     inv: Option<Invoker<'a, Self>>,
     sub_parsers: Vec<Self>,
+    groups: Vec<OptGroup>,
+    layers: Vec<ConfigLayer>,
+    literal: Option<BoolLiteral>,
 }
 
 impl<S> Default for Parser<'_, S>
@@ -80,6 +89,9 @@ where
             app_ser: AppServices::default(),
             inv: Some(Invoker::default()),
             sub_parsers: Default::default(),
+            groups: vec![],
+            layers: vec![],
+            literal: None,
         }
     }
 }
@@ -93,6 +105,9 @@ impl<'a, S> Parser<'a, S> {
             app_ser: AppServices::default(),
             inv: None,
             sub_parsers: vec![],
+            groups: vec![],
+            layers: vec![],
+            literal: None,
         }
     }
 
@@ -136,6 +151,36 @@ impl<'a, S> Parser<'a, S> {
         self
     }
 
+    /// Register an [`OptGroup`], it will be checked in
+    /// [`post_check`](aopt::set::SetChecker::post_check).
+    pub fn add_group(&mut self, group: OptGroup) -> &mut Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Register a [`ConfigLayer`], the values of it are used if option not set
+    /// in command line or environment variable.
+    /// The sub layer is registered to the sub parser which has same name.
+    pub fn add_layer(&mut self, layer: ConfigLayer) -> &mut Self {
+        for parser in self.sub_parsers.iter_mut() {
+            if let Some(sub) = layer.sub(parser.name()) {
+                parser.add_layer(sub.clone());
+            }
+        }
+        self.layers.push(layer);
+        self
+    }
+
+    /// Set the [`BoolLiteral`] used for parsing [`bool`] values,
+    /// it is also set to the sub parsers.
+    pub fn set_bool_literal(&mut self, literal: BoolLiteral) -> &mut Self {
+        for parser in self.sub_parsers.iter_mut() {
+            parser.set_bool_literal(literal.clone());
+        }
+        self.literal = Some(literal);
+        self
+    }
+
     #[doc(hidden)]
     pub fn transfer_appser_to_subparser(&mut self, index: usize) {
         let appser = std::mem::take(&mut self.app_ser);
@@ -214,7 +259,15 @@ impl<'a, S> Parser<'a, S> {
             .ok_or_else(|| aopt::error!("can not find parser named {}", name))
     }
 
-    pub fn add_parser(&mut self, parser: Self) -> &mut Self {
+    pub fn add_parser(&mut self, mut parser: Self) -> &mut Self {
+        for layer in self.layers.iter() {
+            if let Some(sub) = layer.sub(parser.name()) {
+                parser.add_layer(sub.clone());
+            }
+        }
+        if let (Some(literal), None) = (&self.literal, &parser.literal) {
+            parser.set_bool_literal(literal.clone());
+        }
         self.sub_parsers.push(parser);
         self
     }
@@ -290,6 +343,50 @@ where
     {
         Ok(HandlerEntry::new(self.inv.as_mut().unwrap(), uid))
     }
+
+    #[cfg(feature = "sync")]
+    #[allow(clippy::type_complexity)]
+    /// Register an async handler of option `uid`, it is awaited by
+    /// [`parse_policy_async`](aopt::parser::PolicyParser::parse_policy_async) when the option matched.
+    /// The handler return a `Send` [`BoxedFuture`], such as `|set, ctx| Box::pin(async move { .. })`.
+    pub fn entry_async<O, H>(
+        &mut self,
+        uid: Uid,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, '_, Invoker<'a, Self>, Self, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: for<'x> FnMut(&'x mut Self, &'x mut Ctx) -> BoxedFuture<'x, Result<Option<O>, Error>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        Ok(AsyncHandlerEntry::new(
+            self.inv.as_mut().unwrap(),
+            uid,
+            handler,
+        ))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    #[allow(clippy::type_complexity)]
+    /// Register an async handler of option `uid`, it is awaited by
+    /// [`parse_policy_async`](aopt::parser::PolicyParser::parse_policy_async) when the option matched.
+    pub fn entry_async<O, H>(
+        &mut self,
+        uid: Uid,
+        handler: H,
+    ) -> Result<AsyncHandlerEntry<'a, '_, Invoker<'a, Self>, Self, H, O>, Error>
+    where
+        O: ErasedTy,
+        H: AsyncFnMut(&mut Self, &mut Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        Ok(AsyncHandlerEntry::new(
+            self.inv.as_mut().unwrap(),
+            uid,
+            handler,
+        ))
+    }
 }
 
 impl<'a, 'b, S> Parser<'a, S>
@@ -345,6 +442,18 @@ where
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         S::insert(&mut self.set, opt)
     }
+
+    fn groups(&self) -> &[OptGroup] {
+        &self.groups
+    }
+
+    fn layers(&self) -> &[ConfigLayer] {
+        &self.layers
+    }
+
+    fn bool_literal(&self) -> Option<&BoolLiteral> {
+        self.literal.as_ref()
+    }
 }
 
 impl<S> OptParser for Parser<'_, S>
@@ -435,6 +544,30 @@ where
 
         ret
     }
+
+    async fn parse_policy_async(
+        &mut self,
+        args: Args,
+        policy: &mut P,
+    ) -> Result<<P as Policy>::Ret, Self::Error> {
+        assert!(self.inv.is_some());
+
+        self.init()?;
+
+        let mut inv = self.inv.take().unwrap();
+
+        inv.set_allow_async(true);
+
+        let ret = policy
+            .parse_async(self, &mut inv, args)
+            .await
+            .map_err(Into::into);
+
+        inv.set_allow_async(false);
+        self.inv = Some(inv);
+
+        ret
+    }
 }
 
 impl<S> AppStorage for Parser<'_, S> {
@@ -453,6 +586,9 @@ impl<S> AppStorage for Parser<'_, S> {
     fn take_app_data<T: ErasedTy>(&mut self) -> Result<T, Error> {
         AppStorage::take_app_data(&mut self.app_ser)
     }
+    fn set_cloneable_app_data<T: ErasedTy + Clone>(&mut self, val: T) -> Option<T> {
+        AppStorage::set_cloneable_app_data(&mut self.app_ser, val)
+    }
 }
 
 impl<'a, S> Parser<'a, S>
@@ -516,7 +652,8 @@ where
         self.run_mut_with(Args::from_env(), policy, r)
     }
 
-    /// Running async function after parsing.
+    /// Running async function after parsing, the async handlers registered by
+    /// [`on_async`](aopt::parser::ParserCommit::on_async) are awaited when parsing.
     ///
     /// # Example
     ///
@@ -559,7 +696,7 @@ where
         F: AsyncFnMut(P::Ret, &mut Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self>>,
     {
-        match self.parse_policy(args.into(), policy) {
+        match self.parse_policy_async(args.into(), policy).await {
             Ok(ret) => r(ret, self).await,
             Err(e) => Err(e),
         }
@@ -629,7 +766,8 @@ where
         self.run_with(Args::from_env(), policy, r)
     }
 
-    /// Running async function after parsing.
+    /// Running async function after parsing, the async handlers registered by
+    /// [`on_async`](aopt::parser::ParserCommit::on_async) are awaited when parsing.
     ///
     /// # Example
     ///
@@ -672,7 +810,7 @@ where
         F: AsyncFnMut(P::Ret, &Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self>>,
     {
-        match self.parse_policy(args.into(), policy) {
+        match self.parse_policy_async(args.into(), policy).await {
             Ok(ret) => r(ret, self).await,
             Err(e) => Err(e),
         }
@@ -692,14 +830,13 @@ impl<S: Set> HelpDisplay<S> for Parser<'_, S> {
     type Error = crate::Error;
 
     fn display(&self, ctx: HelpContext) -> Result<(), Self::Error> {
-        let set = self.optset();
         let name = ctx.name();
         let head = ctx.head();
         let foot = ctx.foot();
         let max_width = ctx.width();
         let usage_width = ctx.usagew();
 
-        crate::help::display_set_help(set, name, head, foot, max_width, usage_width)
+        crate::help::display_set_help(self, name, head, foot, max_width, usage_width)
             .map_err(|e| aopt::error!("Can not show help message: {:?}", e))
     }
 
@@ -728,7 +865,6 @@ where
             if let Some(name) = names.get(i) {
                 if i == max && (i > 0 || name == self.name()) {
                     let name = names.join(" ");
-                    let optset = self.optset();
 
                     return {
                         let head = ctx.head();
@@ -737,7 +873,7 @@ where
                         let usage_width = ctx.usagew();
 
                         crate::help::display_set_help(
-                            optset,
+                            self,
                             name,
                             head,
                             foot,
@@ -769,6 +905,7 @@ where
 pub(crate) mod shell {
     use std::borrow::Cow;
 
+    use aopt::prelude::BoolLiteral;
     use aopt::prelude::ConfigValue;
     use aopt::prelude::Opt;
     use aopt::prelude::OptValidator;
@@ -778,9 +915,11 @@ pub(crate) mod shell {
     use aopt::prelude::SetValueFindExt;
     use aopt::prelude::Style;
     use aopt::shell::shell;
-    use aopt::shell::shell::complete_eq;
+    use aopt::shell::shell::complete_eq_with;
     use aopt::shell::shell::complete_opt;
     use aopt::shell::shell::complete_val;
+    use aopt::shell::shell::is_conflict;
+    use aopt::shell::shell::used_opts;
     use aopt::shell::shell::Complete;
     use aopt::shell::shell::Shell;
     use aopt::shell::value::Values;
@@ -1003,9 +1142,16 @@ pub(crate) mod shell {
                     let optset = manager.parser();
                     let values = manager.values();
 
-                    complete_eq(arg, bytes, optset.iter(), values, |name, val, opt| {
-                        s.write_eq(name, val, opt)
-                    })?;
+                    let literal = optset.bool_literal().unwrap_or(BoolLiteral::default_ref());
+
+                    complete_eq_with(
+                        arg,
+                        bytes,
+                        optset.iter(),
+                        values,
+                        literal,
+                        |name, val, opt| s.write_eq(name, val, opt),
+                    )?;
                 }
             }
 
@@ -1048,8 +1194,14 @@ pub(crate) mod shell {
                     .map(|v| v.parser())
                     .filter(|v| v.split(&Cow::Borrowed(arg)).is_ok())
                 {
-                    found_opt = found_opt
-                        || complete_opt(arg, p.iter(), |name, opt| s.write_opt(name, opt))?;
+                    // skip the options conflict with the options already set
+                    let used = used_opts(&args[..args.len().min(*cword)], p.iter());
+                    let opts = p
+                        .iter()
+                        .filter(|opt| !used.iter().any(|used| is_conflict(*opt, *used)));
+
+                    found_opt =
+                        found_opt || complete_opt(arg, opts, |name, opt| s.write_opt(name, opt))?;
                 }
             }
 
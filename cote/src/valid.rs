@@ -1,118 +1,222 @@
+use std::fmt::Display;
 use std::marker::PhantomData;
 use std::ops::RangeBounds;
 
 use aopt::prelude::ErasedTy;
+use aopt::value::CheckHandler;
+use aopt::value::CheckResult;
+use aopt::value::ValHint;
 use aopt::value::ValValidator;
-use aopt::value::ValidatorHandler;
+use aopt::value::DEFAULT_CHECK_MESSAGE;
 
 pub trait Validate<T>
 where
     T: ErasedTy,
 {
     fn check(&self, value: &T) -> bool;
+
+    /// Check the value, return the reason if the value is rejected.
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.check(value).into_result()
+    }
+
+    /// The hint of value accepted, it is used in help message and completion.
+    fn hint(&self) -> Option<ValHint> {
+        None
+    }
 }
 
-pub struct Value<K>(K);
+macro_rules! impl_with_msg {
+    ($name:ident < $($param:ident),+ >) => {
+        impl_with_msg!([$($param),+] $name<$($param),+>);
+    };
+
+    ([$($gen:tt)+] $ty:ty) => {
+        impl<$($gen)+> $ty {
+            /// Set the reason when the value is rejected.
+            pub fn with_msg(mut self, msg: impl Into<String>) -> Self {
+                self.msg = Some(msg.into());
+                self
+            }
+
+            fn reason(&self, valid: bool, default: impl FnOnce() -> String) -> Result<(), String> {
+                if valid {
+                    Ok(())
+                } else {
+                    Err(self.msg.clone().unwrap_or_else(default))
+                }
+            }
+        }
+    };
+}
+
+pub struct Value<K> {
+    value: K,
+
+    msg: Option<String>,
+}
 
 impl<K> Value<K> {
     pub fn new(value: K) -> Self {
-        Self(value)
+        Self { value, msg: None }
     }
 }
 
+impl_with_msg!(Value<K>);
+
 impl<T, K> Validate<T> for Value<K>
 where
     T: ErasedTy,
     K: ErasedTy + PartialEq<T>,
 {
     fn check(&self, value: &T) -> bool {
-        &self.0 == value
+        &self.value == value
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || DEFAULT_CHECK_MESSAGE.to_owned())
     }
 }
 
-pub struct GreaterEqual<K>(K);
+pub struct GreaterEqual<K> {
+    value: K,
+
+    msg: Option<String>,
+}
 
 impl<K> GreaterEqual<K> {
     pub fn new(value: K) -> Self {
-        Self(value)
+        Self { value, msg: None }
     }
 }
 
+impl_with_msg!(GreaterEqual<K>);
+
 impl<T, K> Validate<T> for GreaterEqual<K>
 where
-    T: ErasedTy,
-    K: ErasedTy + PartialOrd<T>,
+    T: ErasedTy + PartialOrd<K>,
+    K: ErasedTy + Display,
 {
     fn check(&self, value: &T) -> bool {
-        &self.0 >= value
+        value >= &self.value
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || {
+            format!("must be greater than or equal to {}", self.value)
+        })
     }
 }
 
-pub struct LessEqual<K>(K);
+pub struct LessEqual<K> {
+    value: K,
+
+    msg: Option<String>,
+}
 
 impl<K> LessEqual<K> {
     pub fn new(value: K) -> Self {
-        Self(value)
+        Self { value, msg: None }
     }
 }
 
+impl_with_msg!(LessEqual<K>);
+
 impl<T, K> Validate<T> for LessEqual<K>
 where
-    T: ErasedTy,
-    K: ErasedTy + PartialOrd<T>,
+    T: ErasedTy + PartialOrd<K>,
+    K: ErasedTy + Display,
 {
     fn check(&self, value: &T) -> bool {
-        &self.0 <= value
+        value <= &self.value
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || {
+            format!("must be less than or equal to {}", self.value)
+        })
     }
 }
 
-pub struct Array<const N: usize, K>([K; N]);
+pub struct Array<const N: usize, K> {
+    value: [K; N],
+
+    msg: Option<String>,
+}
 
 impl<const N: usize, K> Array<N, K> {
     pub fn new(value: [K; N]) -> Self {
-        Self(value)
+        Self { value, msg: None }
     }
 }
 
+impl_with_msg!([const N: usize, K] Array<N, K>);
+
 impl<const N: usize, T, K> Validate<T> for Array<N, K>
 where
     T: ErasedTy,
     K: ErasedTy + PartialEq<T>,
 {
     fn check(&self, value: &T) -> bool {
-        self.0.iter().any(|v| PartialEq::eq(v, value))
+        self.value.iter().any(|v| PartialEq::eq(v, value))
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || DEFAULT_CHECK_MESSAGE.to_owned())
     }
 }
 
-pub struct Vector<K>(Vec<K>);
+pub struct Vector<K> {
+    value: Vec<K>,
+
+    msg: Option<String>,
+}
 
 impl<K> Vector<K> {
     pub fn new(value: Vec<K>) -> Self {
-        Self(value)
+        Self { value, msg: None }
     }
 }
 
+impl_with_msg!(Vector<K>);
+
 impl<T, K> Validate<T> for Vector<K>
 where
     T: ErasedTy,
     K: ErasedTy + PartialEq<T>,
 {
     fn check(&self, value: &T) -> bool {
-        self.0.iter().any(|v| PartialEq::eq(v, value))
+        self.value.iter().any(|v| PartialEq::eq(v, value))
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || DEFAULT_CHECK_MESSAGE.to_owned())
     }
 }
 
-pub struct Range<K, R>(R, PhantomData<K>);
+pub struct Range<K, R> {
+    value: R,
+
+    msg: Option<String>,
+
+    marker: PhantomData<K>,
+}
 
 impl<K, R> Range<K, R>
 where
     R: RangeBounds<K> + ErasedTy,
 {
     pub fn new(value: R) -> Self {
-        Self(value, PhantomData)
+        Self {
+            value,
+            msg: None,
+            marker: PhantomData,
+        }
     }
 }
 
+impl_with_msg!(Range<K, R>);
+
 impl<T, K, R> Validate<T> for Range<K, R>
 where
     T: ErasedTy + PartialOrd<K>,
@@ -120,24 +224,29 @@ where
     R: RangeBounds<K> + ErasedTy,
 {
     fn check(&self, value: &T) -> bool {
-        self.0.contains(value)
+        self.value.contains(value)
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.reason(self.check(value), || DEFAULT_CHECK_MESSAGE.to_owned())
     }
 }
 
-pub struct Validator<T>(ValidatorHandler<T>);
+/// Validator using a function, the function can return a [`bool`] or a [`Result`] with reason.
+pub struct Validator<T>(CheckHandler<T>);
 
 impl<T> Validator<T>
 where
     T: ErasedTy,
 {
     #[cfg(feature = "sync")]
-    pub fn new(func: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+    pub fn new<R: CheckResult>(func: impl Fn(&T) -> R + Send + Sync + 'static) -> Self {
+        Self(Box::new(move |val| func(val).into_result()))
     }
 
     #[cfg(not(feature = "sync"))]
-    pub fn new(func: impl Fn(&T) -> bool + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+    pub fn new<R: CheckResult>(func: impl Fn(&T) -> R + 'static) -> Self {
+        Self(Box::new(move |val| func(val).into_result()))
     }
 }
 
@@ -146,6 +255,10 @@ where
     T: ErasedTy,
 {
     fn check(&self, value: &T) -> bool {
+        self.validate(value).is_ok()
+    }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
         (self.0)(value)
     }
 }
@@ -155,12 +268,44 @@ where
     T: ErasedTy,
 {
     fn from(value: Validator<T>) -> Self {
-        ValValidator::new(value.0)
+        ValValidator::new_check(value.0)
+    }
+}
+
+impl<T> Validate<T> for ValValidator<T>
+where
+    T: ErasedTy,
+{
+    fn check(&self, value: &T) -> bool {
+        self.invoke(value)
     }
+
+    fn validate(&self, value: &T) -> Result<(), String> {
+        ValValidator::check(self, value)
+    }
+
+    fn hint(&self) -> Option<ValHint> {
+        ValValidator::hint(self).cloned()
+    }
+}
+
+/// Create a validator accept the string matched the regex `pattern`,
+/// return an error if the `pattern` is not a valid regex.
+#[cfg(feature = "regex")]
+pub fn regex<T: ErasedTy + AsRef<str>>(pattern: &str) -> Result<ValValidator<T>, aopt::Error> {
+    ValValidator::regex(pattern)
 }
 
 /// Check the value of option.
 ///
+/// Beside the literal, range and function, the built-in validators are also supported:
+/// `exists`, `file`, `dir`, `readable`, `writable`, `ext [..]`, `non_empty`,
+/// `len min..=max`, `one_of [..]`, `regex "pattern"` (requires feature `regex`) and `not ..`.
+/// The invalid pattern of `regex` is returned as error by `?`,
+/// so it can only be used in function return [`Result`], such as the `valid` of `arg`.
+/// They can be combined with [`and`](ValValidator::and) and [`or`](ValValidator::or),
+/// the hint of built-in validator such as `<DIR>` is used in help message and completion.
+///
 /// # Example
 /// ```rust
 /// # use cote::prelude::*;
@@ -170,6 +315,9 @@ where
 /// pub struct Cli {
 ///     #[arg(alias = "-v", valid = valid!(42))]
 ///     value: u64,
+///
+///     #[arg(alias = "-c", valid = valid!(file).and(valid!(ext ["toml"])))]
+///     config: Option<std::path::PathBuf>,
 /// }
 ///
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -178,49 +326,73 @@ where
 ///
 ///     assert!(Cli::parse(Args::from(["app", "-v42"])).is_ok());
 ///
+///     assert!(Cli::parse(Args::from(["app", "-v42", "-c", "Cargo.toml"])).is_ok());
+///
+///     assert!(Cli::parse(Args::from(["app", "-v42", "-c", "src"])).is_err());
+///
 ///     Ok(())
 /// }
 /// ```
 #[macro_export]
 macro_rules! valid {
     ($value:literal) => {
-        $crate::valid::Value::new($value)
+        $crate::valid::Value::new($value).with_msg(concat!("must be ", stringify!($value)))
     };
 
     ([$($value:literal),+]) => {
         $crate::valid::Array::new([$($value),+])
+            .with_msg(concat!("must be one of ", stringify!($($value),+)))
     };
 
     (vec![$($value:literal),+]) => {
         $crate::valid::Vector::new(vec![$($value),+])
+            .with_msg(concat!("must be one of ", stringify!($($value),+)))
     };
 
     ($start:literal .. $end:literal) => {
-        $crate::valid::Range::new($start .. $end)
+        $crate::valid::Range::new($start .. $end).with_msg(concat!(
+            "must be greater than or equal to ",
+            stringify!($start),
+            " and less than ",
+            stringify!($end)
+        ))
     };
 
     ($start:literal ..) => {
         $crate::valid::Range::new($start ..)
+            .with_msg(concat!("must be greater than or equal to ", stringify!($start)))
     };
 
     ($start:literal ..= $end:literal) => {
-        $crate::valid::Range::new($start ..= $end)
+        $crate::valid::Range::new($start ..= $end).with_msg(concat!(
+            "must be between ",
+            stringify!($start),
+            " and ",
+            stringify!($end)
+        ))
     };
 
     (.. $end:literal) => {
-        $crate::valid::Range::new($start .. $end)
+        $crate::valid::Range::new(.. $end)
+            .with_msg(concat!("must be less than ", stringify!($end)))
     };
 
     (..= $end:literal) => {
-        $crate::valid::Range::new($start ..= $end)
+        $crate::valid::Range::new(..= $end)
+            .with_msg(concat!("must be less than or equal to ", stringify!($end)))
     };
 
     (> $value:literal) => {
-        $crate::valid::Range::new($value ..)
+        $crate::valid::Range::new((
+            std::ops::Bound::Excluded($value),
+            std::ops::Bound::Unbounded,
+        ))
+        .with_msg(concat!("must be greater than ", stringify!($value)))
     };
 
     (< $value:literal) => {
         $crate::valid::Range::new(.. $value)
+            .with_msg(concat!("must be less than ", stringify!($value)))
     };
 
     (>= $value:literal) => {
@@ -228,7 +400,51 @@ macro_rules! valid {
     };
 
     (<= $value:literal) => {
-        $crate::valid::Range::new(..= $value)
+        $crate::valid::LessEqual::new($value)
+    };
+
+    (exists) => {
+        $crate::prelude::ValValidator::exists()
+    };
+
+    (file) => {
+        $crate::prelude::ValValidator::is_file()
+    };
+
+    (dir) => {
+        $crate::prelude::ValValidator::is_dir()
+    };
+
+    (readable) => {
+        $crate::prelude::ValValidator::readable()
+    };
+
+    (writable) => {
+        $crate::prelude::ValValidator::writable()
+    };
+
+    (ext [$($ext:literal),+]) => {
+        $crate::prelude::ValValidator::extension([$($ext),+])
+    };
+
+    (non_empty) => {
+        $crate::prelude::ValValidator::non_empty()
+    };
+
+    (len $min:literal ..= $max:literal) => {
+        $crate::prelude::ValValidator::len_range($min, $max)
+    };
+
+    (one_of [$($value:literal),+]) => {
+        $crate::prelude::ValValidator::one_of([$($value),+])
+    };
+
+    (regex $pattern:literal) => {
+        $crate::valid::regex($pattern)?
+    };
+
+    (not $($inner:tt)+) => {
+        $crate::valid!($($inner)+).not()
     };
 
     ($func:expr) => {
@@ -125,12 +125,54 @@ impl_fetch!(std::path::PathBuf);
 
 impl_fetch!(std::ffi::OsString);
 
+impl_fetch!(aopt::value::InputFile);
+
+impl_fetch!(aopt::value::OutputFile);
+
 impl_fetch!(std::io::Stdin);
 
 impl_fetch!(aopt::value::Stop);
 
+impl_fetch!(aopt::value::Verbosity);
+
+impl_fetch!(aopt::value::ColorChoice);
+
+impl_fetch!(aopt::value::ErrorFormat);
+
 impl_fetch!(crate::prelude::Cmd);
 
+impl<S, T1, T2> Fetch<S> for (T1, T2)
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+    Self: Infer + Sized,
+{
+}
+
+impl<S, T1, T2, T3> Fetch<S> for (T1, T2, T3)
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+    Self: Infer + Sized,
+{
+}
+
+impl<S, T1, T2, T3, T4> Fetch<S> for (T1, T2, T3, T4)
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+    Self: Infer + Sized,
+{
+}
+
+impl<S, T, const N: usize> Fetch<S> for [T; N]
+where
+    S: SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+    Self: Infer + Sized,
+{
+}
+
 impl<S, T: Infer + ErasedTy> Fetch<S> for crate::prelude::AnyOpt<T>
 where
     S: SetValueFindExt,
@@ -17,10 +17,17 @@ struct Cli {
     /// Enable IPV6 protocol
     ipv6: bool,
 
+    /// Enable IPV4 protocol
+    #[arg(conflicts = "--ipv6")]
+    ipv4: bool,
+
     /// Set http method
     #[arg(scvalues = ["PUT", "GET", "POST"])]
     method: Option<String>,
 
+    /// Set output file
+    output: Option<OutputFile>,
+
     /// Set url
     #[pos(scvalues = read_url_from_config())]
     url: String,
@@ -30,10 +37,46 @@ struct Cli {
     files: Vec<PathBuf>,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+#[test]
+fn completion() -> Result<(), Box<dyn std::error::Error>> {
     assert!(complete_shells(
         vec!["example", "--i"],
         1,
+        vec![
+            (
+                "fish",
+                vec![
+                    "--ipv6\t\"Enable IPV6 protocol\"",
+                    "--ipv4\t\"Enable IPV4 protocol\""
+                ]
+            ),
+            (
+                "zsh",
+                vec!["--ipv6:Enable IPV6 protocol", "--ipv4:Enable IPV4 protocol"]
+            ),
+            ("bash", vec!["--ipv6", "--ipv4"]),
+            (
+                "powershell",
+                vec![
+                    "--ipv6\tEnable IPV6 protocol",
+                    "--ipv4\tEnable IPV4 protocol"
+                ]
+            ),
+            (
+                "powershell7",
+                vec![
+                    "--ipv6\tEnable IPV6 protocol",
+                    "--ipv4\tEnable IPV4 protocol"
+                ]
+            )
+        ]
+    )
+    .is_ok());
+
+    // the conflicting option is hidden
+    assert!(complete_shells(
+        vec!["example", "--ipv6", "--i"],
+        2,
         vec![
             ("fish", vec!["--ipv6\t\"Enable IPV6 protocol\""]),
             ("zsh", vec!["--ipv6:Enable IPV6 protocol"]),
@@ -66,6 +109,70 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .is_ok());
 
+    assert!(complete_shells(
+        vec!["example", "--ipv6=o"],
+        1,
+        vec![
+            ("fish", vec!["--ipv6=on", "--ipv6=off"]),
+            ("zsh", vec!["--ipv6=on", "--ipv6=off"]),
+            ("bash", vec!["on", "off"]),
+            ("powershell", vec!["--ipv6=on", "--ipv6=off"]),
+            ("powershell7", vec!["--ipv6=on", "--ipv6=off"])
+        ]
+    )
+    .is_ok());
+
+    assert!(complete_shells(
+        vec!["example", "--output", "src/p"],
+        2,
+        vec![
+            ("fish", vec!["src/parser.rs"]),
+            ("zsh", vec!["src/parser.rs"]),
+            ("bash", vec!["src/parser.rs"]),
+            ("powershell", vec!["src/parser.rs"]),
+            ("powershell7", vec!["src/parser.rs"])
+        ]
+    )
+    .is_ok());
+
+    assert!(complete_shells(
+        vec!["example", "--output", "not-exist/p"],
+        2,
+        vec![
+            ("fish", Vec::<&str>::new()),
+            ("zsh", vec![]),
+            ("bash", vec![]),
+            ("powershell", vec![]),
+            ("powershell7", vec![])
+        ]
+    )
+    .is_ok());
+
+    assert!(complete_shells(
+        vec!["example", "--output=src/v"],
+        1,
+        vec![
+            (
+                "fish",
+                vec!["--output=src/valid.rs", "--output=src/value.rs"]
+            ),
+            (
+                "zsh",
+                vec!["--output=src/valid.rs", "--output=src/value.rs"]
+            ),
+            ("bash", vec!["src/valid.rs", "src/value.rs"]),
+            (
+                "powershell",
+                vec!["--output=src/valid.rs", "--output=src/value.rs"]
+            ),
+            (
+                "powershell7",
+                vec!["--output=src/valid.rs", "--output=src/value.rs"]
+            )
+        ]
+    )
+    .is_ok());
+
     assert!(complete_shells(
         vec!["example", "--ipv6", "--method", "PUT", ""],
         4,
@@ -0,0 +1,58 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-d")]
+    debug: bool,
+
+    #[arg(alias = "-v")]
+    verbose: bool,
+
+    #[arg(alias = "-c")]
+    color: bool,
+}
+
+#[derive(Debug, Cote)]
+#[cote(switch_literal(parser))]
+pub struct Switch {
+    #[arg(alias = "-d")]
+    debug: bool,
+
+    #[arg(alias = "-v")]
+    verbose: bool,
+}
+
+fn switch_literal<S>(parser: &mut Parser<'_, S>) -> Result<(), cote::Error> {
+    parser.set_bool_literal(
+        BoolLiteral::default()
+            .with_true("enable")
+            .with_false("disable"),
+    );
+    Ok(())
+}
+
+#[test]
+fn bool_literal() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app", "--debug=yes", "-v=On", "-c"]))?;
+
+    assert!(cli.debug);
+    assert!(cli.verbose);
+    assert!(cli.color);
+
+    let cli = Cli::parse(Args::from(["app", "--debug=no", "-v=0", "-c=false"]))?;
+
+    assert!(!cli.debug);
+    assert!(!cli.verbose);
+    assert!(!cli.color);
+    assert!(Cli::parse(Args::from(["app", "--debug=enable"])).is_err());
+
+    let switch = Switch::parse(Args::from(["app", "--debug=enable", "-v=DISABLE"]))?;
+
+    assert!(switch.debug);
+    assert!(!switch.verbose);
+
+    // the literal of `Switch` not affect other parsers
+    assert!(Cli::parse(Args::from(["app", "--debug=enable"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::io::Write;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-i")]
+    input: InputFile,
+
+    #[arg(alias = "-o")]
+    output: Option<OutputFile>,
+}
+
+#[test]
+fn file() -> color_eyre::Result<()> {
+    let dir = std::env::temp_dir();
+    let input = dir.join(format!("cote-input-{}.txt", std::process::id()));
+    let output = dir.join(format!("cote-output-{}.txt", std::process::id()));
+
+    std::fs::write(&input, "cote")?;
+
+    let cli = Cli::parse(Args::from(["app", "-i", "-"]))?;
+
+    assert!(cli.input.is_stdin());
+    assert_eq!(cli.output, None);
+
+    let cli = Cli::parse(Args::from([
+        "app".into(),
+        "-i".into(),
+        input.clone().into_os_string(),
+        "-o".into(),
+        output.clone().into_os_string(),
+    ]))?;
+
+    assert_eq!(cli.input.read_to_string()?, "cote");
+
+    let output_file = cli.output.unwrap().with_atomic(true);
+    let mut writer = output_file.open()?;
+
+    writer.write_all(b"aopt")?;
+    drop(writer);
+    assert!(!output.exists());
+
+    // the writers of same file using different temporary file
+    let mut first = output_file.open()?;
+    let mut second = output_file.open()?;
+
+    first.write_all(b"first")?;
+    second.write_all(b"second")?;
+    first.commit()?;
+    second.commit()?;
+    assert_eq!(std::fs::read_to_string(&output)?, "second");
+
+    let mut writer = output_file.open()?;
+
+    writer.write_all(b"aopt")?;
+    writer.commit()?;
+    assert_eq!(std::fs::read_to_string(&output)?, "aopt");
+
+    let mut writer = output_file.with_mode(OutputMode::Append).open()?;
+
+    writer.write_all(b"+cote")?;
+    writer.commit()?;
+    assert_eq!(std::fs::read_to_string(&output)?, "aopt+cote");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(&output, std::fs::Permissions::from_mode(0o600))?;
+
+        let mut writer = OutputFile::new(&output).with_atomic(true).open()?;
+
+        writer.write_all(b"mode")?;
+        writer.commit()?;
+        assert_eq!(std::fs::read_to_string(&output)?, "mode");
+        assert_eq!(
+            std::fs::metadata(&output)?.permissions().mode() & 0o777,
+            0o600
+        );
+    }
+
+    std::fs::remove_file(&input)?;
+    assert!(InputFile::new(&input).open().is_err());
+    assert!(Cli::parse(Args::from(["app", "-i", ""])).is_err());
+    std::fs::remove_file(&output)?;
+    Ok(())
+}
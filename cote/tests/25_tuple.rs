@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-s", sep = 'x')]
+    size: (u32, u32),
+
+    #[arg(alias = "-p")]
+    point: Option<[i64; 3]>,
+
+    #[arg(alias = "-c", sep = [',', ':'])]
+    color: Vec<(u8, u8, u8, f32)>,
+
+    #[pos()]
+    pair: (String, PathBuf),
+}
+
+#[test]
+fn tuple() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--size",
+        "800x600",
+        "-p",
+        "1,-2,3",
+        "-c=255,0,0,0.5",
+        "-c=0:0:255:1",
+        "config,cote.toml",
+    ]))?;
+
+    assert_eq!(cli.size, (800, 600));
+    assert_eq!(cli.point, Some([1, -2, 3]));
+    assert_eq!(cli.color, vec![(255, 0, 0, 0.5), (0, 0, 255, 1.0)]);
+    assert_eq!(
+        cli.pair,
+        (String::from("config"), PathBuf::from("cote.toml"))
+    );
+
+    let err = Cli::parse(Args::from(["app", "-s", "800xabc", "a,b"])).unwrap_err();
+
+    assert!(format!("{err:?}").contains("can not parse element 2 `abc` as INT"));
+    assert!(Cli::parse(Args::from(["app", "-s", "800", "a,b"])).is_err());
+    assert!(Cli::parse(Args::from(["app", "-s", "800,600", "a,b"])).is_err());
+    assert!(Cli::parse(Args::from(["app", "-s", "8x6", "-p", "1,2", "a,b"])).is_err());
+    // the `x` is not a separator of pair
+    assert!(Cli::parse(Args::from(["app", "-s", "8x6", "-c=1,2,3,0.5", "xml"])).is_err());
+    assert_eq!(
+        Cli::parse(Args::from([
+            "app",
+            "-s",
+            "8x6",
+            "-c=1,2,3,0.5",
+            "xml,box.txt"
+        ]))?
+        .pair,
+        (String::from("xml"), PathBuf::from("box.txt"))
+    );
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--size")?.hint(), "-s, --size <INT>x<INT>");
+    assert_eq!(
+        parser.find_opt("--point")?.hint(),
+        "-p, --point <INT>,<INT>,<INT>"
+    );
+    assert_eq!(
+        parser.find_opt("--color")?.hint(),
+        "-c, --color <INT>,<INT>,<INT>,<FLOAT>"
+    );
+    assert_eq!(parser.find_opt("pair")?.hint(), "pair@1 <STR>,<PATH>");
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-l", valid = valid!(5..=30))]
+    length: Option<u32>,
+
+    #[arg(alias = "-m", valid = valid!(["get", "put"]))]
+    method: Option<String>,
+
+    #[arg(alias = "-r", valid = valid!(>= 2))]
+    retry: Option<i64>,
+
+    #[arg(alias = "-t", valid = valid!(> 0.5))]
+    threshold: Option<f64>,
+
+    #[arg(alias = "-n", valid = valid!(|v: &String| {
+        if v.chars().all(char::is_alphanumeric) {
+            Ok(())
+        } else {
+            Err(format!("`{v}` contains invalid character"))
+        }
+    }))]
+    name: Option<String>,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn valid_message() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from([
+        "app", "-l=5", "-m=put", "-r=2", "-t=0.6", "-n=cote",
+    ]))?;
+
+    assert_eq!(cli.length, Some(5));
+    assert_eq!(cli.method.as_deref(), Some("put"));
+    assert_eq!(cli.retry, Some(2));
+    assert_eq!(cli.threshold, Some(0.6));
+    assert_eq!(cli.name.as_deref(), Some("cote"));
+
+    check_message(["app", "-l=42"], "must be between 5 and 30");
+    check_message(["app", "-m=post"], r#"must be one of \"get\", \"put\""#);
+    check_message(
+        ["app", "-r=1"],
+        "invalid value `1`: must be greater than or equal to 2",
+    );
+    check_message(["app", "-t=0.5"], "must be greater than 0.5");
+    check_message(["app", "-n=a-b"], "`a-b` contains invalid character");
+    Ok(())
+}
+
+#[test]
+fn valid_compare() -> color_eyre::Result<()> {
+    use cote::valid::GreaterEqual;
+    use cote::valid::LessEqual;
+    use cote::valid::Validate;
+
+    let ge = GreaterEqual::new(2i64);
+
+    assert!(ge.check(&2));
+    assert!(ge.check(&3));
+    assert!(!ge.check(&1));
+    assert_eq!(
+        ge.validate(&1),
+        Err("must be greater than or equal to 2".to_owned())
+    );
+
+    let le = LessEqual::new(2i64);
+
+    assert!(le.check(&2));
+    assert!(le.check(&1));
+    assert!(!le.check(&3));
+    assert_eq!(
+        le.validate(&3),
+        Err("must be less than or equal to 2".to_owned())
+    );
+    assert_eq!(
+        le.with_msg("too large").validate(&3),
+        Err("too large".to_owned())
+    );
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-c", valid = valid!(file).and(valid!(ext ["toml"])))]
+    config: Option<PathBuf>,
+
+    #[arg(alias = "-o", valid = valid!(dir))]
+    out_dir: Option<PathBuf>,
+
+    #[arg(alias = "-n", valid = valid!(non_empty).and(valid!(len 2 ..= 8)))]
+    name: Option<String>,
+
+    #[arg(alias = "-l", valid = valid!(one_of ["debug", "info", "warn"]))]
+    level: Option<String>,
+
+    #[arg(alias = "-e", valid = valid!(not exists))]
+    new_file: Option<PathBuf>,
+
+    #[arg(alias = "-r", valid = valid!(readable).or(valid!(one_of ["-"])))]
+    input: Option<String>,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn valid_lib() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from([
+        "app",
+        "-c=Cargo.toml",
+        "-o=src",
+        "-n=cote",
+        "-l=INFO",
+        "-e=not-exists.toml",
+        "-r=-",
+    ]))?;
+
+    assert_eq!(cli.config, Some(PathBuf::from("Cargo.toml")));
+    assert_eq!(cli.out_dir, Some(PathBuf::from("src")));
+    assert_eq!(cli.name.as_deref(), Some("cote"));
+    assert_eq!(cli.level.as_deref(), Some("INFO"));
+    assert_eq!(cli.new_file, Some(PathBuf::from("not-exists.toml")));
+    assert_eq!(cli.input.as_deref(), Some("-"));
+
+    check_message(["app", "-c=src"], "must be an existing file");
+    check_message(
+        ["app", "-c=src/lib.rs"],
+        "must be a path with extension one of `toml`",
+    );
+    check_message(["app", "-o=Cargo.toml"], "must be an existing directory");
+    check_message(["app", "-n="], "must be a non-empty value");
+    check_message(
+        ["app", "-n=cote-derive"],
+        "must be a value with length between 2 and 8",
+    );
+    check_message(
+        ["app", "-l=trace"],
+        "must be one of `debug`, `info`, `warn`",
+    );
+    check_message(["app", "-e=Cargo.toml"], "must not be an existing path");
+    check_message(
+        ["app", "-r=not-exists"],
+        "must be a readable path or must be one of `-`",
+    );
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--config")?.hint(), "-c, --config <FILE>");
+    assert_eq!(parser.find_opt("--out-dir")?.hint(), "-o, --out-dir <DIR>");
+    assert_eq!(parser.find_opt("--name")?.hint(), "-n, --name");
+    assert_eq!(
+        parser.find_opt("--level")?.hint(),
+        "-l, --level <debug|info|warn>"
+    );
+    assert_eq!(parser.find_opt("--new-file")?.hint(), "-e, --new-file");
+    Ok(())
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn valid_regex() -> Result<(), cote::Error> {
+    #[derive(Debug, Cote)]
+    #[cote()]
+    pub struct Version {
+        #[arg(alias = "-v", valid = valid!(regex r"^\d+\.\d+\.\d+$"))]
+        version: Option<String>,
+    }
+
+    let version = Version::parse(Args::from(["app", "-v=0.17.1"]))?;
+
+    assert_eq!(version.version.as_deref(), Some("0.17.1"));
+    assert!(Version::parse(Args::from(["app", "-v=latest"])).is_err());
+    assert!(cote::valid::regex::<String>("(").is_err());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    /// Print as json
+    #[arg(conflicts = "--table")]
+    json: bool,
+
+    /// Print as table
+    #[arg()]
+    table: bool,
+
+    #[arg(requires = ["--cert"])]
+    key: Option<PathBuf>,
+
+    #[arg()]
+    cert: Option<PathBuf>,
+
+    #[arg(required_unless = "--stdin")]
+    input: Option<PathBuf>,
+
+    #[arg()]
+    stdin: bool,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn constraint() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app", "--json", "--input", "a.txt"]))?;
+
+    assert!(cli.json);
+    assert!(!cli.table);
+    assert_eq!(cli.input, Some(PathBuf::from("a.txt")));
+
+    let cli = Cli::parse(Args::from([
+        "app", "--stdin", "--key", "a.key", "--cert", "a.crt",
+    ]))?;
+
+    assert!(cli.stdin);
+    assert_eq!(cli.key, Some(PathBuf::from("a.key")));
+    assert_eq!(cli.cert, Some(PathBuf::from("a.crt")));
+
+    check_message(
+        ["app", "--stdin", "--json", "--table"],
+        "option `--json` can not be used with `--table`",
+    );
+    check_message(
+        ["app", "--stdin", "--key", "a.key"],
+        "option `--key` requires `--cert` to be set",
+    );
+    check_message(
+        ["app", "--table"],
+        "option `--input` is required unless `--stdin` is set",
+    );
+
+    let parser = Cli::into_parser()?;
+    let json = parser.find_opt("--json")?;
+    let table = parser.find_opt("--table")?.uid();
+
+    // the target is resolved to uid even it is inserted after `--json`
+    assert_eq!(json.constraints()[0].targets(), &["--table"]);
+    assert_eq!(json.constraints()[0].uids(), &[Some(table)]);
+    assert_eq!(
+        help_with_constraints(json),
+        "Print as json [conflicts with --table]"
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(
+    group(name = "src", exclusive, required),
+    group(name = "op", required, members = ["--add", "--remove"]),
+    group(name = "auth", all_or_none)
+)]
+pub struct Cli {
+    /// Read from file
+    #[arg(group = "src")]
+    file: Option<PathBuf>,
+
+    /// Read from url
+    #[arg(group = "src")]
+    url: Option<String>,
+
+    /// Read from stdin
+    #[arg(group = "src")]
+    stdin: bool,
+
+    #[arg()]
+    add: Option<Vec<String>>,
+
+    #[arg()]
+    remove: Option<Vec<String>>,
+
+    #[arg(group = "auth")]
+    user: Option<String>,
+
+    #[arg(group = "auth")]
+    password: Option<String>,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn group() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app", "--stdin", "--add", "a", "--add", "b"]))?;
+
+    assert!(cli.stdin);
+    assert_eq!(cli.file, None);
+    assert_eq!(cli.url, None);
+    assert_eq!(cli.add, Some(vec!["a".to_owned(), "b".to_owned()]));
+    assert_eq!(cli.remove, None);
+
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--file",
+        "a.txt",
+        "--remove",
+        "c",
+        "--user",
+        "cote",
+        "--password",
+        "aopt",
+    ]))?;
+
+    assert_eq!(cli.file, Some(PathBuf::from("a.txt")));
+    assert_eq!(cli.remove, Some(vec!["c".to_owned()]));
+    assert_eq!(cli.user.as_deref(), Some("cote"));
+    assert_eq!(cli.password.as_deref(), Some("aopt"));
+
+    check_message(
+        ["app", "--file", "a.txt", "--stdin", "--add", "a"],
+        "options `--file`, `--stdin` in group `src` can not be used together",
+    );
+    check_message(
+        ["app", "--add", "a"],
+        "one of `--file`, `--url`, `--stdin` in group `src` is required",
+    );
+    check_message(
+        ["app", "--stdin"],
+        "one of `--add`, `--remove` in group `op` is required",
+    );
+    check_message(
+        ["app", "--stdin", "--add", "a", "--user", "cote"],
+        "options in group `auth` must be used together, missing `--password`",
+    );
+
+    let parser = Cli::into_parser()?;
+    let groups = parser.groups();
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0].rule(), "exactly one");
+    assert_eq!(groups[0].usage(), "(--file | --url | --stdin)");
+    assert_eq!(groups[1].rule(), "at least one");
+    assert_eq!(groups[1].usage(), "(--add | --remove)");
+    assert_eq!(groups[2].rule(), "all or none");
+    assert_eq!(groups[2].usage(), "[--user --password]");
+    Ok(())
+}
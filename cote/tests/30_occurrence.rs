@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    /// Output file, can only set once
+    #[arg(max = 1)]
+    out: Option<PathBuf>,
+
+    /// Config file, the first one wins
+    #[arg(duplicate = Duplicate::FirstWins)]
+    config: Option<PathBuf>,
+
+    /// Log level, the last one wins
+    #[arg(duplicate = Duplicate::LastWins)]
+    level: Option<Vec<String>>,
+
+    /// Profile name, must not be duplicated
+    #[arg(duplicate = Duplicate::Error)]
+    profile: Option<String>,
+
+    /// Tags, at least two, at most three
+    #[arg(min = 2, max = 3)]
+    tag: Option<Vec<String>>,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn occurrence() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from([
+        "app", "--out", "a", "--config", "a.toml", "--config", "b.toml", "--level", "debug",
+        "--level", "info", "--tag", "x", "--tag", "y",
+    ]))?;
+
+    assert_eq!(cli.out, Some(PathBuf::from("a")));
+    assert_eq!(cli.config, Some(PathBuf::from("a.toml")));
+    assert_eq!(cli.level, Some(vec!["info".to_owned()]));
+    assert_eq!(cli.profile, None);
+    assert_eq!(cli.tag, Some(vec!["x".to_owned(), "y".to_owned()]));
+
+    check_message(
+        ["app", "--out", "a", "--out", "b"],
+        "option `--out` can occur at most 1 time(s), found at position 3 after position 1",
+    );
+    check_message(
+        ["app", "--profile", "dev", "--out", "a", "--profile", "prod"],
+        "option `--profile` is duplicated at position 5, it is first set at position 1",
+    );
+    check_message(
+        ["app", "--tag", "x"],
+        "option `--tag` must occur at least 2 time(s), found 1",
+    );
+    check_message(
+        ["app", "--tag=a", "--tag=b", "--tag=c", "--tag=d"],
+        "option `--tag` can occur at most 3 time(s), found at position 4 after position 3",
+    );
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(
+        parser.find_opt("--tag")?.occurrence(),
+        &Occurrence::default().with_min(2).with_max(3)
+    );
+    Ok(())
+}
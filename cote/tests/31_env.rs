@@ -0,0 +1,79 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(envprefix = "COTE_T31_")]
+pub struct Cli {
+    /// Access token
+    #[arg(env)]
+    token: String,
+
+    /// Listen port
+    #[arg(env = "COTE_T31_LISTEN_PORT", valid = valid!(1..1024))]
+    port: Option<u16>,
+
+    /// Enable debug mode
+    #[arg(env)]
+    debug: bool,
+
+    /// User name
+    #[arg(alias = "-u")]
+    user: Option<String>,
+
+    /// Log level
+    #[arg(env, min = 1)]
+    level: Option<String>,
+}
+
+fn check_message<const N: usize>(args: [&str; N], msg: &str) {
+    let err = Cli::parse(Args::from(args)).unwrap_err();
+
+    assert!(format!("{err:?}").contains(msg), "{err:?}");
+}
+
+#[test]
+fn env() -> color_eyre::Result<()> {
+    check_message(["app"], "--token");
+
+    std::env::set_var("COTE_T31_TOKEN", "secret");
+    std::env::set_var("COTE_T31_LISTEN_PORT", "80");
+    std::env::set_var("COTE_T31_DEBUG", "true");
+    std::env::set_var("COTE_T31_LEVEL", "info");
+
+    let cli = Cli::parse(Args::from(["app", "-u", "cote"]))?;
+
+    assert_eq!(cli.token, "secret");
+    assert_eq!(cli.port, Some(80));
+    assert!(cli.debug);
+    assert_eq!(cli.user.as_deref(), Some("cote"));
+    // the value of environment variable is counted as an occurrence
+    assert_eq!(cli.level.as_deref(), Some("info"));
+
+    // the command line value has higher priority than environment variable
+    let cli = Cli::parse(Args::from(["app", "--token", "plain", "--port", "443"]))?;
+
+    assert_eq!(cli.token, "plain");
+    assert_eq!(cli.port, Some(443));
+
+    // the environment variable value is checked by validator too
+    std::env::set_var("COTE_T31_LISTEN_PORT", "8080");
+    check_message(
+        ["app"],
+        "invalid value `8080`: must be greater than or equal to 1 and less than 1024",
+    );
+    std::env::set_var("COTE_T31_LISTEN_PORT", "port");
+    check_message(["app"], "not a valid value of type u16");
+
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(parser.find_opt("--token")?.env(), Some("COTE_T31_TOKEN"));
+    assert_eq!(
+        parser.find_opt("--port")?.env(),
+        Some("COTE_T31_LISTEN_PORT")
+    );
+    assert_eq!(parser.find_opt("--user")?.env(), None);
+    assert_eq!(
+        help_with_details(parser.find_opt("--token")?),
+        "Access token [env: COTE_T31_TOKEN]"
+    );
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(envprefix = "COTE_T32_")]
+pub struct Cli {
+    /// Number of jobs
+    #[arg(alias = "-j")]
+    jobs: Option<i64>,
+
+    /// User name
+    #[arg()]
+    name: Option<String>,
+
+    /// Access token
+    #[arg(env)]
+    token: Option<String>,
+
+    /// Do not write anything
+    #[arg()]
+    dry_run: bool,
+
+    /// Log level
+    #[arg(valid = valid!(1..10))]
+    level: Option<i64>,
+
+    #[arg()]
+    tag: Option<Vec<String>>,
+
+    #[sub()]
+    list: Option<List>,
+}
+
+#[derive(Debug, Cote)]
+pub struct List {
+    /// List all items
+    #[arg()]
+    all: bool,
+
+    /// Maximum count of items
+    #[arg(value = 10)]
+    limit: i64,
+}
+
+fn parse_with<const N: usize>(args: [&str; N], layers: &[ConfigLayer]) -> cote::Result<Cli> {
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    for layer in layers {
+        parser.add_layer(layer.clone());
+    }
+    let ret = parser.parse_policy(Args::from(args), &mut policy)?;
+
+    Cli::from(ret, parser)
+}
+
+#[test]
+fn layer() -> color_eyre::Result<()> {
+    std::env::set_var("COTE_T32_TOKEN", "env");
+
+    let user = ConfigLayer::new("user.toml")
+        .with_value("jobs", ["4"])
+        .with_value("name", ["user"])
+        .with_value("token", ["user"])
+        .with_value("level", ["3"]);
+    let project = ConfigLayer::new("project.toml")
+        .with_value("j", ["8"])
+        .with_value("dry_run", ["true"])
+        .with_value("tag", ["a", "b"])
+        .with_sub("list", ConfigLayer::new("").with_value("all", ["true"]));
+    let cli = parse_with(["app", "--name", "cli", "list"], &[user.clone(), project])?;
+
+    // command line > env > project file > user file > defaults
+    assert_eq!(cli.jobs, Some(8));
+    assert_eq!(cli.name.as_deref(), Some("cli"));
+    assert_eq!(cli.token.as_deref(), Some("env"));
+    assert!(cli.dry_run);
+    assert_eq!(cli.level, Some(3));
+    assert_eq!(cli.tag, Some(vec!["a".to_owned(), "b".to_owned()]));
+
+    let list = cli.list.unwrap();
+
+    assert!(list.all);
+    assert_eq!(list.limit, 10);
+
+    // the value is checked by validator
+    let project = ConfigLayer::new("project.toml").with_value("level", ["20"]);
+    let err = parse_with(["app"], &[user, project]).unwrap_err();
+
+    assert!(
+        format!("{err:?}").contains("invalid value of key `level` in configuration `project.toml`"),
+        "{err:?}"
+    );
+
+    #[cfg(feature = "toml")]
+    toml_impl()?;
+    #[cfg(feature = "json")]
+    json_impl()?;
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[derive(Debug, Cote)]
+#[cote(
+    layer = std::env::temp_dir().join("cote_t32_user.toml"),
+    layer = std::env::temp_dir().join("cote_t32_project.toml"),
+)]
+pub struct TomlCli {
+    #[arg()]
+    jobs: Option<i64>,
+
+    #[arg()]
+    name: Option<String>,
+
+    #[sub()]
+    list: Option<List>,
+}
+
+#[cfg(feature = "toml")]
+fn toml_impl() -> color_eyre::Result<()> {
+    let dir = std::env::temp_dir();
+
+    std::fs::write(
+        dir.join("cote_t32_user.toml"),
+        "jobs = 4\nname = \"user\"\n",
+    )?;
+    std::fs::write(
+        dir.join("cote_t32_project.toml"),
+        "jobs = 8\n\n[list]\nlimit = 42\n",
+    )?;
+
+    let cli = TomlCli::parse(Args::from(["app", "list"]))?;
+
+    assert_eq!(cli.jobs, Some(8));
+    assert_eq!(cli.name.as_deref(), Some("user"));
+    assert_eq!(cli.list.map(|v| v.limit), Some(42));
+
+    std::fs::write(
+        dir.join("cote_t32_project.toml"),
+        "[list]\nlimit = \"many\"\n",
+    )?;
+
+    let err = TomlCli::parse(Args::from(["app", "list"])).unwrap_err();
+
+    assert!(
+        format!("{err:?}").contains("invalid value of key `list.limit` in configuration"),
+        "{err:?}"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+fn json_impl() -> color_eyre::Result<()> {
+    let path = std::env::temp_dir().join("cote_t32_project.json");
+
+    std::fs::write(
+        &path,
+        r#"{ "jobs": 2, "tag": ["x", "y"], "list": { "all": true } }"#,
+    )?;
+
+    let layer = ConfigLayer::load(&path)?;
+    let cli = parse_with(["app", "list"], &[layer])?;
+
+    assert_eq!(cli.jobs, Some(2));
+    assert_eq!(cli.tag, Some(vec!["x".to_owned(), "y".to_owned()]));
+    assert!(cli.list.unwrap().all);
+    Ok(())
+}
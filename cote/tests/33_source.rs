@@ -0,0 +1,108 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(envprefix = "COTE_T33_")]
+pub struct Cli {
+    /// Number of jobs
+    #[arg(alias = "-j", value = 1)]
+    jobs: i64,
+
+    /// Access token
+    #[arg(env)]
+    token: Option<String>,
+
+    /// Log level
+    #[arg()]
+    level: Option<i64>,
+
+    /// Where the mode come from
+    #[arg(on = source_of_mode)]
+    mode: Option<String>,
+
+    #[arg(append)]
+    tag: Option<Vec<String>>,
+
+    #[pos()]
+    file: Option<String>,
+}
+
+fn source_of_mode<S>(_: &mut S, ctx: &mut Ctx) -> cote::Result<Option<String>> {
+    Ok(Some(format!(
+        "{}={}",
+        ctx.value::<String>()?,
+        ctx.source()?
+    )))
+}
+
+#[test]
+fn source() -> color_eyre::Result<()> {
+    std::env::set_var("COTE_T33_TOKEN", "env");
+
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    parser.add_layer(
+        ConfigLayer::new("project.toml")
+            .with_value("level", ["3"])
+            .with_value("mode", ["fast"]),
+    );
+
+    let args = ["app", "foo", "--tag", "a", "--tag=b"];
+    let ret = parser.parse_policy(Args::from(args), &mut policy)?;
+
+    assert_eq!(
+        Cli::source_of(&parser, "jobs")?,
+        Some(&ValueSource::Default)
+    );
+    assert_eq!(
+        Cli::source_of(&parser, "token")?,
+        Some(&ValueSource::Env {
+            name: "COTE_T33_TOKEN".to_owned()
+        })
+    );
+    assert_eq!(
+        parser.find_source("--level")?,
+        Some(&ValueSource::Config {
+            origin: "project.toml".to_owned(),
+            key: "level".to_owned()
+        })
+    );
+    assert_eq!(
+        parser.find_sources("--tag")?,
+        &[
+            ValueSource::CommandLine { index: 2 },
+            ValueSource::CommandLine { index: 4 }
+        ]
+    );
+    // the index of positional is the index of NOA arguments
+    assert_eq!(
+        Cli::source_of(&parser, "file")?,
+        Some(&ValueSource::CommandLine { index: 1 })
+    );
+    assert!(Cli::source_of(&parser, "user").is_err());
+
+    let cli = Cli::from(ret, parser)?;
+
+    assert_eq!(cli.jobs, 1);
+    assert_eq!(cli.token.as_deref(), Some("env"));
+    assert_eq!(cli.level, Some(3));
+    assert_eq!(cli.tag, Some(vec!["a".to_owned(), "b".to_owned()]));
+    assert_eq!(cli.file.as_deref(), Some("foo"));
+    assert_eq!(
+        cli.mode.as_deref(),
+        Some("fast=configuration `project.toml` key `mode`")
+    );
+
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", "-j", "8", "--mode=slow"]))?;
+
+    assert_eq!(
+        Cli::source_of(&parser, "jobs")?,
+        Some(&ValueSource::CommandLine { index: 1 })
+    );
+    assert_eq!(Cli::source_of(&parser, "level")?, None);
+
+    let cli = Cli::from(Return::default(), parser)?;
+
+    assert_eq!(cli.mode.as_deref(), Some("slow=command line @ 3"));
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use cote::aopt_help::store::Store;
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Timeout in seconds
+    #[arg(value = 30u64)]
+    timeout: u64,
+
+    /// Interval of retry
+    #[arg(value = 5u64, showdef = "5s")]
+    interval: u64,
+
+    /// User name
+    #[arg(value = "cote", showdef = false)]
+    user: String,
+
+    /// Output directory
+    #[arg(value = PathBuf::from("target"))]
+    output: PathBuf,
+
+    /// Enable debug mode
+    #[arg()]
+    debug: bool,
+
+    /// Input files
+    #[pos(index = 1.., values = ["a.txt", "b.txt"])]
+    files: Vec<String>,
+}
+
+fn display_of(parser: &Parser<'_, CoteSet>, name: &str) -> cote::Result<Option<String>> {
+    Ok(parser
+        .find_opt(name)?
+        .accessor()
+        .initializer()
+        .display()
+        .map(String::from))
+}
+
+#[test]
+fn default() -> color_eyre::Result<()> {
+    let parser = Cli::into_parser()?;
+
+    assert_eq!(display_of(&parser, "--timeout")?.as_deref(), Some("30"));
+    assert_eq!(display_of(&parser, "--interval")?.as_deref(), Some("5s"));
+    assert_eq!(display_of(&parser, "--user")?, None);
+    assert_eq!(display_of(&parser, "--output")?.as_deref(), Some("target"));
+    assert_eq!(display_of(&parser, "--debug")?, None);
+    assert_eq!(
+        display_of(&parser, "files")?.as_deref(),
+        Some("a.txt, b.txt")
+    );
+
+    let opt = parser.find_opt("--timeout")?;
+    let mut store = Store::new(opt.name(), opt.hint(), opt.help(), "", !opt.force(), false);
+
+    store.set_default_value(display_of(&parser, "--timeout")?.unwrap());
+    assert_eq!(
+        store.help_with_default(),
+        "Timeout in seconds [default: 30]"
+    );
+
+    let mut help = vec![];
+
+    write_set_help(&parser, &mut help, "app", "", "", 80, 40)?;
+    let help = String::from_utf8(help)?;
+
+    assert!(help.contains("Timeout in seconds [default: 30]"), "{help}");
+    assert!(help.contains("Interval of retry [default: 5s]"), "{help}");
+    assert!(
+        help.contains("Output directory [default: target]"),
+        "{help}"
+    );
+    assert!(!help.contains("[default: cote]"), "{help}");
+
+    // the display string not affect the value
+    let cli = Cli::parse(Args::from(["app", "--interval", "8"]))?;
+
+    assert_eq!(cli.timeout, 30);
+    assert_eq!(cli.interval, 8);
+    assert_eq!(cli.user, "cote");
+    assert_eq!(cli.output, PathBuf::from("target"));
+    assert!(!cli.debug);
+    assert_eq!(cli.files, vec!["a.txt".to_owned(), "b.txt".to_owned()]);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Enable the features
+    #[arg(alias = "-F", action = "uniq")]
+    feature: Option<Vec<String>>,
+
+    /// Include the paths, separated by comma
+    #[arg(alias = "-I", action = Action::Ext)]
+    include: Option<Vec<String>>,
+
+    /// Name of project
+    #[arg(action = "once")]
+    name: Option<String>,
+
+    /// Flip the color mode
+    #[arg(action = Action::Toggle)]
+    color: bool,
+
+    /// The ports, separated by semicolon
+    #[arg(action = "ext", sep = ';', on = double)]
+    port: Option<Vec<i64>>,
+
+    /// Add the authors
+    #[arg(action = "append")]
+    author: Option<Vec<String>>,
+}
+
+fn double<S>(_: &mut S, ctx: &mut Ctx) -> cote::Result<Option<i64>> {
+    // the handler receive each part of value
+    Ok(Some(ctx.value::<i64>()? * 2))
+}
+
+#[test]
+fn action() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from([
+        "app",
+        "-F",
+        "foo",
+        "--feature=bar",
+        "-F",
+        "foo",
+        "-I",
+        "src,tests",
+        "--include",
+        "docs",
+        "--name",
+        "cote",
+        "--color",
+        "--color",
+        "--color",
+    ]))?;
+
+    assert_eq!(cli.feature, Some(vec!["foo".to_owned(), "bar".to_owned()]));
+    assert_eq!(
+        cli.include,
+        Some(vec![
+            "src".to_owned(),
+            "tests".to_owned(),
+            "docs".to_owned()
+        ])
+    );
+    assert_eq!(cli.name.as_deref(), Some("cote"));
+    assert!(cli.color);
+
+    let cli = Cli::parse(Args::from(["app", "--color", "--color"]))?;
+
+    assert!(!cli.color);
+
+    let cli = Cli::parse(Args::from(["app", "--port", "80;443"]))?;
+
+    assert_eq!(cli.port, Some(vec![160, 886]));
+    assert_eq!(cli.author, None);
+
+    // the option with `Action::SetOnce` can only appear once
+    let err = Cli::parse(Args::from(["app", "--name", "a", "--name", "b"])).unwrap_err();
+
+    assert!(format!("{err:?}").contains("--name"), "{err:?}");
+
+    // the repeatable option has a `...` in help
+    let parser = Cli::into_parser()?;
+
+    assert!(hint_with_action(parser.find_opt("--feature")?).ends_with("..."));
+    assert!(hint_with_action(parser.find_opt("--include")?).ends_with("..."));
+    assert!(hint_with_action(parser.find_opt("--author")?).ends_with("..."));
+    assert!(!hint_with_action(parser.find_opt("--name")?).ends_with("..."));
+
+    // set the action in creator string
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    parser.add_opt("--tag=s%uniq")?;
+    parser.add_opt("--flag=b%toggle")?;
+    parser.parse_policy(
+        Args::from(["app", "--tag=a", "--tag=b", "--tag=a", "--flag"]),
+        &mut policy,
+    )?;
+
+    assert_eq!(parser.find_vals::<String>("--tag")?, &["a", "b"]);
+    assert_eq!(parser.find_val::<bool>("--flag")?, &true);
+    assert!(parser.add_opt("--bad=s%unknown").is_err());
+    // the toggle is only available for bool
+    assert!(parser.add_opt("--theme=s%toggle")?.run().is_err());
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, combine)]
+pub struct Cli {
+    #[arg(verbosity)]
+    verbose: Verbosity,
+}
+
+#[derive(Debug, Cote)]
+pub struct Build {
+    /// Set the verbosity of build process
+    #[arg(name = "--log", alias = "-l", verbosity = "info", quiet = ["-s", "--silent"])]
+    log: Verbosity,
+}
+
+#[test]
+fn verbosity() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.verbose.level(), Verbosity::WARN);
+
+    let cli = Cli::parse(Args::from(["app", "-vvv", "-q"]))?;
+
+    assert_eq!(cli.verbose.level(), Verbosity::DEBUG);
+    assert_eq!(cli.verbose.verbose(), 3);
+    assert_eq!(cli.verbose.quiet(), 1);
+
+    let cli = Cli::parse(Args::from(["app", "--quiet", "-q", "-q"]))?;
+
+    assert!(cli.verbose.is_off());
+
+    // both options are registered with a help message and default level
+    let parser = Cli::into_parser()?;
+    let opt = parser.find_opt("-v")?;
+
+    assert_eq!(opt.name(), "--verbose");
+    assert_eq!(opt.help(), "Increase the verbosity level");
+    assert_eq!(opt.accessor().initializer().display(), Some("warn"));
+
+    let opt = parser.find_opt("-q")?;
+
+    assert_eq!(opt.name(), "-q");
+    assert_eq!(opt.alias(), Some(&vec!["--quiet".to_owned()]));
+    assert_eq!(opt.help(), "Decrease the verbosity level");
+
+    let build = Build::parse(Args::from(["app", "-l", "--log"]))?;
+
+    assert_eq!(build.log.level(), Verbosity::TRACE);
+    assert_eq!(build.log.to_string(), "trace");
+
+    let build = Build::parse(Args::from(["app", "--silent", "-s"]))?;
+
+    assert_eq!(build.log.level(), Verbosity::ERROR);
+    // the default names are not registered if `alias` and `quiet` are set
+    assert!(Build::parse(Args::from(["app", "-v"])).is_err());
+    assert!(Build::parse(Args::from(["app", "-q"])).is_err());
+
+    let parser = Build::into_parser()?;
+    let opt = parser.find_opt("--log")?;
+
+    assert_eq!(opt.help(), "Set the verbosity of build process");
+    assert_eq!(opt.accessor().initializer().display(), Some("info"));
+
+    #[cfg(feature = "log")]
+    {
+        let cli = Cli::parse(Args::from(["app", "-v"]))?;
+
+        assert_eq!(
+            cli.verbose
+                .tracing_level()
+                .map(|v| v.to_string())
+                .as_deref(),
+            Some("INFO")
+        );
+        assert_eq!(cli.verbose.level_filter().to_string(), "info");
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use cote::aopt_help::prelude::Style as HelpStyle;
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    #[arg(color)]
+    when: ColorChoice,
+}
+
+#[derive(Debug, Cote)]
+pub struct Plain {
+    /// Colorize the output
+    #[arg(color = "never")]
+    color: ColorChoice,
+}
+
+#[test]
+fn color() -> color_eyre::Result<()> {
+    // registered as `--color` with a help message and default choice
+    let parser = Cli::into_parser()?;
+    let opt = parser.find_opt("--color")?;
+
+    assert_eq!(opt.help(), "When to use colors: auto, always or never");
+    assert_eq!(opt.accessor().initializer().display(), Some("auto"));
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.when, ColorChoice::Auto);
+
+    let cli = Cli::parse(Args::from(["app", "--color", "always"]))?;
+
+    assert_eq!(cli.when, ColorChoice::Always);
+    assert!(Cli::parse(Args::from(["app", "--color=sometimes"])).is_err());
+
+    // the choice is read from the parser, it decide the colors of help message
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", "--color", "always"]))?;
+    let mut help = vec![];
+
+    assert_eq!(color_choice(&parser), ColorChoice::Always);
+    write_set_help(&parser, &mut help, "app", "", "", 40, 10)?;
+    assert!(String::from_utf8(help)?.contains("\x1b[1;4mUsage:\x1b[0m"));
+
+    let CoteRes { parser, .. } = Plain::parse_args(Args::from(["app"]))?;
+    let mut help = vec![];
+
+    assert_eq!(color_choice(&parser), ColorChoice::Never);
+    write_set_help(&parser, &mut help, "app", "", "", 40, 10)?;
+    assert!(!String::from_utf8(help)?.contains('\x1b'));
+
+    let plain = Plain::parse(Args::from(["app"]))?;
+
+    assert_eq!(plain.color, ColorChoice::Never);
+
+    // the `auto` choice is resolved against the writer, not the stdout
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app"]))?;
+    let mut help = vec![];
+
+    if std::env::var_os("CLICOLOR_FORCE").is_none() {
+        write_set_help(&parser, &mut help, "app", "", "", 40, 10)?;
+        assert!(!String::from_utf8(help)?.contains('\x1b'));
+    }
+    let plain = Plain::parse(Args::from(["app", "--color=Auto"]))?;
+
+    assert_eq!(plain.color, ColorChoice::Auto);
+
+    // the resolved choice decide the colors of help and error message
+    let style = HelpStyle::default().with_color(ColorChoice::Always.resolve());
+
+    assert_eq!(style.paint(HelpStyle::BOLD, "  -h "), "  \x1b[1m-h\x1b[0m ");
+    assert_eq!(
+        HelpStyle::default().paint(HelpStyle::BOLD, "  -h "),
+        "  -h "
+    );
+
+    let err = cote::Error::raise_error("invalid value")
+        .cause_by(cote::Error::raise_error("not a number"));
+
+    assert_eq!(
+        error_message(&err, false),
+        "error: invalid value\n  caused by: not a number"
+    );
+    assert!(error_message(&err, ColorChoice::Always.resolve()).starts_with("\x1b[1;31merror:"));
+    assert!(!ColorChoice::Never.resolve_for(&std::io::stderr()));
+    Ok(())
+}
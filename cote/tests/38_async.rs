@@ -0,0 +1,102 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Number of retries
+    #[arg(value = 1i64)]
+    retry: i64,
+
+    /// The urls need to fetch
+    #[arg(append)]
+    url: Option<Vec<String>>,
+}
+
+// the async handler return a `Send` boxed future with feature `sync`
+#[cfg(not(feature = "sync"))]
+macro_rules! handler {
+    (|$set:ident, $ctx:ident| $body:block) => {
+        async |$set: &mut Parser<'_, CoteSet>, $ctx: &mut Ctx| $body
+    };
+}
+
+#[cfg(feature = "sync")]
+macro_rules! handler {
+    (|$set:ident, $ctx:ident| $body:block) => {
+        |$set: &mut Parser<'_, CoteSet>, $ctx: &mut Ctx| Box::pin(async move $body)
+    };
+}
+
+#[test]
+fn async_handler() -> color_eyre::Result<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    runtime.block_on(async {
+        let mut parser = Cli::into_parser()?;
+        let mut policy = Cli::into_policy();
+
+        parser.set_app_data(Vec::<String>::new());
+        // `--size` is fetched asynchronously when it matched
+        let size = parser.add_opt("--size".infer::<i64>())?.run()?;
+        let check = parser.add_opt("--check".infer::<bool>())?.run()?;
+        let tag = parser.add_opt("--tag".infer::<String>())?.run()?;
+
+        parser
+            .entry_async(
+                size,
+                handler!(|set, ctx| {
+                    let size = ctx.value::<i64>()?;
+
+                    tokio::task::yield_now().await;
+                    set.app_data_mut::<Vec<String>>()?
+                        .push(format!("size {size}"));
+                    Ok(Some(size * 1024))
+                }),
+            )?
+            .submit();
+        parser
+            .entry_async(
+                check,
+                handler!(|set, _ctx| {
+                    tokio::task::yield_now().await;
+                    set.app_data_mut::<Vec<String>>()?.push("check".to_owned());
+                    Ok(Some(true))
+                }),
+            )?
+            .submit();
+        parser
+            .entry(tag)?
+            .on(|set: &mut Parser<'_, CoteSet>, ctx: &mut Ctx| {
+                let tag = ctx.value::<String>()?;
+
+                set.app_data_mut::<Vec<String>>()?
+                    .push(format!("tag {tag}"));
+                Ok(Some(tag))
+            });
+
+        let args = ["app", "--check", "--size=4", "--tag=t", "--size", "8"];
+
+        // the async handlers are awaited in the order the options matched
+        let cli = parser
+            .run_async_mut_with(args, &mut policy, async |ret, parser| {
+                assert!(ret.status());
+                assert_eq!(
+                    parser.app_data::<Vec<String>>()?,
+                    &["check", "size 4", "tag t", "size 8"]
+                );
+                assert_eq!(parser.find_val::<i64>("--size")?, &8192);
+                assert_eq!(parser.find_val::<bool>("--check")?, &true);
+                Cli::try_extract(parser.optset_mut())
+            })
+            .await?;
+
+        assert_eq!(cli.retry, 1);
+        assert_eq!(cli.url, None);
+
+        // the sync parse report an error for async handler
+        assert!(parser.parse_policy(Args::from(args), &mut policy).is_err());
+        Ok::<_, color_eyre::Report>(())
+    })
+}
@@ -0,0 +1,49 @@
+use cote::aopt::ctx::OptVal;
+use cote::aopt::ctx::Service;
+use cote::aopt::ctx::Value;
+use cote::prelude::*;
+
+cote::aopt::opt_name!(Scale = "--scale");
+
+#[derive(Debug, Clone)]
+pub struct Unit(String);
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// Scale of the size
+    #[arg(value = 2i64)]
+    scale: i64,
+
+    /// Size with unit
+    #[arg(on = extract_handler(size_with_unit))]
+    size: Option<String>,
+}
+
+fn size_with_unit(
+    size: Value<i64>,
+    scale: OptVal<Scale, i64>,
+    unit: Option<Service<Unit>>,
+) -> cote::Result<Option<String>> {
+    let unit = unit.map(|v| v.into_inner().0).unwrap_or_default();
+
+    Ok(Some(format!("{}{}", *size * *scale, unit)))
+}
+
+#[test]
+fn extract() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app", "--size=4"]))?;
+
+    assert_eq!(cli.size.as_deref(), Some("8"));
+
+    let mut parser = Cli::into_parser()?;
+    let mut policy = Cli::into_policy();
+
+    parser.set_app_data(Unit("KB".to_owned()));
+    parser.parse_policy(Args::from(["app", "--scale=3", "--size=4"]), &mut policy)?;
+
+    let cli = Cli::try_extract(parser.optset_mut())?;
+
+    assert_eq!(cli.scale, 3);
+    assert_eq!(cli.size.as_deref(), Some("12KB"));
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(strict = true)]
+pub struct Cli {
+    /// The format of error output
+    #[arg(name = "--error-format")]
+    format: ErrorFormat,
+
+    #[arg(value = 1i64)]
+    depth: i64,
+}
+
+#[test]
+fn error_format() -> color_eyre::Result<()> {
+    let cli = Cli::parse(Args::from(["app", "--error-format=json"]))?;
+
+    assert_eq!(cli.format, ErrorFormat::Json);
+    assert_eq!(cli.depth, 1);
+
+    // the format is read from the parser
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", "--error-format=json"]))?;
+
+    assert_eq!(cote::prelude::error_format(&parser), ErrorFormat::Json);
+
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app"]))?;
+
+    assert_eq!(cote::prelude::error_format(&parser), ErrorFormat::Text);
+
+    // the error keep the option name, argument index and value
+    let err = Cli::parse(Args::from(["app", "--depth=x"])).unwrap_err();
+    let not_found = err.caused_by().unwrap();
+    let cause = not_found.caused_by().unwrap();
+
+    assert_eq!(not_found.kind(), &cote::aopt::err::Kind::OptionNotFound);
+    assert_eq!(cause.kind(), &cote::aopt::err::Kind::RawValParse);
+    assert_eq!(cause.name(), Some("--depth"));
+    assert_eq!(cause.index(), Some(1));
+    assert_eq!(cause.argument(), Some("x"));
+    assert_eq!(exit_code(&err), EXIT_FAILURE);
+    assert_eq!(exit_code(&cote::Error::raise_error("bug")), EXIT_ERROR);
+
+    // the similar names are suggested
+    let err = Cli::parse(Args::from(["app", "--dpeth=2"])).unwrap_err();
+    let cause = err.caused_by().unwrap();
+
+    assert_eq!(cause.kind(), &cote::aopt::err::Kind::OptionNotFound);
+    assert_eq!(cause.suggestions(), ["--depth"]);
+
+    #[cfg(feature = "json")]
+    {
+        let json: serde_json::Value = serde_json::from_str(&error_json(&err)?)?;
+
+        assert_eq!(json["failure"], true);
+        assert_eq!(json["cause"]["kind"], "OptionNotFound");
+        assert_eq!(json["cause"]["name"], "--dpeth");
+        assert_eq!(json["cause"]["suggestions"][0], "--depth");
+    }
+    Ok(())
+}